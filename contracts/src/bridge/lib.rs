@@ -0,0 +1,232 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod bridge {
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use kudos_ink_contracts::traits::lifecycle::{LifecycleHookError, *};
+    use openbrush::{modifiers, traits::Storage};
+
+    use ink::storage::Mapping;
+
+    /// An event bridge relaying the contribution lifecycle transitions of the reward contracts.
+    ///
+    /// Every transition notified by an allowed reward contract is emitted as a standardized
+    /// `LifecycleMessage`, consumed by an off-chain relayer (e.g. to comment "reward claimable"
+    /// on the GitHub issue), so that the reward contracts stay unaware of GitHub.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct EventBridge {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The reward contracts allowed to notify.
+        callers: Mapping<AccountId, ()>,
+
+        // The number of messages emitted so far.
+        nonce: u64,
+    }
+
+    /// Emitted for every lifecycle `transition` of a given `contribution_id` notified by
+    /// a `workflow` contract.
+    ///
+    /// The `nonce` is incremented for every message, so that the relayer processes them in
+    /// order and detects the missed ones.
+    #[ink(event)]
+    pub struct LifecycleMessage {
+        #[ink(topic)]
+        workflow: AccountId,
+        #[ink(topic)]
+        contribution_id: u64,
+        transition: Transition,
+        nonce: u64,
+    }
+
+    /// Emitted when a reward contract is allowed or disallowed to notify.
+    #[ink(event)]
+    pub struct CallerUpdated {
+        #[ink(topic)]
+        caller: AccountId,
+        allowed: bool,
+    }
+
+    impl LifecycleHook for EventBridge {
+        /// Notify a lifecycle `transition` of a given `contribution_id`.
+        ///
+        /// Constraint(s):
+        /// 1. The caller must be an allowed reward contract.
+        ///
+        /// A `LifecycleMessage` event is emitted.
+        #[ink(message)]
+        fn notify(&mut self, contribution_id: u64, transition: Transition) -> Result<(), LifecycleHookError> {
+            self.notify(contribution_id, transition)
+        }
+    }
+
+    impl Introspection for EventBridge {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl EventBridge {
+        /// Constructor that initializes the bridge.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            instance
+        }
+
+        /// Notify a lifecycle `transition` of a given `contribution_id`.
+        ///
+        /// Only callable by an allowed reward contract.
+        #[ink(message)]
+        pub fn notify(&mut self, contribution_id: u64, transition: Transition) -> Result<(), LifecycleHookError> {
+            let workflow = Self::env().caller();
+            if !self.callers.contains(workflow) {
+                return Err(LifecycleHookError::CallerNotAllowed);
+            }
+
+            let nonce = self.nonce;
+            self.nonce = nonce.wrapping_add(1);
+
+            self.env().emit_event(LifecycleMessage {
+                workflow,
+                contribution_id,
+                transition,
+                nonce,
+            });
+
+            Ok(())
+        }
+
+        /// Allow or disallow a reward contract to notify.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_caller(&mut self, caller: AccountId, allowed: bool) -> Result<(), LifecycleHookError> {
+            if allowed {
+                self.callers.insert(caller, &());
+            } else {
+                self.callers.remove(caller);
+            }
+
+            self.env().emit_event(CallerUpdated { caller, allowed });
+
+            Ok(())
+        }
+
+        /// Simply checks if a given `caller` is allowed to notify.
+        #[ink(message)]
+        pub fn is_allowed_caller(&self, caller: AccountId) -> bool {
+            self.callers.contains(caller)
+        }
+
+        /// Simply returns the nonce of the next message.
+        #[ink(message)]
+        pub fn get_nonce(&self) -> u64 {
+            self.nonce
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(
+                interface_id,
+                interface_ids::INTROSPECTION | interface_ids::LIFECYCLE_HOOK
+            )
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB -> reward contract
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+        type Event = <EventBridge as ::ink::reflect::ContractEventBase>::Type;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let bridge = create_contract();
+            assert_eq!(Ownable::owner(&bridge), Some(accounts.alice));
+            assert_eq!(bridge.get_nonce(), 0);
+        }
+
+        #[ink::test]
+        fn only_allowed_caller_can_notify() {
+            let accounts = default_accounts();
+            let mut bridge = create_contract();
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                bridge.notify(1, Transition::Approved),
+                Err(LifecycleHookError::CallerNotAllowed)
+            );
+            assert_eq!(
+                bridge.set_caller(accounts.bob, true),
+                Err(LifecycleHookError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(bridge.set_caller(accounts.bob, true), Ok(()));
+            assert!(bridge.is_allowed_caller(accounts.bob));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(bridge.notify(1, Transition::Approved), Ok(()));
+            assert_eq!(bridge.notify(1, Transition::Claimed), Ok(()));
+            assert_eq!(bridge.get_nonce(), 2);
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let event = emitted_events.last().expect("a message is emitted");
+            if let Ok(Event::LifecycleMessage(LifecycleMessage {
+                workflow,
+                contribution_id,
+                transition,
+                nonce,
+            })) = <Event as scale::Decode>::decode(&mut &event.data[..])
+            {
+                assert_eq!(workflow, accounts.bob);
+                assert_eq!(contribution_id, 1);
+                assert_eq!(transition, Transition::Claimed);
+                assert_eq!(nonce, 1);
+            } else {
+                panic!("encountered unexpected event kind: expected a LifecycleMessage event")
+            }
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let bridge = create_contract();
+            assert!(bridge.supports_interface(interface_ids::INTROSPECTION));
+            assert!(bridge.supports_interface(interface_ids::LIFECYCLE_HOOK));
+            assert!(!bridge.supports_interface(interface_ids::WORKFLOW_V1));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        /// Creates a new instance of `EventBridge`.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract() -> EventBridge {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            EventBridge::new()
+        }
+    }
+}
@@ -0,0 +1,297 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod factory {
+    use kudos_ink_contracts::traits::factory::{FactoryError, *};
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use kudos_ink_contracts::traits::types::HashValue;
+    use kudos_ink_contracts::traits::workflow::ROLES;
+    use openbrush::{
+        contracts::traits::{access_control::AccessControlError, ownable::OwnableError},
+        modifiers,
+        traits::Storage,
+    };
+
+    use ink::env::call::{build_call, build_create, ExecutionInput, FromAccountId, Selector};
+    use ink::storage::Mapping;
+
+    /// A reference to a created workflow contract.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct WorkflowRef {
+        account_id: AccountId,
+    }
+
+    impl FromAccountId<Environment> for WorkflowRef {
+        fn from_account_id(account_id: AccountId) -> Self {
+            Self { account_id }
+        }
+    }
+
+    impl ink::env::ContractEnv for WorkflowRef {
+        type Env = Environment;
+    }
+
+    /// A factory instantiating a workflow contract (e.g. `SingleToken`) per repository.
+    ///
+    /// The workflow contracts are instantiated from the code hash set by the owner, with the
+    /// `new(workflow)` constructor and the repository as salt, so that the address of the
+    /// workflow contract of a repository is deterministic. The ownership of every created
+    /// contract is then transferred to the requested owner.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct WorkflowFactory {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The code hash of the workflow contracts to instantiate, if some.
+        code_hash: Option<Hash>,
+
+        // The workflow contract per repository.
+        workflows: Mapping<HashValue, AccountId>,
+
+        // The repository per creation index.
+        repositories: Mapping<u32, HashValue>,
+
+        // The number of workflow contracts created.
+        workflows_count: u32,
+    }
+
+    /// Emitted when the workflow contract of a `repository` is created.
+    #[ink(event)]
+    pub struct WorkflowCreated {
+        #[ink(topic)]
+        repository: HashValue,
+        workflow: AccountId,
+        code_hash: Hash,
+        owner: AccountId,
+    }
+
+    impl Factory for WorkflowFactory {
+        /// Returns the workflow contract created for a given `repository`, if some.
+        #[ink(message)]
+        fn workflow_of(&self, repository: HashValue) -> Option<AccountId> {
+            self.workflow_of(repository)
+        }
+    }
+
+    impl Introspection for WorkflowFactory {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl WorkflowFactory {
+        /// Constructor that initializes the factory with the workflow `code_hash`, if some.
+        #[ink(constructor)]
+        pub fn new(code_hash: Option<Hash>) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            Self { code_hash, ..instance }
+        }
+
+        /// Create the workflow contract of a given `repository`, initialized for a given
+        /// `workflow` and owned by a given `owner`, granted all the roles.
+        ///
+        /// Constraint(s):
+        /// 1. The workflow code hash must be set.
+        /// 2. No workflow contract must be already created for the `repository`.
+        ///
+        /// A `WorkflowCreated` event is emitted.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn create(
+            &mut self,
+            repository: HashValue,
+            workflow: HashValue,
+            owner: AccountId,
+        ) -> Result<AccountId, FactoryError> {
+            let code_hash = self.code_hash.ok_or(FactoryError::CodeHashNotSet)?;
+            if self.workflows.contains(repository) {
+                return Err(FactoryError::WorkflowAlreadyCreated);
+            }
+
+            let created = build_create::<WorkflowRef>()
+                .code_hash(code_hash)
+                .gas_limit(0)
+                .endowment(0)
+                .exec_input(ExecutionInput::new(Selector::new(ink::selector_bytes!("new"))).push_arg(workflow))
+                .salt_bytes(&repository)
+                .returns::<WorkflowRef>()
+                .try_instantiate();
+            let Ok(Ok(WorkflowRef { account_id: address })) = created else {
+                return Err(FactoryError::InstantiationFailed);
+            };
+
+            let transferred = build_call::<Environment>()
+                .call(address)
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink::selector_bytes!("Ownable::transfer_ownership")))
+                        .push_arg(owner),
+                )
+                .returns::<Result<(), OwnableError>>()
+                .try_invoke();
+            if !matches!(transferred, Ok(Ok(Ok(())))) {
+                return Err(FactoryError::OwnershipTransferFailed);
+            }
+
+            // Hand the roles over, the factory renouncing its own ones once the owner holds them all
+            let factory = self.env().account_id();
+            let grants = ROLES
+                .iter()
+                .map(|role| (ink::selector_bytes!("AccessControl::grant_role"), *role, owner));
+            let renunciations = ROLES
+                .iter()
+                .map(|role| (ink::selector_bytes!("AccessControl::renounce_role"), *role, factory));
+            for (selector, role, account) in grants.chain(renunciations) {
+                let handed_over = build_call::<Environment>()
+                    .call(address)
+                    .exec_input(
+                        ExecutionInput::new(Selector::new(selector))
+                            .push_arg(role)
+                            .push_arg(Some(account)),
+                    )
+                    .returns::<Result<(), AccessControlError>>()
+                    .try_invoke();
+                if !matches!(handed_over, Ok(Ok(Ok(())))) {
+                    return Err(FactoryError::OwnershipTransferFailed);
+                }
+            }
+
+            self.workflows.insert(repository, &address);
+            self.repositories.insert(self.workflows_count, &repository);
+            self.workflows_count += 1;
+
+            self.env().emit_event(WorkflowCreated {
+                repository,
+                workflow: address,
+                code_hash,
+                owner,
+            });
+
+            Ok(address)
+        }
+
+        /// Set the code hash of the workflow contracts to instantiate, if some.
+        ///
+        /// The workflow contracts already created are not affected.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_code_hash(&mut self, code_hash: Option<Hash>) -> Result<(), FactoryError> {
+            self.code_hash = code_hash;
+            Ok(())
+        }
+
+        /// Simply returns the code hash of the workflow contracts to instantiate if some.
+        #[ink(message)]
+        pub fn get_code_hash(&self) -> Option<Hash> {
+            self.code_hash
+        }
+
+        /// Returns the workflow contract created for a given `repository`, if some.
+        #[ink(message)]
+        pub fn workflow_of(&self, repository: HashValue) -> Option<AccountId> {
+            self.workflows.get(repository)
+        }
+
+        /// Simply returns the repository of the workflow contract created at a given `index` if some.
+        #[ink(message)]
+        pub fn get_repository(&self, index: u32) -> Option<HashValue> {
+            self.repositories.get(index)
+        }
+
+        /// Simply returns the number of workflow contracts created.
+        #[ink(message)]
+        pub fn get_workflows_count(&self) -> u32 {
+            self.workflows_count
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(interface_id, interface_ids::INTROSPECTION | interface_ids::FACTORY)
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB -> workflow owner
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let factory = create_contract(None);
+            assert_eq!(Ownable::owner(&factory), Some(accounts.alice));
+            assert_eq!(factory.get_code_hash(), None);
+            assert_eq!(factory.get_workflows_count(), 0);
+            assert_eq!(factory.workflow_of([1; 32]), None);
+        }
+
+        #[ink::test]
+        fn create_requires_code_hash() {
+            let accounts = default_accounts();
+            let mut factory = create_contract(None);
+            assert_eq!(
+                factory.create([1; 32], [0; 32], accounts.bob),
+                Err(FactoryError::CodeHashNotSet)
+            );
+        }
+
+        #[ink::test]
+        fn only_owner_can_create() {
+            let accounts = default_accounts();
+            let mut factory = create_contract(Some(Hash::from([1; 32])));
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                factory.create([1; 32], [0; 32], accounts.bob),
+                Err(FactoryError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                factory.set_code_hash(None),
+                Err(FactoryError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(factory.set_code_hash(Some(Hash::from([2; 32]))), Ok(()));
+            assert_eq!(factory.get_code_hash(), Some(Hash::from([2; 32])));
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let factory = create_contract(None);
+            assert!(factory.supports_interface(interface_ids::INTROSPECTION));
+            assert!(factory.supports_interface(interface_ids::FACTORY));
+            assert!(!factory.supports_interface(interface_ids::WORKFLOW_V1));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        /// Creates a new instance of `WorkflowFactory`.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract(code_hash: Option<Hash>) -> WorkflowFactory {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            WorkflowFactory::new(code_hash)
+        }
+    }
+}
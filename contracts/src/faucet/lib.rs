@@ -0,0 +1,399 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod faucet {
+    use kudos_ink_contracts::traits::faucet::{FaucetError, *};
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use openbrush::{modifiers, traits::Storage};
+
+    use ink::storage::Mapping;
+
+    /// A permissioned test faucet for demo deployments.
+    ///
+    /// The owner funded faucet drips a small native amount to newly registered contributors,
+    /// so they can immediately pay the fees to claim their rewards. Drips are triggered by
+    /// the allowed reward contracts upon identity registration, and rate-limited both per
+    /// account and per period.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct NativeFaucet {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The amount sent per drip.
+        drip_amount: Balance,
+
+        // The minimum delay (in milliseconds) between two drips to the same account.
+        cooldown: Timestamp,
+
+        // The duration (in milliseconds) of a rate limiting period.
+        period: Timestamp,
+
+        // The maximum number of drips per period.
+        max_drips_per_period: u32,
+
+        // The start timestamp of the current period.
+        period_start: Timestamp,
+
+        // The number of drips of the current period.
+        period_drips: u32,
+
+        // The last drip timestamp per account.
+        last_drips: Mapping<AccountId, Timestamp>,
+
+        // The reward contracts allowed to trigger drips.
+        callers: Mapping<AccountId, ()>,
+    }
+
+    /// Emitted when native funds are dripped to an `account`.
+    #[ink(event)]
+    pub struct Dripped {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when a reward contract is allowed or disallowed to trigger drips.
+    #[ink(event)]
+    pub struct CallerUpdated {
+        #[ink(topic)]
+        caller: AccountId,
+        allowed: bool,
+    }
+
+    impl Faucet for NativeFaucet {
+        /// Drip the configured amount to a newly registered `account`.
+        ///
+        /// Constraint(s):
+        /// 1. The caller must be the owner or an allowed reward contract.
+        /// 2. The `account` must not have received a drip during the cooldown.
+        /// 3. The maximum number of drips of the current period must not be reached.
+        ///
+        /// A `Dripped` event is emitted.
+        #[ink(message)]
+        fn drip(&mut self, account: AccountId) -> Result<(), FaucetError> {
+            self.drip(account)
+        }
+    }
+
+    impl Introspection for NativeFaucet {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl NativeFaucet {
+        /// Constructor that initializes the faucet rate limiting configuration.
+        #[ink(constructor)]
+        pub fn new(
+            drip_amount: Balance,
+            cooldown: Timestamp,
+            period: Timestamp,
+            max_drips_per_period: u32,
+        ) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            Self {
+                drip_amount,
+                cooldown,
+                period,
+                max_drips_per_period,
+                ..instance
+            }
+        }
+
+        /// Drip the configured amount to a given `account`.
+        ///
+        /// Only callable by the owner or an allowed reward contract.
+        #[ink(message)]
+        pub fn drip(&mut self, account: AccountId) -> Result<(), FaucetError> {
+            let caller = Self::env().caller();
+            if Some(caller) != self.ownable.owner && !self.callers.contains(caller) {
+                return Err(FaucetError::CallerNotAllowed);
+            }
+
+            let now = self.env().block_timestamp();
+            if let Some(last_drip) = self.last_drips.get(account) {
+                if now < last_drip.saturating_add(self.cooldown) {
+                    return Err(FaucetError::RateLimited);
+                }
+            }
+
+            let (period_start, period_drips) = if now >= self.period_start.saturating_add(self.period) {
+                (now, 0)
+            } else {
+                (self.period_start, self.period_drips)
+            };
+            if period_drips >= self.max_drips_per_period {
+                return Err(FaucetError::DripLimitReached);
+            }
+
+            if self.env().balance() < self.drip_amount {
+                return Err(FaucetError::InsufficientFunds);
+            }
+            if self.env().transfer(account, self.drip_amount).is_err() {
+                return Err(FaucetError::TransferFailed);
+            }
+
+            self.period_start = period_start;
+            self.period_drips = period_drips + 1;
+            self.last_drips.insert(account, &now);
+
+            self.env().emit_event(Dripped {
+                account,
+                amount: self.drip_amount,
+            });
+
+            Ok(())
+        }
+
+        /// Fund the faucet.
+        #[ink(message, payable)]
+        #[modifiers(only_owner)]
+        pub fn fund(&mut self) -> Result<(), FaucetError> {
+            Ok(())
+        }
+
+        /// Withdraw `amount` from the faucet to the owner.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn withdraw(&mut self, amount: Balance) -> Result<(), FaucetError> {
+            if self.env().balance() < amount {
+                return Err(FaucetError::InsufficientFunds);
+            }
+            if self.env().transfer(Self::env().caller(), amount).is_err() {
+                return Err(FaucetError::TransferFailed);
+            }
+            Ok(())
+        }
+
+        /// Allow or disallow a reward contract to trigger drips.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_caller(&mut self, caller: AccountId, allowed: bool) -> Result<(), FaucetError> {
+            if allowed {
+                self.callers.insert(caller, &());
+            } else {
+                self.callers.remove(caller);
+            }
+
+            self.env().emit_event(CallerUpdated { caller, allowed });
+
+            Ok(())
+        }
+
+        /// Update the faucet rate limiting configuration.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_config(
+            &mut self,
+            drip_amount: Balance,
+            cooldown: Timestamp,
+            period: Timestamp,
+            max_drips_per_period: u32,
+        ) -> Result<(), FaucetError> {
+            self.drip_amount = drip_amount;
+            self.cooldown = cooldown;
+            self.period = period;
+            self.max_drips_per_period = max_drips_per_period;
+            Ok(())
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(
+                interface_id,
+                interface_ids::INTROSPECTION | interface_ids::FAUCET
+            )
+        }
+
+        /// Simply checks if a given `caller` is allowed to trigger drips.
+        #[ink(message)]
+        pub fn is_allowed_caller(&self, caller: AccountId) -> bool {
+            self.callers.contains(caller)
+        }
+
+        /// Simply returns the amount sent per drip.
+        #[ink(message)]
+        pub fn get_drip_amount(&self) -> Balance {
+            self.drip_amount
+        }
+
+        /// Simply returns the last drip timestamp of a given `account` if some.
+        #[ink(message)]
+        pub fn get_last_drip(&self, account: AccountId) -> Option<Timestamp> {
+            self.last_drips.get(account)
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB -> reward contract
+        // CHARLIE, DJANGO -> newly registered contributors
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        use ink::env::test::EmittedEvent;
+        type Event = <NativeFaucet as ::ink::reflect::ContractEventBase>::Type;
+
+        const DRIP_AMOUNT: Balance = 10;
+        const COOLDOWN: Timestamp = 1_000;
+        const PERIOD: Timestamp = 100;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let faucet = create_contract(100);
+            assert_eq!(faucet.get_drip_amount(), DRIP_AMOUNT);
+            assert_eq!(Ownable::owner(&faucet), Some(accounts.alice));
+            assert!(!faucet.is_allowed_caller(accounts.bob));
+        }
+
+        #[ink::test]
+        fn drip_works() {
+            let accounts = default_accounts();
+            let mut faucet = create_contract(100);
+            assert_eq!(faucet.set_caller(accounts.bob, true), Ok(()));
+
+            let django_initial_balance = get_balance(accounts.django);
+            set_next_caller(accounts.bob);
+            assert_eq!(faucet.drip(accounts.django), Ok(()));
+            assert_eq!(get_balance(accounts.django), django_initial_balance + DRIP_AMOUNT);
+            assert_eq!(faucet.get_last_drip(accounts.django), Some(0));
+
+            // Validate `Dripped` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            if let Some(Event::Dripped(Dripped { account, amount })) = decoded_events.last() {
+                assert_eq!(*account, accounts.django);
+                assert_eq!(*amount, DRIP_AMOUNT);
+            } else {
+                panic!("encountered unexpected event kind: expected a Dripped event")
+            }
+        }
+
+        #[ink::test]
+        fn only_allowed_caller_can_drip() {
+            let accounts = default_accounts();
+            let mut faucet = create_contract(100);
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                faucet.drip(accounts.django),
+                Err(FaucetError::CallerNotAllowed)
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(faucet.set_caller(accounts.bob, true), Ok(()));
+            assert_eq!(faucet.set_caller(accounts.bob, false), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                faucet.drip(accounts.django),
+                Err(FaucetError::CallerNotAllowed)
+            );
+            assert_eq!(
+                faucet.set_caller(accounts.bob, true),
+                Err(FaucetError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn drip_is_rate_limited_per_account() {
+            let accounts = default_accounts();
+            let mut faucet = create_contract(100);
+            assert_eq!(faucet.drip(accounts.django), Ok(()));
+            set_block_timestamp(COOLDOWN - 1);
+            assert_eq!(faucet.drip(accounts.django), Err(FaucetError::RateLimited));
+            set_block_timestamp(COOLDOWN);
+            assert_eq!(faucet.drip(accounts.django), Ok(()));
+        }
+
+        #[ink::test]
+        fn drip_is_rate_limited_per_period() {
+            let accounts = default_accounts();
+            let mut faucet = create_contract(100);
+            assert_eq!(faucet.drip(accounts.charlie), Ok(()));
+            assert_eq!(faucet.drip(accounts.django), Ok(()));
+            assert_eq!(
+                faucet.drip(accounts.eve),
+                Err(FaucetError::DripLimitReached)
+            );
+            set_block_timestamp(PERIOD);
+            assert_eq!(faucet.drip(accounts.eve), Ok(()));
+        }
+
+        #[ink::test]
+        fn drip_without_funds_fails() {
+            let accounts = default_accounts();
+            let mut faucet = create_contract(DRIP_AMOUNT - 1);
+            assert_eq!(
+                faucet.drip(accounts.django),
+                Err(FaucetError::InsufficientFunds)
+            );
+            assert_eq!(faucet.get_last_drip(accounts.django), None);
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let faucet = create_contract(100);
+            assert!(faucet.supports_interface(interface_ids::INTROSPECTION));
+            assert!(faucet.supports_interface(interface_ids::FAUCET));
+            assert!(!faucet.supports_interface(interface_ids::WORKFLOW_V1));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn contract_id() -> AccountId {
+            ink::env::test::callee::<ink::env::DefaultEnvironment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        fn set_block_timestamp(timestamp: Timestamp) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        fn set_balance(account_id: AccountId, balance: Balance) {
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account_id, balance)
+        }
+
+        fn get_balance(account: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
+                .expect("Cannot get account balance")
+        }
+
+        /// Creates a new instance of `NativeFaucet` with `initial_balance`, allowing 2 drips per period.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract(initial_balance: Balance) -> NativeFaucet {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            set_balance(contract_id(), initial_balance);
+            NativeFaucet::new(DRIP_AMOUNT, COOLDOWN, PERIOD, 2)
+        }
+
+        fn decode_events(emittend_events: Vec<EmittedEvent>) -> Vec<Event> {
+            emittend_events
+                .into_iter()
+                .map(|event| {
+                    <Event as scale::Decode>::decode(&mut &event.data[..]).expect("invalid data")
+                })
+                .collect()
+        }
+    }
+}
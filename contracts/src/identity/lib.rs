@@ -0,0 +1,209 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod identity {
+    use kudos_ink_contracts::traits::identity::{IdentityError, *};
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use kudos_ink_contracts::traits::types::HashValue;
+    use kudos_ink_contracts::traits::verifier::VerifierError;
+    use openbrush::{modifiers, traits::Storage};
+
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// A registry of the contributor identities shared by the reward contracts.
+    ///
+    /// Contributors register their identity once, optionally validated by an identity
+    /// verifier, and every reward contract set to use the registry resolves its
+    /// contributors with `account_of`.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct IdentityRegistry {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The registered account per identity.
+        identities: Mapping<HashValue, AccountId>,
+
+        // The identity verifier validating the registrations, if some.
+        identity_verifier: Option<AccountId>,
+    }
+
+    /// Emitted when an `identity` is registered for an `account`.
+    #[ink(event)]
+    pub struct IdentityRegistered {
+        #[ink(topic)]
+        identity: HashValue,
+        account: AccountId,
+    }
+
+    impl Identity for IdentityRegistry {
+        /// Returns the account a given `identity` is registered for, if some.
+        #[ink(message)]
+        fn account_of(&self, identity: HashValue) -> Option<AccountId> {
+            self.account_of(identity)
+        }
+    }
+
+    impl Introspection for IdentityRegistry {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl IdentityRegistry {
+        /// Constructor that initializes an empty registry.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            instance
+        }
+
+        /// Register a given `identity` for the caller, with a `proof` validated by the
+        /// identity verifier if set (e.g. a signature or an attestation).
+        ///
+        /// Constraint(s):
+        /// 1. The `identity` must not be already registered.
+        ///
+        /// An `IdentityRegistered` event is emitted.
+        #[ink(message)]
+        pub fn register_identity(&mut self, identity: HashValue, proof: Vec<u8>) -> Result<(), IdentityError> {
+            if self.identities.contains(identity) {
+                return Err(IdentityError::IdentityAlreadyRegistered);
+            }
+
+            let account = Self::env().caller();
+            if let Some(identity_verifier) = self.identity_verifier {
+                let verified = ink::env::call::build_call::<Environment>()
+                    .call(identity_verifier)
+                    .exec_input(
+                        ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                            ink::selector_bytes!("IdentityVerifier::verify"),
+                        ))
+                        .push_arg(identity)
+                        .push_arg(account)
+                        .push_arg(proof),
+                    )
+                    .returns::<Result<(), VerifierError>>()
+                    .try_invoke();
+                if !matches!(verified, Ok(Ok(Ok(())))) {
+                    return Err(IdentityError::IdentityNotVerified);
+                }
+            }
+
+            self.identities.insert(identity, &account);
+
+            self.env().emit_event(IdentityRegistered { identity, account });
+
+            Ok(())
+        }
+
+        /// Set the identity verifier implementing the `IdentityVerifier` trait, if some.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_identity_verifier(&mut self, identity_verifier: Option<AccountId>) -> Result<(), IdentityError> {
+            self.identity_verifier = identity_verifier;
+            Ok(())
+        }
+
+        /// Simply returns the identity verifier if some.
+        #[ink(message)]
+        pub fn get_identity_verifier(&self) -> Option<AccountId> {
+            self.identity_verifier
+        }
+
+        /// Returns the account a given `identity` is registered for, if some.
+        #[ink(message)]
+        pub fn account_of(&self, identity: HashValue) -> Option<AccountId> {
+            self.identities.get(identity)
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(interface_id, interface_ids::INTROSPECTION | interface_ids::IDENTITY)
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB, CHARLIE -> contributors
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let registry = create_contract();
+            assert_eq!(Ownable::owner(&registry), Some(accounts.alice));
+            assert_eq!(registry.account_of([1; 32]), None);
+            assert_eq!(registry.get_identity_verifier(), None);
+        }
+
+        #[ink::test]
+        fn register_identity_works() {
+            let accounts = default_accounts();
+            let mut registry = create_contract();
+            set_next_caller(accounts.bob);
+            assert_eq!(registry.register_identity([1; 32], Vec::new()), Ok(()));
+            assert_eq!(registry.account_of([1; 32]), Some(accounts.bob));
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                registry.register_identity([1; 32], Vec::new()),
+                Err(IdentityError::IdentityAlreadyRegistered)
+            );
+        }
+
+        #[ink::test]
+        fn only_owner_can_set_identity_verifier() {
+            let accounts = default_accounts();
+            let mut registry = create_contract();
+            assert_eq!(registry.set_identity_verifier(Some(accounts.django)), Ok(()));
+            assert_eq!(registry.get_identity_verifier(), Some(accounts.django));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                registry.set_identity_verifier(None),
+                Err(IdentityError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let registry = create_contract();
+            assert!(registry.supports_interface(interface_ids::INTROSPECTION));
+            assert!(registry.supports_interface(interface_ids::IDENTITY));
+            assert!(!registry.supports_interface(interface_ids::WORKFLOW_V1));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        /// Creates a new instance of `IdentityRegistry`.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract() -> IdentityRegistry {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            IdentityRegistry::new()
+        }
+    }
+}
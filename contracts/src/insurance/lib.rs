@@ -0,0 +1,450 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod insurance {
+    use kudos_ink_contracts::math::checked_mul_div;
+    use kudos_ink_contracts::traits::insurance::{InsuranceError, *};
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use kudos_ink_contracts::traits::workflow::WorkflowError;
+    use openbrush::{modifiers, traits::Storage};
+
+    use ink::storage::Mapping;
+
+    /// The basis points of 100 percent.
+    pub const BASIS_POINTS: u32 = 10_000;
+
+    /// The coverage of an approved contribution:
+    /// - the contributor the reward is owed to.
+    /// - the covered reward amount.
+    /// - whether the contributor has been compensated.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Coverage {
+        pub contributor: AccountId,
+        pub amount: Balance,
+        pub compensated: bool,
+    }
+
+    /// An insurance pool covering the rewards approved by the workflow contracts.
+    ///
+    /// The allowed workflow contracts pay a premium (out of their funds, i.e. paid by their
+    /// funders) upon every approval to cover its reward. If a workflow contract becomes
+    /// insolvent, its failed payouts are credited to the contributors instead of being paid and
+    /// recorded per contribution; such a failed payout is the on-chain evidence the covered
+    /// contributor presents to be compensated by the pool, up to the covered amount. The pool
+    /// takes the compensated payout over, so that it cannot be withdrawn from the workflow too.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct InsurancePool {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The minimum premium (in basis points of the covered amount) per approval.
+        premium_rate: u32,
+
+        // The workflow contracts allowed to be covered.
+        workflows: Mapping<AccountId, ()>,
+
+        // The coverage per workflow contract and contribution.
+        coverages: Mapping<(AccountId, u64), Coverage>,
+    }
+
+    /// Emitted when the reward of a `contribution_id` approved by a `workflow` is covered.
+    #[ink(event)]
+    pub struct Covered {
+        #[ink(topic)]
+        workflow: AccountId,
+        contribution_id: u64,
+        amount: Balance,
+        premium: Balance,
+    }
+
+    /// Emitted when a `contributor` is compensated for a failed payout of a `workflow`.
+    #[ink(event)]
+    pub struct Compensated {
+        #[ink(topic)]
+        workflow: AccountId,
+        contribution_id: u64,
+        contributor: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when a workflow contract is allowed or disallowed to be covered.
+    #[ink(event)]
+    pub struct WorkflowUpdated {
+        #[ink(topic)]
+        workflow: AccountId,
+        allowed: bool,
+    }
+
+    impl Insurance for InsurancePool {
+        /// Cover the reward `amount` of a given `contribution_id` owed to a `contributor` by the
+        /// calling workflow contract, the transferred value being the premium.
+        #[ink(message, payable)]
+        fn cover(&mut self, contribution_id: u64, contributor: AccountId, amount: Balance) -> Result<(), InsuranceError> {
+            self.cover(contribution_id, contributor, amount)
+        }
+    }
+
+    impl Introspection for InsurancePool {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl InsurancePool {
+        /// Constructor that initializes the pool with a minimum `premium_rate` (in basis points).
+        #[ink(constructor)]
+        pub fn new(premium_rate: u32) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            Self {
+                premium_rate,
+                ..instance
+            }
+        }
+
+        /// Cover the reward `amount` of a given `contribution_id` owed to a `contributor` by the
+        /// calling workflow contract, the transferred value being the premium.
+        ///
+        /// Constraint(s):
+        /// 1. The caller must be an allowed workflow contract.
+        /// 2. The premium must be at least the premium rate of the `amount`.
+        /// 3. The contribution must not be covered already.
+        ///
+        /// A `Covered` event is emitted.
+        #[ink(message, payable)]
+        pub fn cover(&mut self, contribution_id: u64, contributor: AccountId, amount: Balance) -> Result<(), InsuranceError> {
+            let workflow = self.env().caller();
+            if !self.workflows.contains(workflow) {
+                return Err(InsuranceError::CallerNotAllowed);
+            }
+            let premium = self.env().transferred_value();
+            if premium < self.premium_of(amount) {
+                return Err(InsuranceError::InsufficientPremium);
+            }
+            if self.coverages.contains((workflow, contribution_id)) {
+                return Err(InsuranceError::AlreadyCovered);
+            }
+
+            self.coverages.insert(
+                (workflow, contribution_id),
+                &Coverage {
+                    contributor,
+                    amount,
+                    compensated: false,
+                },
+            );
+
+            self.env().emit_event(Covered {
+                workflow,
+                contribution_id,
+                amount,
+                premium,
+            });
+
+            Ok(())
+        }
+
+        /// Claim the compensation of a covered `contribution_id` approved by a `workflow`, as
+        /// the contributor whose payout failed.
+        ///
+        /// The failure evidence is the native payout of the contribution the workflow contract
+        /// credited to the contributor instead of paying it (see `get_failed_payout`), the
+        /// compensation being capped to the covered amount and the pool balance. The compensated
+        /// amount is taken over from the workflow contract (see `take_over_failed_payout`),
+        /// removing it from the contributor pending payout. A `Compensated` event is emitted.
+        #[ink(message)]
+        pub fn claim_compensation(&mut self, workflow: AccountId, contribution_id: u64) -> Result<(), InsuranceError> {
+            let mut coverage = self
+                .coverages
+                .get((workflow, contribution_id))
+                .ok_or(InsuranceError::UnknownCoverage)?;
+            if coverage.contributor != self.env().caller() {
+                return Err(InsuranceError::CallerIsNotContributor);
+            }
+            if coverage.compensated {
+                return Err(InsuranceError::AlreadyCompensated);
+            }
+
+            if self.env().balance() == 0 {
+                return Err(InsuranceError::InsufficientFunds);
+            }
+            let amount = self.take_over(workflow, contribution_id, coverage.amount.min(self.env().balance()));
+            if amount == 0 {
+                return Err(InsuranceError::NoFailureEvidence);
+            }
+            if self.env().transfer(coverage.contributor, amount).is_err() {
+                return Err(InsuranceError::TransferFailed);
+            }
+
+            coverage.compensated = true;
+            self.coverages.insert((workflow, contribution_id), &coverage);
+
+            self.env().emit_event(Compensated {
+                workflow,
+                contribution_id,
+                contributor: coverage.contributor,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Fund the pool.
+        #[ink(message, payable)]
+        pub fn fund(&mut self) -> Result<(), InsuranceError> {
+            Ok(())
+        }
+
+        /// Withdraw `amount` from the pool to the owner.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn withdraw(&mut self, amount: Balance) -> Result<(), InsuranceError> {
+            if self.env().balance() < amount {
+                return Err(InsuranceError::InsufficientFunds);
+            }
+            if self.env().transfer(Self::env().caller(), amount).is_err() {
+                return Err(InsuranceError::TransferFailed);
+            }
+            Ok(())
+        }
+
+        /// Allow or disallow a workflow contract to be covered.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_workflow(&mut self, workflow: AccountId, allowed: bool) -> Result<(), InsuranceError> {
+            if allowed {
+                self.workflows.insert(workflow, &());
+            } else {
+                self.workflows.remove(workflow);
+            }
+
+            self.env().emit_event(WorkflowUpdated { workflow, allowed });
+
+            Ok(())
+        }
+
+        /// Set the minimum premium rate (in basis points of the covered amount).
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_premium_rate(&mut self, premium_rate: u32) -> Result<(), InsuranceError> {
+            if premium_rate > BASIS_POINTS {
+                return Err(InsuranceError::InvalidPremiumRate);
+            }
+            self.premium_rate = premium_rate;
+            Ok(())
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(
+                interface_id,
+                interface_ids::INTROSPECTION | interface_ids::INSURANCE
+            )
+        }
+
+        /// Simply checks if a given `workflow` contract is allowed to be covered.
+        #[ink(message)]
+        pub fn is_allowed_workflow(&self, workflow: AccountId) -> bool {
+            self.workflows.contains(workflow)
+        }
+
+        /// Simply returns the minimum premium rate (in basis points).
+        #[ink(message)]
+        pub fn get_premium_rate(&self) -> u32 {
+            self.premium_rate
+        }
+
+        /// Simply returns the coverage of a given `contribution_id` approved by a `workflow` if some.
+        #[ink(message)]
+        pub fn get_coverage(&self, workflow: AccountId, contribution_id: u64) -> Option<Coverage> {
+            self.coverages.get((workflow, contribution_id))
+        }
+
+        /// A helper function to compute the minimum premium of a covered `amount`.
+        fn premium_of(&self, amount: Balance) -> Balance {
+            checked_mul_div(amount, Balance::from(self.premium_rate), Balance::from(BASIS_POINTS))
+                .unwrap_or(Balance::MAX)
+        }
+
+        /// A helper function to take over, up to a `max` amount, the failed native payout of a
+        /// given `contribution_id` from a `workflow` contract, zero if none or on failure.
+        fn take_over(&self, workflow: AccountId, contribution_id: u64, max: Balance) -> Balance {
+            ink::env::call::build_call::<Environment>()
+                .call(workflow)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("take_over_failed_payout"),
+                    ))
+                    .push_arg(contribution_id)
+                    .push_arg(max),
+                )
+                .returns::<Result<Balance, WorkflowError>>()
+                .try_invoke()
+                .ok()
+                .and_then(Result::ok)
+                .and_then(Result::ok)
+                .unwrap_or_default()
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB -> workflow contract
+        // CHARLIE -> contributor
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        use ink::env::test::EmittedEvent;
+        type Event = <InsurancePool as ::ink::reflect::ContractEventBase>::Type;
+
+        const PREMIUM_RATE: u32 = 100;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let pool = create_contract();
+            assert_eq!(pool.get_premium_rate(), PREMIUM_RATE);
+            assert_eq!(Ownable::owner(&pool), Some(accounts.alice));
+            assert!(!pool.is_allowed_workflow(accounts.bob));
+        }
+
+        #[ink::test]
+        fn cover_works() {
+            let accounts = default_accounts();
+            let mut pool = create_contract();
+            assert_eq!(pool.set_workflow(accounts.bob, true), Ok(()));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                ink::env::pay_with_call!(pool.cover(1, accounts.charlie, 1_000), 9),
+                Err(InsuranceError::InsufficientPremium)
+            );
+            assert_eq!(
+                ink::env::pay_with_call!(pool.cover(1, accounts.charlie, 1_000), 10),
+                Ok(())
+            );
+            assert_eq!(
+                pool.get_coverage(accounts.bob, 1),
+                Some(Coverage { contributor: accounts.charlie, amount: 1_000, compensated: false })
+            );
+            assert_eq!(
+                ink::env::pay_with_call!(pool.cover(1, accounts.charlie, 1_000), 10),
+                Err(InsuranceError::AlreadyCovered)
+            );
+
+            // Validate `Covered` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            if let Some(Event::Covered(Covered { workflow, contribution_id, amount, premium })) = decoded_events.last() {
+                assert_eq!(*workflow, accounts.bob);
+                assert_eq!(*contribution_id, 1);
+                assert_eq!(*amount, 1_000);
+                assert_eq!(*premium, 10);
+            } else {
+                panic!("encountered unexpected event kind: expected a Covered event")
+            }
+        }
+
+        #[ink::test]
+        fn only_allowed_workflow_can_cover() {
+            let accounts = default_accounts();
+            let mut pool = create_contract();
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                ink::env::pay_with_call!(pool.cover(1, accounts.charlie, 1_000), 10),
+                Err(InsuranceError::CallerNotAllowed)
+            );
+            assert_eq!(
+                pool.set_workflow(accounts.bob, true),
+                Err(InsuranceError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+            assert_eq!(
+                pool.set_premium_rate(0),
+                Err(InsuranceError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(pool.set_premium_rate(BASIS_POINTS + 1), Err(InsuranceError::InvalidPremiumRate));
+        }
+
+        #[ink::test]
+        fn only_covered_contributor_can_claim_compensation() {
+            let accounts = default_accounts();
+            let mut pool = create_contract();
+            assert_eq!(
+                pool.claim_compensation(accounts.bob, 1),
+                Err(InsuranceError::UnknownCoverage)
+            );
+            assert_eq!(pool.set_workflow(accounts.bob, true), Ok(()));
+            set_next_caller(accounts.bob);
+            let _ = ink::env::pay_with_call!(pool.cover(1, accounts.charlie, 1_000), 10);
+
+            set_next_caller(accounts.django);
+            assert_eq!(
+                pool.claim_compensation(accounts.bob, 1),
+                Err(InsuranceError::CallerIsNotContributor)
+            );
+
+            // The failure evidence being read with a cross-contract call, the compensation is set directly
+            let coverage = pool.get_coverage(accounts.bob, 1).expect("contribution is covered");
+            pool.coverages
+                .insert((accounts.bob, 1), &Coverage { compensated: true, ..coverage });
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                pool.claim_compensation(accounts.bob, 1),
+                Err(InsuranceError::AlreadyCompensated)
+            );
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let pool = create_contract();
+            assert!(pool.supports_interface(interface_ids::INTROSPECTION));
+            assert!(pool.supports_interface(interface_ids::INSURANCE));
+            assert!(!pool.supports_interface(interface_ids::WORKFLOW_V1));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        /// Creates a new instance of `InsurancePool` with a 1 percent premium rate.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract() -> InsurancePool {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            InsurancePool::new(PREMIUM_RATE)
+        }
+
+        fn decode_events(emittend_events: Vec<EmittedEvent>) -> Vec<Event> {
+            emittend_events
+                .into_iter()
+                .map(|event| {
+                    <Event as scale::Decode>::decode(&mut &event.data[..]).expect("invalid data")
+                })
+                .collect()
+        }
+    }
+}
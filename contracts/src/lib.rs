@@ -1,3 +1,4 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+pub mod math;
 pub mod traits;
@@ -0,0 +1,2 @@
+//! The payout math, shared with the runtimes embedding `kudos-core`.
+pub use kudos_core::math::*;
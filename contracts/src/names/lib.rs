@@ -0,0 +1,339 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod names {
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use kudos_ink_contracts::traits::names::{NamesError, *};
+    use kudos_ink_contracts::traits::types::HashValue;
+    use openbrush::{modifiers, traits::Storage};
+
+    use ink::env::hash::{HashOutput, Sha2x256};
+    use ink::storage::Mapping;
+
+    /// A registered name.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Record {
+        /// The account allowed to update or transfer the name.
+        pub owner: AccountId,
+        /// The contract address the name resolves to.
+        pub address: AccountId,
+    }
+
+    /// A registry resolving repository slugs (e.g. `"org/repo"`) to workflow contracts.
+    ///
+    /// Names are the SHA-256 hash of the slugs, registered by the owner to prevent squatting,
+    /// then updated, transferred or unregistered by their own owner (i.e. the repository
+    /// owner). Every address is registered under one name at most, for the reverse lookup.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct NameRegistry {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The record per name.
+        records: Mapping<HashValue, Record>,
+
+        // The name per registered address.
+        reverse: Mapping<AccountId, HashValue>,
+    }
+
+    /// Emitted when a `name` is registered, updated or transferred.
+    #[ink(event)]
+    pub struct NameUpdated {
+        #[ink(topic)]
+        name: HashValue,
+        owner: AccountId,
+        address: AccountId,
+    }
+
+    /// Emitted when a `name` is unregistered.
+    #[ink(event)]
+    pub struct NameUnregistered {
+        #[ink(topic)]
+        name: HashValue,
+    }
+
+    impl Names for NameRegistry {
+        /// Returns the contract address a given `name` resolves to, if some.
+        #[ink(message)]
+        fn resolve(&self, name: HashValue) -> Option<AccountId> {
+            self.resolve(name)
+        }
+
+        /// Returns the name a given contract `address` is registered under, if some.
+        #[ink(message)]
+        fn name_of(&self, address: AccountId) -> Option<HashValue> {
+            self.name_of(address)
+        }
+    }
+
+    impl Introspection for NameRegistry {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl NameRegistry {
+        /// Constructor that initializes an empty registry.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            instance
+        }
+
+        /// Register a `name` resolving to a given `address`, owned by a given `owner`.
+        ///
+        /// Constraint(s):
+        /// 1. The `name` must not be already registered.
+        /// 2. The `address` must not be already registered under another name.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn register(&mut self, name: HashValue, owner: AccountId, address: AccountId) -> Result<(), NamesError> {
+            if self.records.contains(name) {
+                return Err(NamesError::NameAlreadyRegistered);
+            }
+            if self.reverse.contains(address) {
+                return Err(NamesError::AddressAlreadyNamed);
+            }
+
+            self.reverse.insert(address, &name);
+            self.update(name, Record { owner, address });
+
+            Ok(())
+        }
+
+        /// Update the `address` a given `name` resolves to.
+        ///
+        /// Only callable by the owner of the name.
+        #[ink(message)]
+        pub fn set_address(&mut self, name: HashValue, address: AccountId) -> Result<(), NamesError> {
+            let record = self.ensure_name_owner(name)?;
+            if record.address == address {
+                return Ok(());
+            }
+            if self.reverse.contains(address) {
+                return Err(NamesError::AddressAlreadyNamed);
+            }
+
+            self.reverse.remove(record.address);
+            self.reverse.insert(address, &name);
+            self.update(name, Record { address, ..record });
+
+            Ok(())
+        }
+
+        /// Transfer a given `name` to a `new_owner`.
+        ///
+        /// Only callable by the owner of the name.
+        #[ink(message)]
+        pub fn transfer_name(&mut self, name: HashValue, new_owner: AccountId) -> Result<(), NamesError> {
+            let record = self.ensure_name_owner(name)?;
+            self.update(
+                name,
+                Record {
+                    owner: new_owner,
+                    ..record
+                },
+            );
+
+            Ok(())
+        }
+
+        /// Unregister a given `name`, releasing its address.
+        ///
+        /// Only callable by the owner of the name.
+        #[ink(message)]
+        pub fn unregister(&mut self, name: HashValue) -> Result<(), NamesError> {
+            let record = self.ensure_name_owner(name)?;
+            self.records.remove(name);
+            self.reverse.remove(record.address);
+
+            self.env().emit_event(NameUnregistered { name });
+
+            Ok(())
+        }
+
+        /// Returns the contract address a given `name` resolves to, if some.
+        #[ink(message)]
+        pub fn resolve(&self, name: HashValue) -> Option<AccountId> {
+            self.records.get(name).map(|record| record.address)
+        }
+
+        /// Returns the name a given contract `address` is registered under, if some.
+        #[ink(message)]
+        pub fn name_of(&self, address: AccountId) -> Option<HashValue> {
+            self.reverse.get(address)
+        }
+
+        /// Simply returns the record of a given `name` if some.
+        #[ink(message)]
+        pub fn get_record(&self, name: HashValue) -> Option<Record> {
+            self.records.get(name)
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(interface_id, interface_ids::INTROSPECTION | interface_ids::NAMES)
+        }
+
+        /// A helper function to hash a repository slug (e.g. `"org/repo"`) into a name.
+        pub fn hash(slug: &str) -> HashValue {
+            let mut hash_value = <Sha2x256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Sha2x256>(slug.as_bytes(), &mut hash_value);
+            hash_value
+        }
+
+        /// A helper function to ensure the caller owns a given `name`.
+        fn ensure_name_owner(&self, name: HashValue) -> Result<Record, NamesError> {
+            let record = self.records.get(name).ok_or(NamesError::UnknownName)?;
+            if record.owner != self.env().caller() {
+                return Err(NamesError::CallerIsNotNameOwner);
+            }
+            Ok(record)
+        }
+
+        /// A helper function to store the `record` of a given `name`.
+        fn update(&mut self, name: HashValue, record: Record) {
+            self.records.insert(name, &record);
+
+            self.env().emit_event(NameUpdated {
+                name,
+                owner: record.owner,
+                address: record.address,
+            });
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB -> name owner
+        // CHARLIE, DJANGO -> workflow contracts
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let registry = create_contract();
+            assert_eq!(Ownable::owner(&registry), Some(accounts.alice));
+            assert_eq!(registry.resolve(NameRegistry::hash("kudos-ink/contracts")), None);
+        }
+
+        #[ink::test]
+        fn register_and_resolve_works() {
+            let accounts = default_accounts();
+            let mut registry = create_contract();
+            let name = NameRegistry::hash("kudos-ink/contracts");
+            assert_eq!(registry.register(name, accounts.bob, accounts.charlie), Ok(()));
+            assert_eq!(registry.resolve(name), Some(accounts.charlie));
+            assert_eq!(registry.name_of(accounts.charlie), Some(name));
+            assert_eq!(
+                registry.register(name, accounts.bob, accounts.django),
+                Err(NamesError::NameAlreadyRegistered)
+            );
+            assert_eq!(
+                registry.register(NameRegistry::hash("kudos-ink/portal"), accounts.bob, accounts.charlie),
+                Err(NamesError::AddressAlreadyNamed)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                registry.register(NameRegistry::hash("kudos-ink/portal"), accounts.bob, accounts.django),
+                Err(NamesError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn only_name_owner_can_update() {
+            let accounts = default_accounts();
+            let mut registry = create_contract();
+            let name = NameRegistry::hash("kudos-ink/contracts");
+            let _ = registry.register(name, accounts.bob, accounts.charlie);
+            assert_eq!(
+                registry.set_address(name, accounts.django),
+                Err(NamesError::CallerIsNotNameOwner)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(registry.set_address(name, accounts.django), Ok(()));
+            assert_eq!(registry.resolve(name), Some(accounts.django));
+            assert_eq!(registry.name_of(accounts.charlie), None);
+            assert_eq!(registry.name_of(accounts.django), Some(name));
+
+            assert_eq!(registry.transfer_name(name, accounts.eve), Ok(()));
+            assert_eq!(
+                registry.get_record(name),
+                Some(Record { owner: accounts.eve, address: accounts.django })
+            );
+            assert_eq!(
+                registry.transfer_name(name, accounts.bob),
+                Err(NamesError::CallerIsNotNameOwner)
+            );
+            assert_eq!(
+                registry.set_address([0; 32], accounts.bob),
+                Err(NamesError::UnknownName)
+            );
+        }
+
+        #[ink::test]
+        fn only_name_owner_can_unregister() {
+            let accounts = default_accounts();
+            let mut registry = create_contract();
+            let name = NameRegistry::hash("kudos-ink/contracts");
+            let _ = registry.register(name, accounts.bob, accounts.charlie);
+            assert_eq!(registry.unregister(name), Err(NamesError::CallerIsNotNameOwner));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(registry.unregister(name), Ok(()));
+            assert_eq!(registry.resolve(name), None);
+            assert_eq!(registry.name_of(accounts.charlie), None);
+            assert_eq!(registry.unregister(name), Err(NamesError::UnknownName));
+
+            // The name and address can be registered again
+            set_next_caller(accounts.alice);
+            assert_eq!(registry.register(name, accounts.django, accounts.charlie), Ok(()));
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let registry = create_contract();
+            assert!(registry.supports_interface(interface_ids::INTROSPECTION));
+            assert!(registry.supports_interface(interface_ids::NAMES));
+            assert!(!registry.supports_interface(interface_ids::WORKFLOW_V1));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        /// Creates a new instance of `NameRegistry`.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract() -> NameRegistry {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            NameRegistry::new()
+        }
+    }
+}
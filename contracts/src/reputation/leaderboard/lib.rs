@@ -0,0 +1,286 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod leaderboard {
+    use kudos_ink_contracts::traits::reputation::{ReputationError, *};
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use kudos_ink_contracts::traits::types::HashValue;
+    use openbrush::{modifiers, traits::Storage};
+
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// The maximum number of ranked identities.
+    pub const MAX_RANKED: usize = 50;
+
+    /// A reputation leaderboard shared by the reward contracts.
+    ///
+    /// Reputation points are reported by the allowed reporters (e.g. reward contracts or
+    /// workflow bots), and the top `MAX_RANKED` identities are ranked.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Leaderboard {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The reputation points per contributor identity.
+        reputations: Mapping<HashValue, u64>,
+
+        // The ranked identities, sorted by decreasing reputation.
+        ranking: Vec<(HashValue, u64)>,
+
+        // The accounts allowed to report reputation points.
+        reporters: Mapping<AccountId, ()>,
+    }
+
+    /// Emitted when reputation points are added to an `identity`.
+    #[ink(event)]
+    pub struct ReputationAdded {
+        identity: HashValue,
+        points: u64,
+        reputation: u64,
+    }
+
+    /// Emitted when a reporter is allowed or disallowed.
+    #[ink(event)]
+    pub struct ReporterUpdated {
+        #[ink(topic)]
+        reporter: AccountId,
+        allowed: bool,
+    }
+
+    impl Reputation for Leaderboard {
+        /// Returns the reputation points of a given contributor `identity`.
+        #[ink(message)]
+        fn reputation_of(&self, identity: HashValue) -> u64 {
+            self.reputation_of(identity)
+        }
+
+        /// Returns the rank (starting at 1) of a given contributor `identity`, if ranked.
+        #[ink(message)]
+        fn rank_of(&self, identity: HashValue) -> Option<u32> {
+            self.rank_of(identity)
+        }
+    }
+
+    impl Introspection for Leaderboard {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl Leaderboard {
+        /// Constructor that initializes an empty leaderboard.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            instance
+        }
+
+        /// Add reputation `points` to a given contributor `identity`.
+        ///
+        /// Only callable by the owner or an allowed reporter.
+        #[ink(message)]
+        pub fn add_reputation(&mut self, identity: HashValue, points: u64) -> Result<(), ReputationError> {
+            let caller = Self::env().caller();
+            if Some(caller) != self.ownable.owner && !self.reporters.contains(caller) {
+                return Err(ReputationError::CallerNotAllowed);
+            }
+
+            let reputation = self
+                .reputation_of(identity)
+                .checked_add(points)
+                .ok_or(ReputationError::Overflow)?;
+            self.reputations.insert(identity, &reputation);
+            self.update_ranking(identity, reputation);
+
+            self.env().emit_event(ReputationAdded {
+                identity,
+                points,
+                reputation,
+            });
+
+            Ok(())
+        }
+
+        /// Allow or disallow a `reporter` to add reputation points.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_reporter(&mut self, reporter: AccountId, allowed: bool) -> Result<(), ReputationError> {
+            if allowed {
+                self.reporters.insert(reporter, &());
+            } else {
+                self.reporters.remove(reporter);
+            }
+
+            self.env().emit_event(ReporterUpdated { reporter, allowed });
+
+            Ok(())
+        }
+
+        /// Returns the reputation points of a given contributor `identity`.
+        #[ink(message)]
+        pub fn reputation_of(&self, identity: HashValue) -> u64 {
+            self.reputations.get(identity).unwrap_or(0)
+        }
+
+        /// Returns the rank (starting at 1) of a given contributor `identity`, if ranked.
+        #[ink(message)]
+        pub fn rank_of(&self, identity: HashValue) -> Option<u32> {
+            self.ranking
+                .iter()
+                .position(|(ranked, _)| *ranked == identity)
+                .map(|position| position as u32 + 1)
+        }
+
+        /// Simply returns the ranked identities with their reputation, sorted by decreasing reputation.
+        #[ink(message)]
+        pub fn get_ranking(&self) -> Vec<(HashValue, u64)> {
+            self.ranking.clone()
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(
+                interface_id,
+                interface_ids::INTROSPECTION | interface_ids::REPUTATION
+            )
+        }
+
+        /// Simply checks if a given `reporter` is allowed to add reputation points.
+        #[ink(message)]
+        pub fn is_reporter(&self, reporter: AccountId) -> bool {
+            self.reporters.contains(reporter)
+        }
+
+        /// A helper function to move an `identity` to its rank given its new `reputation`.
+        ///
+        /// Identities with the same reputation are ranked by seniority.
+        fn update_ranking(&mut self, identity: HashValue, reputation: u64) {
+            self.ranking.retain(|(ranked, _)| *ranked != identity);
+            let position = self
+                .ranking
+                .iter()
+                .position(|(_, ranked_reputation)| *ranked_reputation < reputation)
+                .unwrap_or(self.ranking.len());
+            if position < MAX_RANKED {
+                self.ranking.insert(position, (identity, reputation));
+                self.ranking.truncate(MAX_RANKED);
+            }
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB -> reporter
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let leaderboard = create_contract();
+            assert_eq!(Ownable::owner(&leaderboard), Some(accounts.alice));
+            assert_eq!(leaderboard.get_ranking(), Vec::new());
+        }
+
+        #[ink::test]
+        fn add_reputation_works() {
+            let mut leaderboard = create_contract();
+            let bobby = hash("bobby");
+            let charly = hash("charly");
+            assert_eq!(leaderboard.add_reputation(bobby, 2), Ok(()));
+            assert_eq!(leaderboard.add_reputation(charly, 2), Ok(()));
+            assert_eq!(leaderboard.reputation_of(bobby), 2);
+            assert_eq!(leaderboard.rank_of(bobby), Some(1));
+            assert_eq!(leaderboard.rank_of(charly), Some(2));
+
+            assert_eq!(leaderboard.add_reputation(charly, 1), Ok(()));
+            assert_eq!(leaderboard.rank_of(charly), Some(1));
+            assert_eq!(leaderboard.rank_of(bobby), Some(2));
+            assert_eq!(leaderboard.get_ranking(), vec![(charly, 3), (bobby, 2)]);
+            assert_eq!(leaderboard.rank_of(hash("unknown")), None);
+        }
+
+        #[ink::test]
+        fn only_allowed_reporter_can_add_reputation() {
+            let accounts = default_accounts();
+            let mut leaderboard = create_contract();
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                leaderboard.add_reputation(hash("bobby"), 1),
+                Err(ReputationError::CallerNotAllowed)
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(leaderboard.set_reporter(accounts.bob, true), Ok(()));
+            assert!(leaderboard.is_reporter(accounts.bob));
+            set_next_caller(accounts.bob);
+            assert_eq!(leaderboard.add_reputation(hash("bobby"), 1), Ok(()));
+            assert_eq!(
+                leaderboard.set_reporter(accounts.charlie, true),
+                Err(ReputationError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn ranking_is_bounded() {
+            let mut leaderboard = create_contract();
+            for index in 0..=MAX_RANKED as u8 {
+                let _ = leaderboard.add_reputation([index; 32], 100 - u64::from(index));
+            }
+            assert_eq!(leaderboard.get_ranking().len(), MAX_RANKED);
+            assert_eq!(leaderboard.rank_of([MAX_RANKED as u8; 32]), None);
+            assert_eq!(leaderboard.reputation_of([MAX_RANKED as u8; 32]), 100 - MAX_RANKED as u64);
+
+            // An unranked identity enters the ranking once its reputation is high enough
+            let _ = leaderboard.add_reputation([MAX_RANKED as u8; 32], 100);
+            assert_eq!(leaderboard.rank_of([MAX_RANKED as u8; 32]), Some(1));
+            assert_eq!(leaderboard.get_ranking().len(), MAX_RANKED);
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let leaderboard = create_contract();
+            assert!(leaderboard.supports_interface(interface_ids::INTROSPECTION));
+            assert!(leaderboard.supports_interface(interface_ids::REPUTATION));
+            assert!(!leaderboard.supports_interface(interface_ids::WORKFLOW_V1));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        fn hash(input: &str) -> HashValue {
+            let mut output = <ink::env::hash::Sha2x256 as ink::env::hash::HashOutput>::Type::default();
+            ink::env::hash_bytes::<ink::env::hash::Sha2x256>(input.as_bytes(), &mut output);
+            output
+        }
+
+        /// Creates a new instance of `Leaderboard`.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract() -> Leaderboard {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            Leaderboard::new()
+        }
+    }
+}
@@ -1,603 +1,12736 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-#[openbrush::implementation(Ownable)]
+#[openbrush::implementation(Ownable, Pausable, AccessControl)]
 #[openbrush::contract]
 pub mod single_token {
+    use kudos_ink_contracts::math::{checked_mul_div, split_pro_rata};
+    use kudos_ink_contracts::traits::faucet::FaucetError;
+    use kudos_ink_contracts::traits::insurance::InsuranceError;
+    use kudos_ink_contracts::traits::lifecycle::{LifecycleHookError, Transition};
+    use kudos_ink_contracts::traits::verifier::VerifierError;
+    use kudos_ink_contracts::traits::vesting::VestingError;
+    use kudos_ink_contracts::traits::yield_adapter::YieldAdapterError;
     use kudos_ink_contracts::traits::workflow::{WorkflowError, *};
-    use kudos_ink_contracts::traits::types::HashValue;
-    use openbrush::{modifiers, traits::Storage};
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use kudos_ink_contracts::traits::types::{Asset, HashValue, Identity, Platform, SignatureValue};
+    use openbrush::{
+        contracts::traits::{
+            psp22::{PSP22Error, PSP22ReceiverError},
+            psp34::{Id, PSP34Error},
+        },
+        modifiers,
+        traits::Storage,
+    };
 
-    use ink::env::hash::{HashOutput, Sha2x256};
+    use ink::env::hash::{Blake2x256, HashOutput, Sha2x256};
+    use ink::prelude::{string::String, vec::Vec};
     use ink::storage::Mapping;
 
-    /// A Contribution is represented by:
-    /// - a unique id.
-    /// - the contributor; allowed to claim the reward.
+    pub use kudos_core::{cursor::MAX_CHUNK_SIZE, ContributorStats, Cursor, RewardAmount, Streak};
+
+    /// An approved contribution, see [`kudos_core::Contribution`].
+    pub type Contribution = kudos_core::Contribution<AccountId>;
+
+    /// The basis points denominator, `10_000` basis points being a 1x multiplier.
+    pub const BASIS_POINTS: u32 = 10_000;
+
+    /// The maximum length (in bytes) of a claim memo.
+    pub const MAX_MEMO_LENGTH: usize = 256;
+
+    /// The maximum number of assets in the payout basket.
+    pub const MAX_BASKET_ASSETS: usize = 4;
+
+    /// The maximum number of recipient accounts a claim can be split across.
+    pub const MAX_SPLITS: usize = 4;
+
+    /// The maximum number of tags of a contribution template.
+    pub const MAX_TEMPLATE_TAGS: usize = 8;
+
+    /// The maximum number of pre-approved contributions awaiting identity registration.
+    pub const MAX_UNBOUND_APPROVALS: usize = 100;
+
+    /// The maximum number of messages a session account can be allowed to call.
+    pub const MAX_SESSION_SELECTORS: usize = 8;
+
+    /// The maximum number of contributions claimed at once.
+    pub const MAX_CLAIM_BATCH: usize = 16;
+
+    /// The maximum number of identities and contributions imported at once.
+    pub const MAX_IMPORT_BATCH: usize = 100;
+
+    /// The maximum number of auditors performing the spot checks.
+    pub const MAX_AUDITORS: usize = 8;
+
+    /// The maximum number of metrics of the payout formula.
+    pub const MAX_FORMULA_METRICS: usize = 4;
+
+    /// The maximum number of contributions an earmarked deposit can back, and of earmarked
+    /// deposits backing a contribution.
+    pub const MAX_EARMARKS: usize = 16;
+
+    /// The default minimum delay (in milliseconds) before a queued parameter change can be executed.
+    pub const DEFAULT_CHANGE_DELAY: Timestamp = 2 * 24 * 60 * 60 * 1000;
+
+    /// The duration (in milliseconds) of an accounting epoch, i.e. 30 days.
+    pub const EPOCH_DURATION: Timestamp = 30 * 24 * 60 * 60 * 1000;
+
+    /// The duration (in milliseconds) of a day late, used for the late payout compensation.
+    pub const DAY: Timestamp = 24 * 60 * 60 * 1000;
+
+    /// The export status code of an approval pending finality, a dispute window, a challenge
+    /// or a spot check.
+    pub const STATUS_PENDING: u8 = 0;
+
+    /// The export status code of a claimable approval.
+    pub const STATUS_CLAIMABLE: u8 = 1;
+
+    /// The export status code of a claimed approval.
+    pub const STATUS_CLAIMED: u8 = 2;
+
+    /// The export asset code of a reward paid in the native currency.
+    pub const ASSET_CODE_NATIVE: u8 = 0;
+
+    /// The export asset code of a reward paid according to the payout basket.
+    pub const ASSET_CODE_BASKET: u8 = 1;
+
+    /// The storage key prefix of the `benchmark_probe` records, outside of the contract storage layout.
+    #[cfg(feature = "benchmark")]
+    const BENCHMARK_KEY: u32 = ink::selector_id!("benchmark_probe");
+
+    /// The version of the storage layout, bumped by every upgrade requiring a migration.
+    pub const STORAGE_VERSION: u32 = 1;
+
+    /// The message selectors per class, other messages (e.g. `fund` or `register_identity`)
+    /// being permissionless and unclassified.
+    const MESSAGE_CLASSES: &[(MessageClass, &[[u8; 4]])] = &[
+        (
+            MessageClass::Query,
+            &[
+                ink::selector_bytes!("can_claim"),
+                ink::selector_bytes!("describe_error"),
+                ink::selector_bytes!("export_contributions"),
+                ink::selector_bytes!("get_account"),
+                ink::selector_bytes!("get_approved_count"),
+                ink::selector_bytes!("get_attestor_key"),
+                ink::selector_bytes!("get_available_balance"),
+                ink::selector_bytes!("get_arbitrator"),
+                ink::selector_bytes!("get_attestation"),
+                ink::selector_bytes!("get_backup"),
+                ink::selector_bytes!("get_basket"),
+                ink::selector_bytes!("get_boost"),
+                ink::selector_bytes!("get_challenge"),
+                ink::selector_bytes!("get_change_delay"),
+                ink::selector_bytes!("get_circuit_breaker"),
+                ink::selector_bytes!("get_claim_cooldown"),
+                ink::selector_bytes!("get_claim_deadline"),
+                ink::selector_bytes!("get_claim_window"),
+                ink::selector_bytes!("get_claims_cap"),
+                ink::selector_bytes!("get_claims_count"),
+                ink::selector_bytes!("get_closure_statement"),
+                ink::selector_bytes!("get_contribution"),
+                ink::selector_bytes!("get_contributions"),
+                ink::selector_bytes!("get_contributions_by_contributor"),
+                ink::selector_bytes!("get_credential"),
+                ink::selector_bytes!("get_current_epoch"),
+                ink::selector_bytes!("get_deposit"),
+                ink::selector_bytes!("get_disputable_until"),
+                ink::selector_bytes!("get_dust_policy"),
+                ink::selector_bytes!("get_earmark"),
+                ink::selector_bytes!("get_difficulty_reward"),
+                ink::selector_bytes!("get_earmarked"),
+                ink::selector_bytes!("get_earmarks_of"),
+                ink::selector_bytes!("get_escalated_reward"),
+                ink::selector_bytes!("get_escalation"),
+                ink::selector_bytes!("get_escrow"),
+                ink::selector_bytes!("get_identities"),
+                ink::selector_bytes!("get_identity_count"),
+                ink::selector_bytes!("get_identity_of"),
+                ink::selector_bytes!("get_identity_registry"),
+                ink::selector_bytes!("get_reservation"),
+                ink::selector_bytes!("get_reserved"),
+                ink::selector_bytes!("get_faucet"),
+                ink::selector_bytes!("get_free_balance"),
+                ink::selector_bytes!("get_finality_depth"),
+                ink::selector_bytes!("get_finalizable_at"),
+                ink::selector_bytes!("get_first_claim_bonus"),
+                ink::selector_bytes!("get_first_contribution_multiplier"),
+                ink::selector_bytes!("get_guardian"),
+                ink::selector_bytes!("get_heartbeat_period"),
+                ink::selector_bytes!("get_identity_validity"),
+                ink::selector_bytes!("get_identity_verifier"),
+                ink::selector_bytes!("get_imported_reputation"),
+                ink::selector_bytes!("get_insurance"),
+                ink::selector_bytes!("get_keeper_bounty"),
+                ink::selector_bytes!("get_last_claim"),
+                ink::selector_bytes!("get_offered_reward"),
+                ink::selector_bytes!("get_lifecycle_hook"),
+                ink::selector_bytes!("get_last_heartbeat"),
+                ink::selector_bytes!("get_late_compensation"),
+                ink::selector_bytes!("get_late_compensation_of"),
+                ink::selector_bytes!("get_low_funds_threshold"),
+                ink::selector_bytes!("get_formula_reward"),
+                ink::selector_bytes!("get_oracle_key"),
+                ink::selector_bytes!("get_outstanding_obligations"),
+                ink::selector_bytes!("get_outflow"),
+                ink::selector_bytes!("get_parked"),
+                ink::selector_bytes!("get_payout_formula"),
+                ink::selector_bytes!("get_pending_change"),
+                ink::selector_bytes!("get_pending_owner"),
+                ink::selector_bytes!("get_pending_payout"),
+                ink::selector_bytes!("get_failed_payout"),
+                ink::selector_bytes!("get_platform"),
+                ink::selector_bytes!("get_platform_key"),
+                ink::selector_bytes!("get_proposal"),
+                ink::selector_bytes!("get_proxy"),
+                ink::selector_bytes!("get_refund"),
+                ink::selector_bytes!("get_remark_challenge"),
+                ink::selector_bytes!("get_remark_proof"),
+                ink::selector_bytes!("get_reputation"),
+                ink::selector_bytes!("get_reputation_source"),
+                ink::selector_bytes!("get_reward"),
+                ink::selector_bytes!("get_run"),
+                ink::selector_bytes!("get_run_limits"),
+                ink::selector_bytes!("get_session"),
+                ink::selector_bytes!("get_session_spent"),
+                ink::selector_bytes!("get_spot_check"),
+                ink::selector_bytes!("get_spot_checks"),
+                ink::selector_bytes!("get_stats"),
+                ink::selector_bytes!("get_streak"),
+                ink::selector_bytes!("get_tax_reference"),
+                ink::selector_bytes!("get_template"),
+                ink::selector_bytes!("get_tier_min_reputation"),
+                ink::selector_bytes!("get_tier_reward"),
+                ink::selector_bytes!("get_total_funded"),
+                ink::selector_bytes!("get_trials"),
+                ink::selector_bytes!("get_trials_count"),
+                ink::selector_bytes!("get_unbinding_eta"),
+                ink::selector_bytes!("get_unattributed_funds"),
+                ink::selector_bytes!("get_verifier"),
+                ink::selector_bytes!("get_version"),
+                ink::selector_bytes!("get_vesting"),
+                ink::selector_bytes!("get_voted_reward"),
+                ink::selector_bytes!("get_voting"),
+                ink::selector_bytes!("get_workflow"),
+                ink::selector_bytes!("get_yield_adapter"),
+                ink::selector_bytes!("get_yield_policy"),
+                ink::selector_bytes!("identity_is_expired"),
+                ink::selector_bytes!("is_allowed_asset"),
+                ink::selector_bytes!("is_awaiting_registration"),
+                ink::selector_bytes!("is_canonical_identities"),
+                ink::selector_bytes!("is_compliance_mode"),
+                ink::selector_bytes!("is_multiple_identities"),
+                ink::selector_bytes!("is_issue_closed"),
+                ink::selector_bytes!("is_separation_of_duties"),
+                ink::selector_bytes!("is_signer"),
+                ink::selector_bytes!("is_solvent"),
+                ink::selector_bytes!("is_strict_funding"),
+                ink::selector_bytes!("list_unbound_approvals"),
+                ink::selector_bytes!("message_class"),
+                ink::selector_bytes!("person_of"),
+                ink::selector_bytes!("statement_of"),
+                ink::selector_bytes!("supports_interface"),
+                ink::selector_bytes!("verify_no_double_payment"),
+            ],
+        ),
+        (
+            MessageClass::Claim,
+            &[
+                ink::selector_bytes!("claim"),
+                ink::selector_bytes!("claim_many"),
+                ink::selector_bytes!("claim_split"),
+                ink::selector_bytes!("claim_with_boost"),
+                ink::selector_bytes!("claim_with_memo"),
+                ink::selector_bytes!("claim_with_ratio"),
+                ink::selector_bytes!("proxy_claim"),
+                ink::selector_bytes!("report_late_payout"),
+                ink::selector_bytes!("withdraw_pending"),
+                ink::selector_bytes!("withdraw_pending_to"),
+            ],
+        ),
+        (
+            MessageClass::Admin,
+            &[
+                ink::selector_bytes!("accept_ownership"),
+                ink::selector_bytes!("add_signer"),
+                ink::selector_bytes!("approve"),
+                ink::selector_bytes!("approve_from_template"),
+                ink::selector_bytes!("approve_in_run"),
+                ink::selector_bytes!("approve_platform"),
+                ink::selector_bytes!("approve_trial"),
+                ink::selector_bytes!("approve_with_closure"),
+                ink::selector_bytes!("approve_with_difficulty"),
+                ink::selector_bytes!("approve_with_metadata"),
+                ink::selector_bytes!("approve_with_metrics"),
+                ink::selector_bytes!("authorize_session"),
+                ink::selector_bytes!("cancel_change"),
+                ink::selector_bytes!("close_issue"),
+                ink::selector_bytes!("confirm_remark_proof"),
+                ink::selector_bytes!("create_template"),
+                ink::selector_bytes!("execute_change"),
+                ink::selector_bytes!("expire"),
+                ink::selector_bytes!("force_unregister_identity"),
+                ink::selector_bytes!("heartbeat"),
+                ink::selector_bytes!("import_state"),
+                ink::selector_bytes!("link_identity"),
+                ink::selector_bytes!("migrate"),
+                ink::selector_bytes!("open_issue"),
+                ink::selector_bytes!("park"),
+                ink::selector_bytes!("pause"),
+                ink::selector_bytes!("pre_approve"),
+                ink::selector_bytes!("propose"),
+                ink::selector_bytes!("queue_change"),
+                ink::selector_bytes!("queue_identity_unbinding"),
+                ink::selector_bytes!("register_credential_signature"),
+                ink::selector_bytes!("remove_signer"),
+                ink::selector_bytes!("remove_template"),
+                ink::selector_bytes!("revoke_verifier"),
+                ink::selector_bytes!("rotate_signer"),
+                ink::selector_bytes!("set_allowed_asset"),
+                ink::selector_bytes!("set_backup"),
+                ink::selector_bytes!("set_canonical_identities"),
+                ink::selector_bytes!("set_change_delay"),
+                ink::selector_bytes!("set_circuit_breaker"),
+                ink::selector_bytes!("set_claims_cap"),
+                ink::selector_bytes!("set_compliance_mode"),
+                ink::selector_bytes!("set_cooldown_exemption"),
+                ink::selector_bytes!("set_dust_policy"),
+                ink::selector_bytes!("set_first_claim_bonus"),
+                ink::selector_bytes!("set_escalation"),
+                ink::selector_bytes!("set_faucet"),
+                ink::selector_bytes!("set_finality_depth"),
+                ink::selector_bytes!("set_guardian"),
+                ink::selector_bytes!("set_late_compensation"),
+                ink::selector_bytes!("set_low_funds_threshold"),
+                ink::selector_bytes!("set_multiple_identities"),
+                ink::selector_bytes!("set_lifecycle_hook"),
+                ink::selector_bytes!("set_payout_formula"),
+                ink::selector_bytes!("set_reputation"),
+                ink::selector_bytes!("set_reputation_source"),
+                ink::selector_bytes!("set_run_limits"),
+                ink::selector_bytes!("set_separation_of_duties"),
+                ink::selector_bytes!("set_spot_checks"),
+                ink::selector_bytes!("set_strict_funding"),
+                ink::selector_bytes!("set_tier_min_reputation"),
+                ink::selector_bytes!("set_trials"),
+                ink::selector_bytes!("set_voting"),
+                ink::selector_bytes!("set_yield_policy"),
+                ink::selector_bytes!("terminate"),
+                ink::selector_bytes!("transfer_ownership_to_contract"),
+                ink::selector_bytes!("unpark"),
+                ink::selector_bytes!("unpause"),
+            ],
+        ),
+    ];
+
+    /// An owner defined template for recurring contributions (e.g. release notes, triage rotations):
+    /// - the contribution kind.
+    /// - the contribution tier.
+    /// - the duration (in milliseconds) the reward can be claimed for after approval, if some.
+    /// - the contribution tags.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Template {
+        kind: String,
+        tier: u8,
+        expiry: Option<Timestamp>,
+        tags: Vec<String>,
+    }
+
+    /// A proposed contribution, whose tier is voted by the badge holders until `ends_at`,
+    /// along with the votes per tier.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Proposal {
+        pub ends_at: Timestamp,
+        pub tallies: Vec<(u8, u32)>,
+    }
+
+    /// The coarse classification of the messages, from the least to the most privileged.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub enum MessageClass {
+        /// Read-only messages.
+        Query,
+        /// Messages claiming or withdrawing the rewards of the caller.
+        Claim,
+        /// Messages restricted to the owner.
+        Admin,
+    }
+
+    /// The claim proof a platform signature is issued for:
+    /// - the paying contract.
+    /// - the contribution and its contributor.
+    /// - the reward amount.
     #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
     #[cfg_attr(
         feature = "std",
         derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
     )]
-    pub struct Contribution {
-        // The unique contribution ID (e.g. the Github issue #id).
-        id: u64,
-        // The contributor public key (e.g. extract from the `identities` mapping).
+    pub struct CredentialPayload {
+        contract: AccountId,
+        contribution_id: u64,
         contributor: AccountId,
-        is_reward_claimed: bool,
+        reward: Balance,
     }
 
-    #[ink(storage)]
-    #[derive(Default, Storage)]
-    pub struct SingleToken {
-        #[storage_field]
-        ownable: ownable::Data,
+    /// A claim receipt contributors can present off-chain to prove a payout.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Credential {
+        payload: CredentialPayload,
+        // The block number at which the reward was claimed.
+        claimed_at: BlockNumber,
+        // The platform signature over the Blake2x256 hash of the SCALE encoded `payload`, if registered.
+        signature: Option<Vec<u8>>,
+    }
 
-        // The registered workflow.
-        // It is usually represented with the SHA hash of the workflow file (e.g. Github Workflow file).
-        workflow: HashValue,
+    /// A self-audit report of the payouts of a contribution, recomputed from the contract state:
+    /// - whether the contribution is claimed.
+    /// - the reward paid according to the claim receipt, if some.
+    /// - the native reward still reserved, i.e. that can still be paid.
+    /// - whether at most one payout can ever leave for the contribution.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PaymentAudit {
+        pub claimed: bool,
+        pub paid: Option<Balance>,
+        pub reserved: Balance,
+        pub holds: bool,
+    }
 
-        // The contribution reward amount.
-        reward: Balance,
+    /// The description of a `WorkflowError`:
+    /// - the name of the error (e.g. `InsufficientFunds`).
+    /// - what the error means.
+    /// - how to remediate it (e.g. to show contributors when claims fail).
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ErrorDescription {
+        pub name: String,
+        pub description: String,
+        pub hint: String,
+    }
 
-        // The approved `Contribution`.
-        contribution: Option<Contribution>,
+    /// A contribution flattened for the reporting pipeline (e.g. spreadsheets), as
+    /// `(id, status_code, contributor, amount, asset_code, approved_at, claimed_at)`:
+    /// - the status code (`STATUS_PENDING`, `STATUS_CLAIMABLE` or `STATUS_CLAIMED`).
+    /// - the reward amount, the paid one once claimed.
+    /// - the asset code (`ASSET_CODE_NATIVE` or `ASSET_CODE_BASKET`).
+    /// - the block numbers of the approval and of the claim, zero if unknown or unclaimed.
+    pub type ExportRecord = (u64, u8, AccountId, Balance, u8, BlockNumber, BlockNumber);
 
-        // The registered contributors ids database.
-        // The key refers to a registered and unique contribution ID (e.g. the Github issue #id).
-        // The value is the associated registered `AccountId` (public key) of the contributor.
-        identities: Mapping<HashValue, AccountId>, // HashValue refers to the contributo id (e.g. github ID)
+    /// The share (in basis points) of the reward paid with an asset.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct BasketShare {
+        asset: Asset,
+        share: u32,
     }
 
-    /// Emitted when an `identity` is registered by an aspiring contributor.
-    #[ink(event)]
-    pub struct IdentityRegistered {
+    /// A deposit earmarked by a funder for specific contributions (i.e. issues):
+    /// - the funder.
+    /// - the contribution ids the deposit may back.
+    /// - the amount not allocated to an approved contribution yet.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Earmark {
+        pub funder: AccountId,
+        pub contribution_ids: Vec<u64>,
+        pub remaining: Balance,
+    }
+
+    /// The refund state of a terminated contract:
+    /// - the free balance to be refunded pro-rata to the funders.
+    /// - the total amount funded when terminating, the refunded earmarks excluded.
+    /// - the index of the next funder to refund.
+    /// - the amount allocated to the refunded funders, rounded down.
+    /// - the earmarked balance to be refunded to the funders of the earmarks.
+    /// - the index of the next earmark to refund.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Refund {
+        pool: Balance,
+        total_funded: Balance,
+        cursor: Cursor,
+        allocated: Balance,
+        earmarks: Balance,
+        earmark_cursor: Cursor,
+    }
+
+    /// The accounting statement of a funder for a given epoch:
+    /// - the amount deposited by the funder.
+    /// - the rewards paid out, attributed pro-rata to the funder deposits.
+    /// - the amount refunded to the funder.
+    /// - the fees charged to the funder, none being charged by this contract.
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Statement {
+        deposited: Balance,
+        allocated: Balance,
+        refunded: Balance,
+        fees: Balance,
+    }
+
+    /// The sensitive parameters, only updatable through the timelock queue.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub enum Parameter {
+        PlatformKey,
+        FirstContributionMultiplier,
+        OracleKey,
+        IdentityValidity,
+        ClaimCooldown,
+        AttestorKey,
+        Reward,
+        TierReward(u8),
+        DifficultyReward(HashValue),
+        IdentityVerifier,
+        Verifier(AccountId),
+        ClaimWindow,
+        ChallengeConfig,
+        KeeperBounty,
+        CodeHash,
+        Vesting,
+        IdentityRegistry,
+        YieldAdapter,
+        Basket,
+        Arbitrator,
+        Insurance,
+        Boost,
+        /// The delay of the queued identity unbindings, not a parameter change itself.
+        IdentityUnbinding,
+    }
+
+    /// A sensitive parameter change with its new value.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub enum ParameterChange {
+        PlatformKey(Option<AccountId>),
+        FirstContributionMultiplier(u32),
+        OracleKey(Option<AccountId>),
+        IdentityValidity(Option<Timestamp>),
+        ClaimCooldown(Timestamp),
+        AttestorKey(Option<AccountId>),
+        Reward(Balance),
+        TierReward(u8, Option<RewardAmount>),
+        DifficultyReward(HashValue, Option<RewardAmount>),
+        IdentityVerifier(Option<AccountId>),
+        Verifier(VerifierKey),
+        ClaimWindow(BlockNumber),
+        ChallengeConfig {
+            bond: Balance,
+            dispute_window: Timestamp,
+            challenger_share: u32,
+        },
+        KeeperBounty(Balance),
+        CodeHash(Hash),
+        Vesting(Option<AccountId>),
+        IdentityRegistry(Option<AccountId>),
+        YieldAdapter(Option<AccountId>),
+        Basket(Vec<BasketShare>),
+        Arbitrator(Option<AccountId>),
+        Insurance(Option<InsuranceConfig>),
+        Boost(Option<Boost>),
+    }
+
+    impl ParameterChange {
+        /// Returns the parameter updated by the change.
+        pub fn parameter(&self) -> Parameter {
+            match self {
+                ParameterChange::PlatformKey(_) => Parameter::PlatformKey,
+                ParameterChange::FirstContributionMultiplier(_) => Parameter::FirstContributionMultiplier,
+                ParameterChange::OracleKey(_) => Parameter::OracleKey,
+                ParameterChange::IdentityValidity(_) => Parameter::IdentityValidity,
+                ParameterChange::ClaimCooldown(_) => Parameter::ClaimCooldown,
+                ParameterChange::AttestorKey(_) => Parameter::AttestorKey,
+                ParameterChange::Reward(_) => Parameter::Reward,
+                ParameterChange::TierReward(tier, _) => Parameter::TierReward(*tier),
+                ParameterChange::DifficultyReward(difficulty, _) => Parameter::DifficultyReward(*difficulty),
+                ParameterChange::IdentityVerifier(_) => Parameter::IdentityVerifier,
+                ParameterChange::Verifier(verifier) => Parameter::Verifier(verifier.key),
+                ParameterChange::ClaimWindow(_) => Parameter::ClaimWindow,
+                ParameterChange::ChallengeConfig { .. } => Parameter::ChallengeConfig,
+                ParameterChange::KeeperBounty(_) => Parameter::KeeperBounty,
+                ParameterChange::CodeHash(_) => Parameter::CodeHash,
+                ParameterChange::Vesting(_) => Parameter::Vesting,
+                ParameterChange::IdentityRegistry(_) => Parameter::IdentityRegistry,
+                ParameterChange::YieldAdapter(_) => Parameter::YieldAdapter,
+                ParameterChange::Basket(_) => Parameter::Basket,
+                ParameterChange::Arbitrator(_) => Parameter::Arbitrator,
+                ParameterChange::Insurance(_) => Parameter::Insurance,
+                ParameterChange::Boost(_) => Parameter::Boost,
+            }
+        }
+    }
+
+    /// The state of a GitHub issue, as attested by the oracle.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub enum IssueState {
+        Open,
+        ClosedAsCompleted,
+        ClosedAsNotPlanned,
+    }
+
+    /// The oracle statement about the metrics of a contribution (e.g. reviewed lines of code,
+    /// severity score), in the order of the payout formula coefficients:
+    /// - the contract the statement is issued for.
+    /// - the contribution id (i.e. the Github issue #id).
+    /// - the metrics.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct MetricsStatement {
+        contract: AccountId,
+        contribution_id: u64,
+        metrics: Vec<u32>,
+    }
+
+    /// The payout formula computing the reward from the contribution metrics:
+    /// `min(base + sum(coefficient * metric), max_reward)`.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct PayoutFormula {
+        pub base: Balance,
+        pub coefficients: Vec<Balance>,
+        pub max_reward: Balance,
+    }
+
+    /// The escalation policy of the reward offered for the open issues: the reward is
+    /// increased by `increment` every epoch the issue stays unassigned, up to `max_reward`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct EscalationPolicy {
+        pub increment: RewardAmount,
+        pub max_reward: RewardAmount,
+    }
+
+    /// The insurance covering every approval: the insurance contract implementing the
+    /// `Insurance` trait, and the premium (in basis points of the reward) paid per approval.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct InsuranceConfig {
+        pub pool: AccountId,
+        pub premium_rate: u32,
+    }
+
+    /// The oracle statement about the issue linked to a contribution:
+    /// - the contract the statement is issued for.
+    /// - the contribution id (i.e. the Github issue #id).
+    /// - the issue state.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct ClosureStatement {
+        contract: AccountId,
+        contribution_id: u64,
+        state: IssueState,
+    }
+
+    /// A verifier key (e.g. of the Kudos backend) attesting identities and approvals, valid
+    /// from `valid_from` (included) to `valid_to` (excluded), so that keys are rotated with
+    /// overlapping validity windows.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct VerifierKey {
+        pub key: AccountId,
+        pub valid_from: Timestamp,
+        pub valid_to: Timestamp,
+    }
+
+    /// The identity registration a verifier key signature is issued for (e.g. once the
+    /// OAuth flow of the contributor is verified):
+    /// - the contract the registration is issued for.
+    /// - the identity and its account.
+    /// - the id of the verifier key.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct RegistrationPayload {
+        contract: AccountId,
         identity: HashValue,
-        caller: AccountId,
+        account: AccountId,
+        key_id: u32,
     }
 
-    /// Emitted when a `contribution` is approved.
-    #[ink(event)]
-    pub struct ContributionApproval {
-        id: u64,
-        contributor: AccountId,
+    /// The approval a verifier key signature is issued for, i.e. an `ApprovalPayload` along
+    /// with the id of the verifier key.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct VerifiedApprovalPayload {
+        approval: ApprovalPayload,
+        key_id: u32,
     }
 
-    /// Emitted when the reward associated with the `contribution` is claimed.
-    #[ink(event)]
-    pub struct RewardClaimed {
+    /// The approval a trusted workflow signer signature is issued for:
+    /// - the contract the approval is issued for.
+    /// - the contribution id (i.e. the Github issue #id).
+    /// - the contributor identity.
+    /// - a nonce chosen by the signer (e.g. the workflow run id), every approval being accepted once.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct ApprovalPayload {
+        contract: AccountId,
         contribution_id: u64,
-        contributor: AccountId,
-        reward: Balance,
+        contributor_identity: HashValue,
+        nonce: u64,
     }
 
-    impl Workflow for SingleToken {
-        /// Register the caller as an aspiring contributor.
-        ///
-        /// Constraint(s):
-        /// 1. The `identity` id should not already be registered.
-        ///
-        /// A `IdentityRegistered` event is emitted.
-        #[ink(message)]
-        fn register_identity(&mut self, identity: HashValue) -> Result<(), WorkflowError> {
-            self.register_identity(identity)
-        }
+    /// The reputation of an identity attested by another Kudos deployment:
+    /// - the attesting deployment (e.g. a workflow or leaderboard contract).
+    /// - the contributor identity.
+    /// - the reputation points.
+    /// - the attestation timestamp.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct ReputationAttestation {
+        source: AccountId,
+        identity: HashValue,
+        points: u64,
+        issued_at: Timestamp,
+    }
 
-        /// Approve contribution. This is triggered by a workflow run.
-        ///
-        /// Constraint(s):
-        /// 1. The `contribution_id` should not already be approved.
-        /// 2. The `contributor_identity` must be registered.
-        ///
-        /// An `ContributionApproval` event is emitted.
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        fn approve(
-            &mut self,
-            contribution_id: u64,
-            contributor_identity: HashValue,
-        ) -> Result<(), WorkflowError> {
-            self.approve(contribution_id, contributor_identity)
-        }
+    /// The latest attestation of an identity registration:
+    /// - the attestation timestamp (i.e. registration or re-attestation).
+    /// - the number of re-attestations, used as the signature nonce.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Attestation {
+        attested_at: Timestamp,
+        nonce: u32,
+    }
 
-        /// Check the ability to claim for a given `contribution_id`.
-        ///
-        /// Constraint(s):
-        /// 1. A `contribution` must be approved.
-        /// 2. The `contribution_id` must be the same as the one in the approved `contribution`.
-        /// 3. The caller has to be the contributor of the approved `contribution`.
-        /// 4. The claim must be available (marked as false in the claims mapping).
-        #[ink(message)]
-        fn can_claim(&self, contribution_id: u64) -> Result<bool, WorkflowError> {
-            self.can_claim(contribution_id)
-        }
+    /// A pending remark proof challenge:
+    /// - the account requesting to register the identity.
+    /// - the challenge to include in the `system.remark` extrinsic.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct RemarkChallenge {
+        account: AccountId,
+        challenge: HashValue,
+    }
 
-        /// Claim reward for a given `contribution_id`.
-        ///
-        /// Constraint(s): Ensure `can_claim`.
-        ///
-        /// A `RewardClaimed` event is emitted.
-        #[ink(message)]
-        fn claim(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
-            self.claim(contribution_id)
-        }
+    /// The reference of a confirmed `system.remark` extrinsic:
+    /// - the block number.
+    /// - the extrinsic index in the block.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct RemarkProof {
+        block: BlockNumber,
+        index: u32,
     }
 
-    impl SingleToken {
-        /// Constructor that initializes an asset reward for a given workflow
-        #[ink(constructor)]
-        pub fn new(workflow: HashValue) -> Self {
-            let mut instance = Self::default();
-            let caller = instance.env().caller();
-            ownable::Internal::_init_with_owner(&mut instance, caller);
-            Self {
-                workflow,
-                reward: 0,
-                ..instance
+    /// The re-attestation proof a platform signature is issued for.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct AttestationPayload {
+        contract: AccountId,
+        identity: HashValue,
+        account: AccountId,
+        nonce: u32,
+    }
+
+    /// The boost NFT collection:
+    /// - the PSP34 collection of the boost NFTs.
+    /// - the reward multiplier (in basis points) granted by consuming a boost NFT.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Boost {
+        collection: AccountId,
+        multiplier: u32,
+    }
+
+    /// The circuit breaker configuration:
+    /// - the duration (in milliseconds) of the sliding window.
+    /// - the maximum reward outflow over the sliding window.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct CircuitBreaker {
+        window: Timestamp,
+        max_outflow: Balance,
+    }
+
+    /// The reward outflow tracking, approximating the sliding window with the outflow
+    /// of the current and the previous fixed windows:
+    /// - the start of the current window.
+    /// - the outflow of the current window.
+    /// - the outflow of the previous window.
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Outflow {
+        window_start: Timestamp,
+        current: Balance,
+        previous: Balance,
+    }
+
+    /// The routing policy of the yield earned by the parked funds.
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub enum YieldPolicy {
+        /// The yield is kept in the contract, increasing the funders pro-rata refunds.
+        #[default]
+        Funders,
+        /// The yield is sent to the treasury.
+        Treasury(AccountId),
+    }
+
+    /// The recipient of the rounding remainders (i.e. dust) of the pro-rata payouts.
+    ///
+    /// Basket payouts always add up to the reward, the remainder being paid in the last asset.
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub enum DustPolicy {
+        /// The remainder goes to the primary recipient: the first recipient of a split claim
+        /// or the last refunded funder.
+        #[default]
+        Recipient,
+        /// The remainder goes to the treasury.
+        Treasury(AccountId),
+    }
+
+    /// The scope of a session account (e.g. a CI system key), allowed to call some approval
+    /// messages without the `APPROVER` role:
+    /// - the selectors of the messages allowed.
+    /// - the maximum total reward value approved, each approval accounting for the current reward.
+    /// - the block after which the session expires.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Session {
+        selectors: Vec<[u8; 4]>,
+        max_value: Balance,
+        expires_at: BlockNumber,
+    }
+
+    /// The limits applied to every workflow run:
+    /// - the maximum number of approvals per run.
+    /// - the maximum total reward value approved per run.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct RunLimits {
+        max_approvals: u32,
+        max_value: Balance,
+    }
+
+    /// The trial contributions configuration (e.g. "good first issues"):
+    /// - the fixed reward of every trial contribution.
+    /// - the maximum number of trial contributions approved per identity.
+    /// - the duration (in milliseconds) the trial reward can be claimed for after approval.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct TrialConfig {
+        reward: RewardAmount,
+        max_per_identity: u32,
+        duration: Timestamp,
+    }
+
+    /// The approvals of a workflow run:
+    /// - the number of approvals.
+    /// - the total reward value approved.
+    #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct RunUsage {
+        approvals: u32,
+        value: Balance,
+    }
+
+    /// A challenge against a suspicious approval:
+    /// - the challenger; rewarded if the challenge is upheld.
+    /// - the bond posted by the challenger.
+    /// - whether it is a funder veto; neither bonded nor rewarded.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Challenge {
+        challenger: AccountId,
+        bond: Balance,
+        is_veto: bool,
+    }
+
+    /// A queued parameter change:
+    /// - the change to apply.
+    /// - the timestamp from which it can be executed.
+    /// - the account which queued it, the change being attributed to it once executed.
+    #[derive(Debug, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct PendingChange {
+        change: ParameterChange,
+        eta: Timestamp,
+        account: AccountId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct SingleToken {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        #[storage_field]
+        pausable: pausable::Data,
+
+        #[storage_field]
+        access_control: access_control::Data,
+
+        // The emergency guardian, only allowed to pause the contract.
+        guardian: Option<AccountId>,
+
+        // Require claims to include a memo and the contributor tax reference.
+        compliance_mode: bool,
+
+        // The pre-registered invoice or tax reference hash per contributor identity.
+        tax_references: Mapping<HashValue, HashValue>,
+
+        // The backup account allowed to assume ownership once the owner stops sending heartbeats.
+        backup: Option<AccountId>,
+
+        // The period (in milliseconds) after the last heartbeat the backup can assume ownership.
+        heartbeat_period: Timestamp,
+
+        // The timestamp of the last owner heartbeat.
+        last_heartbeat: Timestamp,
+
+        // The multisig contract the ownership is being transferred to, until it accepts it.
+        pending_owner: Option<AccountId>,
+
+        // The faucet dripping native funds to newly registered contributors (e.g. on testnets).
+        faucet: Option<AccountId>,
+
+        // The reputation source, used to identify first-time contributors across contracts.
+        reputation: Option<AccountId>,
+
+        // The ECDSA account signing the reputation attestations, per trusted Kudos deployment.
+        reputation_sources: Mapping<AccountId, AccountId>,
+
+        // The reputation points imported from other Kudos deployments, per identity.
+        imported_reputations: Mapping<HashValue, u64>,
+
+        // The minimum contributor reputation per template tier.
+        tier_min_reputations: Mapping<u8, u64>,
+
+        // The badge collection whose holders vote on the proposed contributions tier, if some.
+        badge: Option<AccountId>,
+
+        // The duration (in milliseconds) of the votes on the proposed contributions tier.
+        voting_period: Timestamp,
+
+        // The reward per tier votable for the proposed contributions.
+        tier_rewards: Mapping<u8, Balance>,
+
+        // The proposed contributions whose tier vote is not closed yet.
+        proposals: Mapping<u64, Proposal>,
+
+        // The badge holders who voted per proposed contribution.
+        votes: Mapping<(u64, AccountId), ()>,
+
+        // The reward of the winning tier per voted contribution.
+        voted_rewards: Mapping<u64, Balance>,
+
+        // The payout formula applied to the contributions approved with metrics, if some.
+        payout_formula: Option<PayoutFormula>,
+
+        // The reward computed by the payout formula per contribution approved with metrics.
+        formula_rewards: Mapping<u64, Balance>,
+
+        // The reward per difficulty tier (e.g. the hash of the "good first issue" label).
+        difficulty_rewards: Mapping<HashValue, Balance>,
+
+        // The reward of the difficulty tier per contribution approved with a difficulty.
+        tiered_rewards: Mapping<u64, Balance>,
+
+        // The insurance covering every approval, if some.
+        insurance: Option<InsuranceConfig>,
+
+        // The escalation policy of the reward offered for the open issues, if some.
+        escalation: Option<EscalationPolicy>,
+
+        // The epoch each open issue was opened at.
+        open_issues: Mapping<u64, u32>,
+
+        // The escalated reward each open issue was approved with.
+        escalated_rewards: Mapping<u64, Balance>,
+
+        // The registered workflow.
+        // It is usually represented with the SHA hash of the workflow file (e.g. Github Workflow file).
+        workflow: HashValue,
+
+        // The contribution reward amount.
+        reward: Balance,
+
+        // The payout basket, the reward is paid in native currency only if empty.
+        basket: Vec<BasketShare>,
+
+        // The assets allowed in the payout basket besides the native currency.
+        allowed_assets: Mapping<Asset, ()>,
+
+        // The basket payouts that failed upon claim, withdrawable later by the contributor.
+        pending_payouts: Mapping<(AccountId, Asset), Balance>,
+
+        // The total pending payouts per asset.
+        pending_totals: Mapping<Asset, Balance>,
+
+        // The native payouts credited to the contributor instead of being paid, per contribution.
+        failed_payouts: Mapping<u64, Balance>,
+
+        // The approved contributions database.
+        // The key refers to a unique contribution ID (e.g. the Github issue #id).
+        contributions: Mapping<u64, Contribution>,
+
+        // The contribution templates database.
+        templates: Mapping<u32, Template>,
+
+        // The number of created templates, used as the next template ID.
+        templates_count: u32,
+
+        // The number of approved contributions.
+        approved_count: u32,
+
+        // The pre-approved contributions awaiting the registration of their contributor identity.
+        unbound_approvals: Vec<(u64, HashValue)>,
+
+        // The approver of the pre-approved contributions awaiting identity registration.
+        pre_approvers: Mapping<u64, AccountId>,
+
+        // The number of approved contributions with an unclaimed reward.
+        unclaimed_count: u32,
+
+        // The number of claimed rewards per person (see `person_of`).
+        claims_count: Mapping<HashValue, u32>,
+
+        // The maximum number of claimed rewards per person, unlimited if zero.
+        claims_cap: u32,
+
+        // The minimum delay (in milliseconds) between two claims of the same person, disabled if zero.
+        claim_cooldown: Timestamp,
+
+        // The last claim timestamp per person.
+        last_claims: Mapping<HashValue, Timestamp>,
+
+        // The claim streak per identity.
+        streaks: Mapping<HashValue, Streak>,
+
+        // The claim statistics per identity.
+        stats: Mapping<HashValue, ContributorStats>,
+
+        // The compensation (in basis points of the reward) per day late for underfunded claims.
+        late_compensation_rate: u32,
+
+        // The maximum compensation (in basis points of the reward) for underfunded claims.
+        late_compensation_cap: u32,
+
+        // The timestamp from which each reported underfunded claim is late.
+        late_since: Mapping<u64, Timestamp>,
+
+        // The compensation paid per late claimed contribution.
+        late_compensations: Mapping<u64, Balance>,
+
+        // The class of calls delegated by each account to each of its proxies.
+        proxies: Mapping<(AccountId, AccountId), MessageClass>,
+
+        // The recipient of the rounding remainders of the pro-rata payouts.
+        dust_policy: DustPolicy,
+
+        // The person linked to each identity asserted by the owner to belong to the same person.
+        persons: Mapping<HashValue, HashValue>,
+
+        // The contributions exempted from the claim cooldown by the owner.
+        cooldown_exemptions: Mapping<u64, ()>,
+
+        // The reward multiplier (in basis points) applied to a contributor first claim.
+        first_contribution_multiplier: u32,
+
+        // The bonus (in basis points of the reward) paid on top of the first claim of an identity.
+        first_claim_bonus: u32,
+
+        // The registered contributors ids database.
+        // The key refers to a registered and unique contribution ID (e.g. the Github issue #id).
+        // The value is the associated registered `AccountId` (public key) of the contributor.
+        identities: Mapping<HashValue, AccountId>, // HashValue refers to the contributo id (e.g. github ID)
+
+        // The shared identity registry resolving the identities instead of `identities`, if some.
+        identity_registry: Option<AccountId>,
+
+        // Require identities to be registered from their handle, rejecting the handles whose
+        // canonical form (i.e. lowercase) collides with a registered one.
+        canonical_identities: bool,
+
+        // The registered account per canonical identity (i.e. the hash of the lowercase handle).
+        canonical_accounts: Mapping<HashValue, AccountId>,
+
+        // The registered identities index, used to list identities in pages.
+        identity_index: Mapping<u32, HashValue>,
+
+        // The position of each registered identity in the identities index.
+        identity_positions: Mapping<HashValue, u32>,
+
+        // The number of registered identities.
+        identity_count: u32,
+
+        // The identity per registered account, the last registered one if multiple.
+        identity_of: Mapping<AccountId, HashValue>,
+
+        // Allow an account to register multiple identities (e.g. an organization).
+        multiple_identities: bool,
+
+        // The platform of the registered identities, GitHub if unset.
+        platforms: Mapping<HashValue, Platform>,
+
+        // The canonical identity per identity registered from its handle.
+        canonical_of: Mapping<HashValue, HashValue>,
+
+        // The timestamp the owner can unregister an identity at, per queued identity.
+        unbinding_etas: Mapping<HashValue, Timestamp>,
+
+        // The queued unbindings index, used to execute the ready unbindings in bounded chunks.
+        unbinding_index: Mapping<u32, HashValue>,
+
+        // The position of every queued unbinding in the queued unbindings index.
+        unbinding_positions: Mapping<HashValue, u32>,
+
+        // The number of queued unbindings.
+        unbinding_count: u32,
+
+        // The identity verifier validating the identity registrations, if some.
+        identity_verifier: Option<AccountId>,
+
+        // The pending remark proof challenges per identity.
+        remark_challenges: Mapping<HashValue, RemarkChallenge>,
+
+        // The confirmed remark proof references per identity.
+        remark_proofs: Mapping<HashValue, RemarkProof>,
+
+        // The latest attestation per registered identity.
+        attestations: Mapping<HashValue, Attestation>,
+
+        // The duration (in milliseconds) an identity registration is valid for, unless re-attested.
+        // Registrations never expire if unset.
+        identity_validity: Option<Timestamp>,
+
+        // The platform ECDSA account expected to sign claim credentials.
+        platform_key: Option<AccountId>,
+
+        // The oracle ECDSA account expected to sign the issue closure statements.
+        // Once set, approvals must include a closure statement.
+        oracle_key: Option<AccountId>,
+
+        // The attestor ECDSA account (e.g. the Kudos GitHub App) expected to sign the identity
+        // ownership proofs. Once set, registrations must include an ownership proof.
+        attestor_key: Option<AccountId>,
+
+        // The ECDSA accounts (e.g. the GitHub Action keys) trusted to sign approvals.
+        signers: Mapping<AccountId, bool>,
+
+        // The hash of every signed approval already accepted, preventing replays.
+        signed_approvals: Mapping<HashValue, ()>,
+
+        // The hash of the closure statement each contribution was approved with.
+        closure_statements: Mapping<u64, HashValue>,
+
+        // The pre-registered platform signatures, attached to the credential upon claim.
+        credential_signatures: Mapping<u64, Vec<u8>>,
+
+        // The issued claim credentials.
+        credentials: Mapping<u64, Credential>,
+
+        // The block number at which each contribution was approved.
+        approval_blocks: Mapping<u64, BlockNumber>,
+
+        // The contributions index in approval order, used to list contributions in pages.
+        contribution_index: Mapping<u32, u64>,
+
+        // The number of indexed contributions.
+        contribution_index_len: u32,
+
+        // The contributions index per contributor account.
+        contributor_index: Mapping<(AccountId, u32), u64>,
+
+        // The number of indexed contributions per contributor account.
+        contributor_index_lens: Mapping<AccountId, u32>,
+
+        // The funders index, used to iterate over funders in bounded chunks.
+        funders: Mapping<u32, AccountId>,
+
+        // The number of recorded funders.
+        funders_count: u32,
+
+        // The funders already recorded in the funders index, whatever their current deposit.
+        indexed_funders: Mapping<AccountId, ()>,
+
+        // The total amount deposited per funder.
+        deposits: Mapping<AccountId, Balance>,
+
+        // The total amount deposited by all the funders.
+        total_funded: Balance,
+
+        // The earmarked deposits per index.
+        earmarks: Mapping<u32, Earmark>,
+
+        // The number of earmarked deposits.
+        earmarks_count: u32,
+
+        // The earmarked deposit indexes backing each contribution.
+        earmarks_of: Mapping<u64, Vec<u32>>,
+
+        // The earmarked amounts allocated on approval per unclaimed contribution, per earmark index.
+        earmark_allocations: Mapping<u64, Vec<(u32, Balance)>>,
+
+        // The total earmarked amount not allocated to an approved contribution yet.
+        earmarked: Balance,
+
+        // The issues closed without approval, releasing their earmarks.
+        closed_issues: Mapping<u64, ()>,
+
+        // The refund state, set once the contract is terminated.
+        refund: Option<Refund>,
+
+        // The deposits and refunds per funder and epoch.
+        statements: Mapping<(AccountId, u32), Statement>,
+
+        // The rewards paid out per epoch.
+        epoch_allocations: Mapping<u32, Balance>,
+
+        // The verifier keys per key id.
+        verifier_keys: Mapping<u32, VerifierKey>,
+
+        // The number of added verifier keys, i.e. the next key id.
+        verifier_keys_count: u32,
+
+        // The funds received without context (i.e. through the fallback message).
+        unattributed_funds: Balance,
+
+        // Reject funds received without context instead of recording them.
+        strict_funding: bool,
+
+        // The queued sensitive parameter changes, at most one per parameter.
+        pending_changes: Mapping<Parameter, PendingChange>,
+
+        // The queued changes index, used to execute the ready changes in bounded chunks.
+        change_index: Mapping<u32, Parameter>,
+
+        // The position of every queued change in the queued changes index.
+        change_positions: Mapping<Parameter, u32>,
+
+        // The number of queued changes.
+        change_count: u32,
+
+        // The minimum delay (in milliseconds) per parameter, `DEFAULT_CHANGE_DELAY` if unset.
+        change_delays: Mapping<Parameter, Timestamp>,
+
+        // The bond required to challenge an approval.
+        challenge_bond: Balance,
+
+        // The duration (in milliseconds) approvals can be challenged for. Challenges are disabled if zero.
+        dispute_window: Timestamp,
+
+        // The share (in basis points) of the reward earned by the challenger of an upheld challenge.
+        challenger_share: u32,
+
+        // The arbitrator resolving the challenges, the owner if unset.
+        arbitrator: Option<AccountId>,
+
+        // The fraction (in basis points) of approvals flagged for a spot check.
+        spot_check_rate: u32,
+
+        // The auditors the spot checks are assigned to, the owner if empty.
+        auditors: Vec<AccountId>,
+
+        // The assigned auditor per approval pending a spot check.
+        spot_checks: Mapping<u64, AccountId>,
+
+        // The end of the dispute window per approved contribution, if challengeable.
+        disputable_until: Mapping<u64, Timestamp>,
+
+        // The pending challenge per contribution.
+        challenges: Mapping<u64, Challenge>,
+
+        // The total amount of bonds posted by the pending challenges.
+        challenge_bonds: Balance,
+
+        // The native reward reserved on approval per unclaimed contribution.
+        reservations: Mapping<u64, Balance>,
+
+        // The base and multiplied rewards approved per contribution, paid out at claim time.
+        approved_rewards: Mapping<u64, (Balance, Balance)>,
+
+        // The total native reward reserved for the unclaimed contributions.
+        reserved: Balance,
+
+        // The PSP22 rewards escrowed on approval per unclaimed contribution, per basket asset.
+        escrow_allocations: Mapping<u64, Vec<(Asset, Balance)>>,
+
+        // The total PSP22 rewards escrowed for the unclaimed contributions, per basket asset.
+        escrows: Mapping<Asset, Balance>,
+
+        // The number of blocks after which anyone can finalize an approval. Finality is not required if zero.
+        finality_depth: BlockNumber,
+
+        // The block number from which each approval pending finality can be finalized by anyone.
+        finalizable_at: Mapping<u64, BlockNumber>,
+
+        // The number of blocks the approved rewards can be claimed for. No deadline if zero.
+        claim_window: BlockNumber,
+
+        // The block number after which each unclaimed contribution can be expired.
+        claim_deadlines: Mapping<u64, BlockNumber>,
+
+        // Forbid approvers to approve their own contributions.
+        separation_of_duties: bool,
+
+        // The free balance below which a `LowFunds` event is emitted. Disabled if zero.
+        low_funds_threshold: Balance,
+
+        // The bounty paid to keepers per processed item.
+        keeper_bounty: Balance,
+
+        // The contributions left to sweep, compacted as their entries are pruned.
+        sweep_index: Mapping<u32, u64>,
+
+        // The number of contributions left to sweep.
+        sweep_index_len: u32,
+
+        // The position of the next contribution to sweep in the sweep index.
+        keeper_cursor: Cursor,
+
+        // The position of the next queued change to sweep in the queued changes index.
+        change_cursor: Cursor,
+
+        // The position of the next queued unbinding to sweep in the queued unbindings index.
+        unbinding_cursor: Cursor,
+
+        // The vetoed contributions, a contribution being vetoable once.
+        vetoes: Mapping<u64, ()>,
+
+        // The limits applied to every workflow run, unlimited if unset.
+        run_limits: Option<RunLimits>,
+
+        // The approvals per workflow run.
+        runs: Mapping<u64, RunUsage>,
+
+        // The boost NFT collection consumable upon claim, if some.
+        boost: Option<Boost>,
+
+        // The vesting contract the native rewards are routed into, if some.
+        vesting: Option<AccountId>,
+
+        // The hook notified of every contribution lifecycle transition, if some.
+        lifecycle_hook: Option<AccountId>,
+
+        // The yield adapter the idle funds are parked in, if some.
+        yield_adapter: Option<AccountId>,
+
+        // The amount parked in the yield adapter, excluding the earned yield.
+        parked: Balance,
+
+        // The routing policy of the earned yield.
+        yield_policy: YieldPolicy,
+
+        // The circuit breaker pausing the contract upon abnormal payout velocity, if some.
+        circuit_breaker: Option<CircuitBreaker>,
+
+        // The reward outflow tracked by the circuit breaker.
+        outflow: Outflow,
+
+        // The trial contributions configuration, trials being disabled if none.
+        trials: Option<TrialConfig>,
+
+        // The fixed reward of every trial contribution.
+        trial_rewards: Mapping<u64, Balance>,
+
+        // The number of trial contributions approved per identity.
+        trials_count: Mapping<HashValue, u32>,
+
+        // The version of the storage layout, see `STORAGE_VERSION`.
+        version: u32,
+
+        // The scope of every session account.
+        sessions: Mapping<AccountId, Session>,
+
+        // The reward value approved by every session account since its authorization.
+        session_spent: Mapping<AccountId, Balance>,
+    }
+
+    /// Emitted when an `identity` is registered by an aspiring contributor.
+    #[ink(event)]
+    pub struct IdentityRegistered {
+        identity: HashValue,
+        platform: Platform,
+        caller: AccountId,
+    }
+
+    /// Emitted when an `identity` is unregistered, unbinding it from its `account`.
+    #[ink(event)]
+    pub struct IdentityUnregistered {
+        identity: HashValue,
+        account: AccountId,
+    }
+
+    /// Emitted when an `identity` is rotated from its `previous_account` to a new `account`.
+    #[ink(event)]
+    pub struct IdentityRotated {
+        identity: HashValue,
+        previous_account: AccountId,
+        account: AccountId,
+    }
+
+    /// Emitted when the owner queues the unbinding of an `identity`, executable at `eta`.
+    #[ink(event)]
+    pub struct IdentityUnbindingQueued {
+        identity: HashValue,
+        eta: Timestamp,
+    }
+
+    /// Emitted when a remark proof challenge is requested for an `identity`.
+    #[ink(event)]
+    pub struct RemarkProofRequested {
+        identity: HashValue,
+        account: AccountId,
+        challenge: HashValue,
+    }
+
+    /// Emitted when the remark proof of an `identity` is confirmed.
+    #[ink(event)]
+    pub struct RemarkProofConfirmed {
+        identity: HashValue,
+        block: BlockNumber,
+        index: u32,
+    }
+
+    /// Emitted when an `identity` is linked to a `person`, or unlinked if none, by an `account`.
+    #[ink(event)]
+    pub struct IdentityLinked {
+        account: AccountId,
+        identity: HashValue,
+        old_person: Option<HashValue>,
+        new_person: Option<HashValue>,
+    }
+
+    /// Emitted when an `identity` registration is re-attested.
+    #[ink(event)]
+    pub struct IdentityReattested {
+        identity: HashValue,
+        caller: AccountId,
+    }
+
+    /// Emitted when the platform signature of a `contribution_id` credential is registered
+    /// by an `account`.
+    #[ink(event)]
+    pub struct CredentialSignatureRegistered {
+        account: AccountId,
+        contribution_id: u64,
+        old_signature: Option<Vec<u8>>,
+        new_signature: Vec<u8>,
+    }
+
+    /// Emitted when a `role` is granted to a `grantee` by a `grantor`, none upon deployment.
+    #[ink(event)]
+    pub struct RoleGranted {
+        role: RoleType,
+        grantee: Option<AccountId>,
+        grantor: Option<AccountId>,
+    }
+
+    /// Emitted when a `role` is revoked from an `account` by a `sender` (e.g. itself when renounced).
+    #[ink(event)]
+    pub struct RoleRevoked {
+        role: RoleType,
+        account: Option<AccountId>,
+        sender: AccountId,
+    }
+
+    #[overrider(access_control::Internal)]
+    fn _emit_role_granted(&mut self, role: RoleType, grantee: Option<AccountId>, grantor: Option<AccountId>) {
+        self.env().emit_event(RoleGranted { role, grantee, grantor });
+    }
+
+    #[overrider(access_control::Internal)]
+    fn _emit_role_revoked(&mut self, role: RoleType, account: Option<AccountId>, sender: AccountId) {
+        self.env().emit_event(RoleRevoked { role, account, sender });
+    }
+
+    /// Emitted when a verifier `key` is added under a given `key_id`.
+    #[ink(event)]
+    pub struct VerifierAdded {
+        key_id: u32,
+        key: AccountId,
+        valid_from: Timestamp,
+        valid_to: Timestamp,
+    }
+
+    /// Emitted when the verifier key of a given `key_id` is revoked by an `account`, i.e. its
+    /// validity window shortened to end at `valid_to`.
+    #[ink(event)]
+    pub struct VerifierRevoked {
+        account: AccountId,
+        key_id: u32,
+        valid_to: Timestamp,
+    }
+
+    /// Emitted when a `signer` is trusted to sign approvals.
+    #[ink(event)]
+    pub struct SignerAdded {
+        signer: AccountId,
+    }
+
+    /// Emitted when a `signer` is not trusted anymore.
+    #[ink(event)]
+    pub struct SignerRemoved {
+        signer: AccountId,
+    }
+
+    /// Emitted when a trusted `old_signer` is replaced by a `new_signer`.
+    #[ink(event)]
+    pub struct SignerRotated {
+        old_signer: AccountId,
+        new_signer: AccountId,
+    }
+
+    /// Emitted when a session `account` is authorized, or revoked if no `session`.
+    #[ink(event)]
+    pub struct SessionAuthorized {
+        #[ink(topic)]
+        account: AccountId,
+        session: Option<Session>,
+    }
+
+    /// Emitted when a contribution is imported from a legacy reward contract.
+    #[ink(event)]
+    pub struct ContributionImported {
+        id: u64,
+        identity: HashValue,
+        is_reward_claimed: bool,
+    }
+
+    /// Emitted when the reputation `points` of an `identity` are imported from a `source` deployment.
+    #[ink(event)]
+    pub struct ReputationImported {
+        #[ink(topic)]
+        identity: HashValue,
+        source: AccountId,
+        points: u64,
+    }
+
+    /// Emitted when a `contribution` is approved.
+    #[ink(event)]
+    pub struct ContributionApproval {
+        id: u64,
+        contributor: AccountId,
+        platform: Platform,
+        pending_finality: bool,
+        metadata: Option<HashValue>,
+    }
+
+    /// Emitted when the approval of a contribution is finalized.
+    #[ink(event)]
+    pub struct ContributionFinalized {
+        id: u64,
+    }
+
+    /// Emitted when an unclaimed contribution is expired, its reserved reward being released.
+    #[ink(event)]
+    pub struct ContributionExpired {
+        id: u64,
+        released: Balance,
+    }
+
+    /// Emitted when an approval or a claim leaves the free balance below the low funds
+    /// threshold, along with the `outstanding` obligations.
+    #[ink(event)]
+    pub struct LowFunds {
+        outstanding: Balance,
+        balance: Balance,
+    }
+
+    /// Emitted when a `keeper` sweeps the contributions, paid a `bounty` for the processed items.
+    #[ink(event)]
+    pub struct KeeperSwept {
+        #[ink(topic)]
+        keeper: AccountId,
+        processed: u32,
+        bounty: Balance,
+    }
+
+    /// Emitted when an approval is flagged for a spot check by the assigned `auditor`.
+    #[ink(event)]
+    pub struct SpotCheckFlagged {
+        id: u64,
+        auditor: AccountId,
+    }
+
+    /// Emitted when the spot check of an approval is confirmed.
+    #[ink(event)]
+    pub struct SpotCheckConfirmed {
+        id: u64,
+        auditor: AccountId,
+    }
+
+    /// Emitted when a contribution is pre-approved for an unregistered `identity`.
+    #[ink(event)]
+    pub struct ContributionPreApproval {
+        id: u64,
+        identity: HashValue,
+    }
+
+    /// Emitted when a contribution pre-approved for a registered `identity` cannot be approved
+    /// yet (e.g. underfunded), the pre-approval staying pending. The `error` is the code of
+    /// the approval failure (see `describe_error`).
+    #[ink(event)]
+    pub struct PreApprovalDeferred {
+        contribution_id: u64,
+        identity: HashValue,
+        error: u8,
+    }
+
+    /// Emitted when the reward associated with the `contribution` is claimed.
+    #[ink(event)]
+    pub struct RewardClaimed {
+        contribution_id: u64,
+        contributor: AccountId,
+        reward: Balance,
+        // The first claim bonus included in the reward.
+        bonus: Balance,
+    }
+
+    /// Emitted along with `RewardClaimed` when the claim includes a payment reference.
+    #[ink(event)]
+    pub struct PaymentReferenced {
+        contribution_id: u64,
+        memo: String,
+        tax_reference: HashValue,
+    }
+
+    /// Emitted when a claim is reported as failing because of the contract underfunding.
+    #[ink(event)]
+    pub struct LatePayoutReported {
+        contribution_id: u64,
+        reward: Balance,
+        balance: Balance,
+    }
+
+    /// Emitted along with `RewardClaimed` when a late claim earns a compensation.
+    #[ink(event)]
+    pub struct LatePayoutCompensated {
+        contribution_id: u64,
+        days_late: u64,
+        compensation: Balance,
+    }
+
+    /// Emitted when a contribution is proposed to the badge holders vote.
+    #[ink(event)]
+    pub struct ContributionProposed {
+        id: u64,
+        ends_at: Timestamp,
+    }
+
+    /// Emitted when a badge holder votes on the tier of a proposed contribution.
+    #[ink(event)]
+    pub struct TierVoted {
+        id: u64,
+        voter: AccountId,
+        tier: u8,
+    }
+
+    /// Emitted when the tier vote of a proposed contribution is closed, with the winning tier if some.
+    #[ink(event)]
+    pub struct TierVoteClosed {
+        id: u64,
+        tier: Option<u8>,
+        reward: Balance,
+    }
+
+    /// Emitted when an account delegates a class of calls to a `proxy`, or revokes it if none.
+    #[ink(event)]
+    pub struct ProxyUpdated {
+        #[ink(topic)]
+        account: AccountId,
+        proxy: AccountId,
+        class: Option<MessageClass>,
+    }
+
+    /// Emitted when a contribution `template` is created.
+    #[ink(event)]
+    pub struct TemplateCreated {
+        id: u32,
+        template: Template,
+    }
+
+    /// Emitted when a payout fails and is recorded as pending.
+    #[ink(event)]
+    pub struct PayoutDeferred {
+        #[ink(topic)]
+        contributor: AccountId,
+        asset: Asset,
+        amount: Balance,
+    }
+
+    /// Emitted when a native reward cannot be transferred to a `contributor` (e.g. a
+    /// tombstoned contract) and is credited to the internal ledger instead.
+    #[ink(event)]
+    pub struct CreditedInsteadOfPaid {
+        #[ink(topic)]
+        contributor: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when the insurance pool takes over the failed payout of a `contribution_id`,
+    /// the `amount` being removed from the pending payout of the `contributor`.
+    #[ink(event)]
+    pub struct FailedPayoutTakenOver {
+        contribution_id: u64,
+        #[ink(topic)]
+        contributor: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when the guardian is updated by an `account`.
+    #[ink(event)]
+    pub struct GuardianUpdated {
+        account: AccountId,
+        old_guardian: Option<AccountId>,
+        new_guardian: Option<AccountId>,
+    }
+
+    /// Emitted when the `backup` assumes ownership after a missed heartbeat.
+    #[ink(event)]
+    pub struct OwnershipAssumed {
+        backup: AccountId,
+        last_heartbeat: Timestamp,
+    }
+
+    /// Emitted when the ownership transfer to a multisig `pending_owner` is started.
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        pending_owner: AccountId,
+    }
+
+    /// Emitted when the pending owner accepts the ownership.
+    #[ink(event)]
+    pub struct OwnershipAccepted {
+        previous_owner: Option<AccountId>,
+        owner: AccountId,
+    }
+
+    /// Emitted when an `asset` is allowed or disallowed in the payout basket by an `account`.
+    #[ink(event)]
+    pub struct AllowedAssetUpdated {
+        account: AccountId,
+        asset: Asset,
+        old_allowed: bool,
+        new_allowed: bool,
+    }
+
+    /// Emitted when the reward is updated by an `account`.
+    #[ink(event)]
+    pub struct RewardUpdated {
+        account: AccountId,
+        old_reward: Balance,
+        new_reward: Balance,
+    }
+
+    /// Emitted when the fee paid to keepers per processed item (i.e. the keeper bounty) is
+    /// updated by an `account`.
+    #[ink(event)]
+    pub struct FeeUpdated {
+        account: AccountId,
+        old_fee: Balance,
+        new_fee: Balance,
+    }
+
+    /// Emitted when the claim window after which the approved rewards expire is updated by
+    /// an `account`.
+    #[ink(event)]
+    pub struct ExpiryPolicyUpdated {
+        account: AccountId,
+        old_claim_window: BlockNumber,
+        new_claim_window: BlockNumber,
+    }
+
+    /// Emitted when the identity verifier is replaced by an `account`.
+    #[ink(event)]
+    pub struct VerifierRotated {
+        account: AccountId,
+        old_verifier: Option<AccountId>,
+        new_verifier: Option<AccountId>,
+    }
+
+    /// Emitted when the circuit breaker capping the reward outflow is updated by an `account`.
+    #[ink(event)]
+    pub struct BudgetCapUpdated {
+        account: AccountId,
+        old_circuit_breaker: Option<CircuitBreaker>,
+        new_circuit_breaker: Option<CircuitBreaker>,
+    }
+
+    /// Emitted when the reward of a votable `tier` is updated by an `account`.
+    #[ink(event)]
+    pub struct TierUpdated {
+        account: AccountId,
+        tier: u8,
+        old_reward: Option<Balance>,
+        new_reward: Option<Balance>,
+    }
+
+    /// Emitted when the reward of a `difficulty` tier is updated by an `account`.
+    #[ink(event)]
+    pub struct DifficultyRewardUpdated {
+        account: AccountId,
+        difficulty: HashValue,
+        old_reward: Option<Balance>,
+        new_reward: Option<Balance>,
+    }
+
+    /// Emitted when the payout basket is updated by an `account`.
+    #[ink(event)]
+    pub struct BasketUpdated {
+        account: AccountId,
+        old_basket: Vec<BasketShare>,
+        new_basket: Vec<BasketShare>,
+    }
+
+    /// Emitted when the boost NFT collection and multiplier are updated by an `account`.
+    #[ink(event)]
+    pub struct BoostUpdated {
+        account: AccountId,
+        old_boost: Option<Boost>,
+        new_boost: Option<Boost>,
+    }
+
+    /// Emitted when the compliance mode is enabled or disabled by an `account`.
+    #[ink(event)]
+    pub struct ComplianceModeUpdated {
+        account: AccountId,
+        old_compliance_mode: bool,
+        new_compliance_mode: bool,
+    }
+
+    /// Emitted when the late payout compensation rate and cap are updated by an `account`.
+    #[ink(event)]
+    pub struct LateCompensationUpdated {
+        account: AccountId,
+        old_rate: u32,
+        old_cap: u32,
+        new_rate: u32,
+        new_cap: u32,
+    }
+
+    /// Emitted when the first claim bonus is updated by an `account`.
+    #[ink(event)]
+    pub struct FirstClaimBonusUpdated {
+        account: AccountId,
+        old_bonus: u32,
+        new_bonus: u32,
+    }
+
+    /// Emitted when the recipient of the rounding remainders is updated by an `account`.
+    #[ink(event)]
+    pub struct DustPolicyUpdated {
+        account: AccountId,
+        old_dust_policy: DustPolicy,
+        new_dust_policy: DustPolicy,
+    }
+
+    /// Emitted when the maximum number of claimed rewards per person is updated by an `account`.
+    #[ink(event)]
+    pub struct ClaimsCapUpdated {
+        account: AccountId,
+        old_claims_cap: u32,
+        new_claims_cap: u32,
+    }
+
+    /// Emitted when the low funds threshold is updated by an `account`.
+    #[ink(event)]
+    pub struct LowFundsThresholdUpdated {
+        account: AccountId,
+        old_threshold: Balance,
+        new_threshold: Balance,
+    }
+
+    /// Emitted when the separation of duties is enforced or not by an `account`.
+    #[ink(event)]
+    pub struct SeparationOfDutiesUpdated {
+        account: AccountId,
+        old_enforced: bool,
+        new_enforced: bool,
+    }
+
+    /// Emitted when a `contribution_id` is exempted or not from the claim cooldown by an `account`.
+    #[ink(event)]
+    pub struct CooldownExemptionUpdated {
+        account: AccountId,
+        contribution_id: u64,
+        old_exempted: bool,
+        new_exempted: bool,
+    }
+
+    /// Emitted when the escalation policy of the offered rewards is updated by an `account`.
+    #[ink(event)]
+    pub struct EscalationUpdated {
+        account: AccountId,
+        old_escalation: Option<EscalationPolicy>,
+        new_escalation: Option<EscalationPolicy>,
+    }
+
+    /// Emitted when the rejection of the funds received without context is enabled or
+    /// disabled by an `account`.
+    #[ink(event)]
+    pub struct StrictFundingUpdated {
+        account: AccountId,
+        old_strict_funding: bool,
+        new_strict_funding: bool,
+    }
+
+    /// Emitted when the backup account and heartbeat period are updated by an `account`.
+    #[ink(event)]
+    pub struct BackupUpdated {
+        account: AccountId,
+        old_backup: Option<AccountId>,
+        old_heartbeat_period: Timestamp,
+        new_backup: Option<AccountId>,
+        new_heartbeat_period: Timestamp,
+    }
+
+    /// Emitted when the faucet is updated by an `account`.
+    #[ink(event)]
+    pub struct FaucetUpdated {
+        account: AccountId,
+        old_faucet: Option<AccountId>,
+        new_faucet: Option<AccountId>,
+    }
+
+    /// Emitted when the reputation source is updated by an `account`.
+    #[ink(event)]
+    pub struct ReputationUpdated {
+        account: AccountId,
+        old_reputation: Option<AccountId>,
+        new_reputation: Option<AccountId>,
+    }
+
+    /// Emitted when the key of a trusted reputation `source` is updated by an `account`.
+    #[ink(event)]
+    pub struct ReputationSourceUpdated {
+        account: AccountId,
+        source: AccountId,
+        old_key: Option<AccountId>,
+        new_key: Option<AccountId>,
+    }
+
+    /// Emitted when the minimum reputation of a `tier` is updated by an `account`.
+    #[ink(event)]
+    pub struct TierMinReputationUpdated {
+        account: AccountId,
+        tier: u8,
+        old_min_reputation: Option<u64>,
+        new_min_reputation: Option<u64>,
+    }
+
+    /// Emitted when the community voting configuration is updated by an `account`.
+    #[ink(event)]
+    pub struct VotingUpdated {
+        account: AccountId,
+        old_badge: Option<AccountId>,
+        old_voting_period: Timestamp,
+        new_badge: Option<AccountId>,
+        new_voting_period: Timestamp,
+    }
+
+    /// Emitted when the vesting contract is updated by an `account`.
+    #[ink(event)]
+    pub struct VestingUpdated {
+        account: AccountId,
+        old_vesting: Option<AccountId>,
+        new_vesting: Option<AccountId>,
+    }
+
+    /// Emitted when the lifecycle hook is updated by an `account`.
+    #[ink(event)]
+    pub struct LifecycleHookUpdated {
+        account: AccountId,
+        old_lifecycle_hook: Option<AccountId>,
+        new_lifecycle_hook: Option<AccountId>,
+    }
+
+    /// Emitted when the insurance is updated by an `account`.
+    #[ink(event)]
+    pub struct InsuranceUpdated {
+        account: AccountId,
+        old_insurance: Option<InsuranceConfig>,
+        new_insurance: Option<InsuranceConfig>,
+    }
+
+    /// Emitted when the yield adapter is updated by an `account`.
+    #[ink(event)]
+    pub struct YieldAdapterUpdated {
+        account: AccountId,
+        old_yield_adapter: Option<AccountId>,
+        new_yield_adapter: Option<AccountId>,
+    }
+
+    /// Emitted when the routing policy of the yield is updated by an `account`.
+    #[ink(event)]
+    pub struct YieldPolicyUpdated {
+        account: AccountId,
+        old_yield_policy: YieldPolicy,
+        new_yield_policy: YieldPolicy,
+    }
+
+    /// Emitted when the canonical identities are enabled or disabled by an `account`.
+    #[ink(event)]
+    pub struct CanonicalIdentitiesUpdated {
+        account: AccountId,
+        old_canonical_identities: bool,
+        new_canonical_identities: bool,
+    }
+
+    /// Emitted when the registration of multiple identities per account is allowed or not
+    /// by an `account`.
+    #[ink(event)]
+    pub struct MultipleIdentitiesUpdated {
+        account: AccountId,
+        old_multiple_identities: bool,
+        new_multiple_identities: bool,
+    }
+
+    /// Emitted when the shared identity registry is updated by an `account`.
+    #[ink(event)]
+    pub struct IdentityRegistryUpdated {
+        account: AccountId,
+        old_identity_registry: Option<AccountId>,
+        new_identity_registry: Option<AccountId>,
+    }
+
+    /// Emitted when the limits applied to every workflow run are updated by an `account`.
+    #[ink(event)]
+    pub struct RunLimitsUpdated {
+        account: AccountId,
+        old_run_limits: Option<RunLimits>,
+        new_run_limits: Option<RunLimits>,
+    }
+
+    /// Emitted when the trial contributions configuration is updated by an `account`.
+    #[ink(event)]
+    pub struct TrialsUpdated {
+        account: AccountId,
+        old_trials: Option<TrialConfig>,
+        new_trials: Option<TrialConfig>,
+    }
+
+    /// Emitted when the contribution template of a given `id` is removed by an `account`.
+    #[ink(event)]
+    pub struct TemplateRemoved {
+        account: AccountId,
+        id: u32,
+        old_template: Template,
+    }
+
+    /// Emitted when the payout formula is updated by an `account`.
+    #[ink(event)]
+    pub struct PayoutFormulaUpdated {
+        account: AccountId,
+        old_payout_formula: Option<PayoutFormula>,
+        new_payout_formula: Option<PayoutFormula>,
+    }
+
+    /// Emitted when the approval challenges configuration is updated by an `account`.
+    #[ink(event)]
+    pub struct ChallengeConfigUpdated {
+        account: AccountId,
+        old_bond: Balance,
+        old_dispute_window: Timestamp,
+        old_challenger_share: u32,
+        new_bond: Balance,
+        new_dispute_window: Timestamp,
+        new_challenger_share: u32,
+    }
+
+    /// Emitted when the arbitrator of the challenges is updated by an `account`.
+    #[ink(event)]
+    pub struct ArbitratorUpdated {
+        account: AccountId,
+        old_arbitrator: Option<AccountId>,
+        new_arbitrator: Option<AccountId>,
+    }
+
+    /// Emitted when the finality depth of the approvals is updated by an `account`.
+    #[ink(event)]
+    pub struct FinalityDepthUpdated {
+        account: AccountId,
+        old_finality_depth: BlockNumber,
+        new_finality_depth: BlockNumber,
+    }
+
+    /// Emitted when the spot checks configuration is updated by an `account`.
+    #[ink(event)]
+    pub struct SpotChecksUpdated {
+        account: AccountId,
+        old_rate: u32,
+        old_auditors: Vec<AccountId>,
+        new_rate: u32,
+        new_auditors: Vec<AccountId>,
+    }
+
+    /// Emitted when the minimum delay of a `parameter` changes is increased by an `account`.
+    #[ink(event)]
+    pub struct ChangeDelayUpdated {
+        account: AccountId,
+        parameter: Parameter,
+        old_delay: Timestamp,
+        new_delay: Timestamp,
+    }
+
+    /// Emitted when the contract is paused.
+    #[ink(event)]
+    pub struct Paused {
+        account: AccountId,
+    }
+
+    /// Emitted when the circuit breaker pauses the contract.
+    #[ink(event)]
+    pub struct CircuitBreakerTripped {
+        outflow: Balance,
+        max_outflow: Balance,
+    }
+
+    /// Emitted when the contract is unpaused.
+    #[ink(event)]
+    pub struct Unpaused {
+        account: AccountId,
+    }
+
+    /// Emitted when a parameter change is queued.
+    #[ink(event)]
+    pub struct ChangeQueued {
+        change: ParameterChange,
+        eta: Timestamp,
+    }
+
+    /// Emitted when a queued parameter change is executed.
+    #[ink(event)]
+    pub struct ChangeExecuted {
+        change: ParameterChange,
+    }
+
+    /// Emitted when a queued parameter change is cancelled.
+    #[ink(event)]
+    pub struct ChangeCancelled {
+        parameter: Parameter,
+    }
+
+    /// Emitted when a `funder` deposits an `amount`.
+    #[ink(event)]
+    pub struct FundsDeposited {
+        #[ink(topic)]
+        funder: AccountId,
+        amount: Balance,
+        total_funded: Balance,
+    }
+
+    /// Emitted when a `funder` earmarks a deposit for specific contributions.
+    #[ink(event)]
+    pub struct FundsEarmarked {
+        #[ink(topic)]
+        funder: AccountId,
+        index: u32,
+        amount: Balance,
+        contribution_ids: Vec<u64>,
+    }
+
+    /// Emitted when a `funder` withdraws the unused `amount` of an earmarked deposit.
+    #[ink(event)]
+    pub struct EarmarkWithdrawn {
+        #[ink(topic)]
+        funder: AccountId,
+        index: u32,
+        amount: Balance,
+    }
+
+    /// Emitted when funds are received without context.
+    #[ink(event)]
+    pub struct UnattributedFundsReceived {
+        #[ink(topic)]
+        from: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when the contract is terminated by the owner.
+    #[ink(event)]
+    pub struct Terminated {
+        refund_pool: Balance,
+    }
+
+    /// Emitted when the contract code is upgraded to a given `code_hash`.
+    #[ink(event)]
+    pub struct CodeUpgraded {
+        code_hash: Hash,
+    }
+
+    /// Emitted when the storage is migrated `from` a version `to` another.
+    #[ink(event)]
+    pub struct Migrated {
+        from: u32,
+        to: u32,
+    }
+
+    /// Emitted when an approval is challenged.
+    #[ink(event)]
+    pub struct ApprovalChallenged {
+        contribution_id: u64,
+        #[ink(topic)]
+        challenger: AccountId,
+        bond: Balance,
+    }
+
+    /// Emitted when an approval is vetoed by a funder.
+    #[ink(event)]
+    pub struct ApprovalVetoed {
+        contribution_id: u64,
+        #[ink(topic)]
+        funder: AccountId,
+    }
+
+    /// Emitted when a challenge is resolved by the arbitrator.
+    #[ink(event)]
+    pub struct ChallengeResolved {
+        contribution_id: u64,
+        upheld: bool,
+        payout: Balance,
+    }
+
+    /// Emitted when the yield earned by the parked funds is harvested.
+    #[ink(event)]
+    pub struct YieldHarvested {
+        amount: Balance,
+        policy: YieldPolicy,
+    }
+
+    /// Emitted when a funder is refunded after termination.
+    #[ink(event)]
+    pub struct FundsRefunded {
+        #[ink(topic)]
+        funder: AccountId,
+        amount: Balance,
+    }
+
+    impl Workflow for SingleToken {
+        /// Register the caller as an aspiring contributor.
+        ///
+        /// Constraint(s):
+        /// 1. The `identity` id should not already be registered.
+        ///
+        /// A `IdentityRegistered` event is emitted.
+        #[ink(message)]
+        fn register_identity(&mut self, identity: HashValue) -> Result<(), WorkflowError> {
+            self.register_identity(identity)
+        }
+
+        /// Approve contribution. This is triggered by a workflow run.
+        ///
+        /// Constraint(s):
+        /// 1. The `contribution_id` should not already be approved for another identity,
+        ///    approving it again for the same identity is a no-op.
+        /// 2. The `contributor_identity` must be registered.
+        ///
+        /// An `ContributionApproval` event is emitted, only upon the first approval.
+        #[ink(message)]
+        fn approve(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+        ) -> Result<(), WorkflowError> {
+            self.approve(contribution_id, contributor_identity)
+        }
+
+        /// Check the ability to claim for a given `contribution_id`.
+        ///
+        /// Constraint(s):
+        /// 1. A `contribution` must be approved.
+        /// 2. The `contribution_id` must refer to an approved `contribution`.
+        /// 3. The caller has to be the contributor of the approved `contribution`.
+        /// 4. The claim must be available (marked as false in the claims mapping).
+        #[ink(message)]
+        fn can_claim(&self, contribution_id: u64) -> Result<bool, WorkflowError> {
+            self.can_claim(contribution_id)
+        }
+
+        /// Claim reward for a given `contribution_id`.
+        ///
+        /// Constraint(s): Ensure `can_claim`.
+        ///
+        /// The first reward claimed by a contributor identity is multiplied by
+        /// the first contribution multiplier.
+        ///
+        /// A `RewardClaimed` event is emitted.
+        #[ink(message)]
+        fn claim(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            self.claim(contribution_id)
+        }
+    }
+
+    impl Introspection for SingleToken {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl SingleToken {
+        /// Constructor that initializes an asset reward for a given workflow
+        #[ink(constructor)]
+        pub fn new(workflow: HashValue) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            instance.grant_roles(caller);
+            Self {
+                workflow,
+                reward: 0,
+                first_contribution_multiplier: BASIS_POINTS,
+                version: STORAGE_VERSION,
+                ..instance
+            }
+        }
+
+        /// A helper function to set the `reward` of the approvals.
+        fn update_reward(&mut self, account: AccountId, reward: Balance) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+
+            self.env().emit_event(RewardUpdated {
+                account,
+                old_reward: self.reward,
+                new_reward: reward,
+            });
+
+            self.reward = reward;
+            Ok(())
+        }
+
+        /// Fund the contract, the deposit is recorded for the caller.
+        ///
+        /// The deposit is earmarked for the given `contribution_ids` if some: it backs the
+        /// rewards of these contributions before the general pool, and the unused part is
+        /// refundable with `withdraw_earmark` once the issues are closed.
+        ///
+        /// Constraint(s):
+        /// 1. At most `MAX_EARMARKS` contributions, none of them already approved.
+        ///
+        /// A `FundsDeposited` event is emitted, followed by a `FundsEarmarked` event if earmarked.
+        #[ink(message, payable)]
+        pub fn fund(&mut self, contribution_ids: Vec<u64>) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+
+            let funder = Self::env().caller();
+            let amount = self.env().transferred_value();
+            if contribution_ids.len() > MAX_EARMARKS
+                || contribution_ids.iter().any(|contribution_id| {
+                    self.contributions.contains(contribution_id)
+                        || self.get_earmarks_of(*contribution_id).len() >= MAX_EARMARKS
+                })
+            {
+                return Err(WorkflowError::InvalidEarmark);
+            }
+
+            self.record_deposit(funder, amount)?;
+            if contribution_ids.is_empty() || amount == 0 {
+                return Ok(());
+            }
+
+            let index = self.earmarks_count;
+            for contribution_id in contribution_ids.iter() {
+                let mut earmarks = self.get_earmarks_of(*contribution_id);
+                if !earmarks.contains(&index) {
+                    earmarks.push(index);
+                    self.earmarks_of.insert(contribution_id, &earmarks);
+                }
+            }
+
+            self.earmarks.insert(
+                index,
+                &Earmark {
+                    funder,
+                    contribution_ids: contribution_ids.clone(),
+                    remaining: amount,
+                },
+            );
+            self.earmarks_count = self.earmarks_count.checked_add(1).ok_or(WorkflowError::Overflow)?;
+            self.earmarked = self.earmarked.checked_add(amount).ok_or(WorkflowError::Overflow)?;
+
+            self.env().emit_event(FundsEarmarked {
+                funder,
+                index,
+                amount,
+                contribution_ids,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw the amount of the earmarked deposit at a given `index` not allocated to
+        /// an approved contribution.
+        ///
+        /// Constraint(s):
+        /// 1. The caller must be the funder of the earmarked deposit.
+        /// 2. Every backed issue must be closed: either approved, expired, revoked or closed by
+        ///    the owner.
+        ///
+        /// An `EarmarkWithdrawn` event is emitted.
+        #[ink(message)]
+        pub fn withdraw_earmark(&mut self, index: u32) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+
+            let earmark = self.earmarks.get(index).ok_or(WorkflowError::UnknownEarmark)?;
+            if earmark.funder != Self::env().caller() {
+                return Err(WorkflowError::CallerIsNotFunder);
+            }
+            if !earmark.contribution_ids.iter().all(|contribution_id| {
+                self.contributions.contains(contribution_id) || self.closed_issues.contains(contribution_id)
+            }) {
+                return Err(WorkflowError::EarmarkInUse);
+            }
+
+            let amount = earmark.remaining;
+            if amount == 0 {
+                return Ok(());
+            }
+
+            self.earmarked = self.earmarked.checked_sub(amount).ok_or(WorkflowError::Overflow)?;
+            self.earmarks.insert(index, &Earmark { remaining: 0, ..earmark });
+            let deposit = self.get_deposit(earmark.funder).saturating_sub(amount);
+            self.deposits.insert(earmark.funder, &deposit);
+            self.total_funded = self.total_funded.saturating_sub(amount);
+            self.pay(Asset::Native, earmark.funder, amount)?;
+
+            self.env().emit_event(EarmarkWithdrawn {
+                funder: earmark.funder,
+                index,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Close a given `contribution_id` issue without approval, releasing its earmarks.
+        #[ink(message)]
+        #[modifiers(only_role(APPROVER))]
+        pub fn close_issue(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            self.closed_issues.insert(contribution_id, &());
+            self.open_issues.remove(contribution_id);
+            Ok(())
+        }
+
+        /// Open a given `contribution_id` issue, its offered reward escalating from now on
+        /// according to the escalation policy until it is approved.
+        #[ink(message)]
+        #[modifiers(only_role(APPROVER))]
+        pub fn open_issue(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            if self.contributions.contains(contribution_id) {
+                return Err(WorkflowError::ContributionAlreadyApproved);
+            }
+
+            self.open_issues.insert(contribution_id, &self.current_epoch());
+            self.closed_issues.remove(contribution_id);
+            Ok(())
+        }
+
+        /// Set the escalation policy of the reward offered for the open issues, if some.
+        ///
+        /// Constraint(s):
+        /// 1. The increment must not be zero.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_escalation(&mut self, escalation: Option<EscalationPolicy>) -> Result<(), WorkflowError> {
+            if matches!(escalation, Some(escalation) if escalation.increment.plancks() == 0) {
+                return Err(WorkflowError::InvalidEscalation);
+            }
+
+            self.env().emit_event(EscalationUpdated {
+                account: self.env().caller(),
+                old_escalation: self.escalation,
+                new_escalation: escalation,
+            });
+
+            self.escalation = escalation;
+            Ok(())
+        }
+
+        /// Simply returns the escalation policy if some.
+        #[ink(message)]
+        pub fn get_escalation(&self) -> Option<EscalationPolicy> {
+            self.escalation
+        }
+
+        /// Returns the reward currently offered for a given open `contribution_id` issue, if some.
+        ///
+        /// The reward is escalated by the increment for every epoch elapsed since the issue was
+        /// opened, up to the maximum reward (the base reward being kept if already higher).
+        #[ink(message)]
+        pub fn get_offered_reward(&self, contribution_id: u64) -> Option<Balance> {
+            let opened_at = self.open_issues.get(contribution_id)?;
+            let Some(escalation) = self.escalation else {
+                return Some(self.reward);
+            };
+
+            let epochs = Balance::from(self.current_epoch().saturating_sub(opened_at));
+            let escalated = escalation
+                .increment
+                .plancks()
+                .saturating_mul(epochs)
+                .saturating_add(self.reward)
+                .min(escalation.max_reward.plancks());
+            Some(escalated.max(self.reward))
+        }
+
+        /// Simply returns the escalated reward a given `contribution_id` was approved with if some.
+        #[ink(message)]
+        pub fn get_escalated_reward(&self, contribution_id: u64) -> Option<Balance> {
+            self.escalated_rewards.get(contribution_id)
+        }
+
+        /// Simply returns the earmarked deposit at a given `index` if some.
+        #[ink(message)]
+        pub fn get_earmark(&self, index: u32) -> Option<Earmark> {
+            self.earmarks.get(index)
+        }
+
+        /// Simply returns the indexes of the earmarked deposits backing a given `contribution_id`.
+        #[ink(message)]
+        pub fn get_earmarks_of(&self, contribution_id: u64) -> Vec<u32> {
+            self.earmarks_of.get(contribution_id).unwrap_or_default()
+        }
+
+        /// Simply returns the total earmarked amount not allocated to an approved contribution yet.
+        #[ink(message)]
+        pub fn get_earmarked(&self) -> Balance {
+            self.earmarked
+        }
+
+        /// Simply checks if a given `contribution_id` issue is closed without approval.
+        #[ink(message)]
+        pub fn is_issue_closed(&self, contribution_id: u64) -> bool {
+            self.closed_issues.contains(contribution_id)
+        }
+
+        /// Receive funds sent without a known selector.
+        ///
+        /// In strict funding mode the call is rejected, otherwise the funds are
+        /// recorded in the unattributed funds bucket. Calls without value fail with
+        /// `UnknownSelector`, so that outdated or misspelled selectors are not silently accepted.
+        ///
+        /// Plain balance transfers never run the contract code, hence the contract balance
+        /// can still exceed the tracked funds.
+        #[ink(message, payable, selector = _)]
+        pub fn receive(&mut self) -> Result<(), WorkflowError> {
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(WorkflowError::UnknownSelector);
+            }
+            self.ensure_not_terminated()?;
+            if self.strict_funding {
+                return Err(WorkflowError::UntrackedTransfer);
+            }
+
+            self.unattributed_funds = self
+                .unattributed_funds
+                .checked_add(amount)
+                .ok_or(WorkflowError::Overflow)?;
+
+            self.env().emit_event(UnattributedFundsReceived {
+                from: Self::env().caller(),
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Set whether funds received without context are rejected.
+        #[ink(message)]
+        #[modifiers(only_role(FUNDER))]
+        pub fn set_strict_funding(&mut self, strict_funding: bool) -> Result<(), WorkflowError> {
+            self.env().emit_event(StrictFundingUpdated {
+                account: self.env().caller(),
+                old_strict_funding: self.strict_funding,
+                new_strict_funding: strict_funding,
+            });
+
+            self.strict_funding = strict_funding;
+            Ok(())
+        }
+
+        /// Terminate the contract early.
+        ///
+        /// The free balance (i.e. not owed to an approved contribution) is set aside
+        /// to be refunded with `process_refunds`: the earmarked deposits to their own funders,
+        /// the rest pro-rata to the funders.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn terminate(&mut self) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+
+            // Unwind the parked funds along with the earned yield, refunded to the funders
+            if self.parked > 0 {
+                let parked = self.yield_balance()?;
+                self.yield_withdraw(parked)?;
+                self.parked = 0;
+            }
+
+            let available = self.env().balance().saturating_sub(self.outstanding_obligations()?);
+            let earmarks = self.earmarked.min(available);
+            let refund_pool = available - earmarks;
+            self.refund = Some(Refund {
+                pool: refund_pool,
+                total_funded: self.total_funded,
+                cursor: Cursor::default(),
+                allocated: 0,
+                earmarks,
+                earmark_cursor: Cursor::default(),
+            });
+
+            self.env().emit_event(Terminated { refund_pool });
+
+            Ok(())
+        }
+
+        /// Refund up to `max_funders` funders (capped to `MAX_CHUNK_SIZE`) of a terminated contract.
+        ///
+        /// The earmarked deposits are refunded first, each one to its own funder, then the
+        /// funders are refunded pro-rata to their other deposits.
+        ///
+        /// A refund (or the dust sent to the treasury) which cannot be transferred is credited
+        /// to the internal ledger instead, withdrawable later with `withdraw_pending_to`.
+        ///
+        /// Returns the number of earmarks and funders left to refund.
+        #[ink(message)]
+        pub fn process_refunds(&mut self, max_funders: u32) -> Result<u32, WorkflowError> {
+            let mut refund = self.refund.ok_or(WorkflowError::ContractNotTerminated)?;
+
+            let chunk = refund.earmark_cursor.chunk(max_funders, self.earmarks_count);
+            for index in chunk.indices() {
+                let Some(earmark) = self.earmarks.get(index) else {
+                    continue;
+                };
+                let amount = earmark.remaining.min(refund.earmarks);
+                if amount == 0 {
+                    continue;
+                }
+                refund.earmarks -= amount;
+                refund.total_funded = refund.total_funded.saturating_sub(amount);
+                self.earmarked = self.earmarked.saturating_sub(amount);
+                self.earmarks.insert(index, &Earmark { remaining: 0, ..earmark.clone() });
+                let deposit = self.get_deposit(earmark.funder).saturating_sub(amount);
+                self.deposits.insert(earmark.funder, &deposit);
+                self.refund_funder(earmark.funder, amount)?;
+            }
+            refund.earmark_cursor = chunk.next();
+            let earmarks_left = chunk.remaining();
+            if earmarks_left > 0 {
+                self.refund = Some(refund);
+                return earmarks_left
+                    .checked_add(self.funders_count.saturating_sub(refund.cursor.position()))
+                    .ok_or(WorkflowError::Overflow);
+            }
+
+            let max_funders = max_funders.saturating_sub(chunk.indices().len() as u32);
+            let chunk = refund.cursor.chunk(max_funders, self.funders_count);
+            for index in chunk.indices() {
+                if let Some(funder) = self.funders.get(index) {
+                    // Without deposits left (e.g. all earmarked), the whole pool is sent as dust
+                    let mut amount = if refund.total_funded == 0 {
+                        0
+                    } else {
+                        let deposit = self.deposits.get(funder).unwrap_or_default();
+                        checked_mul_div(deposit, refund.pool, refund.total_funded).ok_or(WorkflowError::Overflow)?
+                    };
+                    refund.allocated = refund.allocated.saturating_add(amount);
+
+                    // Pay the rounding remainder along with the last refund
+                    if chunk.is_last(index) {
+                        let dust = refund.pool.saturating_sub(refund.allocated);
+                        match self.dust_policy {
+                            DustPolicy::Recipient => amount += dust,
+                            DustPolicy::Treasury(treasury) => {
+                                if self.pay(Asset::Native, treasury, dust).is_err() {
+                                    self.defer_payout(treasury, Asset::Native, dust)?;
+                                }
+                            }
+                        }
+                        refund.allocated = refund.pool;
+                    }
+                    if amount == 0 {
+                        continue;
+                    }
+                    self.refund_funder(funder, amount)?;
+                }
+            }
+            refund.cursor = chunk.next();
+            self.refund = Some(refund);
+
+            Ok(chunk.remaining())
+        }
+
+        /// A helper function to refund an `amount` to a `funder`, credited to the internal
+        /// ledger if it cannot be transferred.
+        fn refund_funder(&mut self, funder: AccountId, amount: Balance) -> Result<(), WorkflowError> {
+            if self.env().transfer(funder, amount).is_ok() {
+                let key = (funder, self.current_epoch());
+                let mut statement = self.statements.get(key).unwrap_or_default();
+                statement.refunded = statement.refunded.saturating_add(amount);
+                self.statements.insert(key, &statement);
+
+                self.env().emit_event(FundsRefunded { funder, amount });
+            } else {
+                self.defer_payout(funder, Asset::Native, amount)?;
+            }
+
+            Ok(())
+        }
+
+        /// A helper function to upgrade the contract code to a given `code_hash`, keeping the
+        /// storage, the funds and the approved contributions.
+        ///
+        /// The new code must be able to decode the current storage, `migrate` being called
+        /// afterwards to bring the storage to its version.
+        fn upgrade_code(&mut self, code_hash: Hash) -> Result<(), WorkflowError> {
+            self.env().set_code_hash(&code_hash).map_err(|_| WorkflowError::UpgradeFailed)?;
+
+            self.env().emit_event(CodeUpgraded { code_hash });
+
+            Ok(())
+        }
+
+        /// Migrate the storage to the `STORAGE_VERSION` of the current code, once upgraded.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn migrate(&mut self) -> Result<(), WorkflowError> {
+            let from = self.version;
+            if from >= STORAGE_VERSION {
+                return Err(WorkflowError::AlreadyMigrated);
+            }
+
+            // The migrations of every version go here, in order
+            self.version = STORAGE_VERSION;
+
+            self.env().emit_event(Migrated { from, to: STORAGE_VERSION });
+
+            Ok(())
+        }
+
+        /// Simply returns the version of the storage layout.
+        #[ink(message)]
+        pub fn get_version(&self) -> u32 {
+            self.version
+        }
+
+        /// Set the emergency guardian, allowed to `pause` but nothing else.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_guardian(&mut self, guardian: Option<AccountId>) -> Result<(), WorkflowError> {
+            self.env().emit_event(GuardianUpdated {
+                account: self.env().caller(),
+                old_guardian: self.guardian,
+                new_guardian: guardian,
+            });
+
+            self.guardian = guardian;
+            Ok(())
+        }
+
+        /// Set the `backup` account allowed to assume ownership if the owner does not send
+        /// a heartbeat within the `heartbeat_period` (in milliseconds).
+        ///
+        /// Counts as a heartbeat.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_backup(
+            &mut self,
+            backup: Option<AccountId>,
+            heartbeat_period: Timestamp,
+        ) -> Result<(), WorkflowError> {
+            if backup.is_some() && heartbeat_period == 0 {
+                return Err(WorkflowError::InvalidHeartbeatPeriod);
+            }
+
+            self.env().emit_event(BackupUpdated {
+                account: self.env().caller(),
+                old_backup: self.backup,
+                old_heartbeat_period: self.heartbeat_period,
+                new_backup: backup,
+                new_heartbeat_period: heartbeat_period,
+            });
+
+            self.backup = backup;
+            self.heartbeat_period = heartbeat_period;
+            self.last_heartbeat = self.env().block_timestamp();
+            Ok(())
+        }
+
+        /// Prove the owner is still active, postponing the backup takeover.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn heartbeat(&mut self) -> Result<(), WorkflowError> {
+            self.last_heartbeat = self.env().block_timestamp();
+            Ok(())
+        }
+
+        /// Assume ownership as the backup account once the heartbeat period has elapsed
+        /// since the last owner heartbeat, the roles of the owner being transferred to the backup.
+        ///
+        /// The backup is cleared, the new owner being expected to designate a new one.
+        /// An `OwnershipAssumed` event is emitted.
+        #[ink(message)]
+        pub fn assume_ownership(&mut self) -> Result<(), WorkflowError> {
+            let caller = self.env().caller();
+            if Some(caller) != self.backup {
+                return Err(WorkflowError::CallerIsNotBackup);
+            }
+            if self.env().block_timestamp() < self.last_heartbeat.saturating_add(self.heartbeat_period) {
+                return Err(WorkflowError::OwnerStillActive);
+            }
+
+            let last_heartbeat = self.last_heartbeat;
+            self.hand_over_ownership(caller);
+            self.backup = None;
+            self.last_heartbeat = self.env().block_timestamp();
+
+            self.env().emit_event(OwnershipAssumed {
+                backup: caller,
+                last_heartbeat,
+            });
+
+            Ok(())
+        }
+
+        /// Start transferring the ownership to a multisig contract, which must accept it
+        /// with `accept_ownership` to complete the transfer.
+        ///
+        /// The target is probed first through the `Multisig` standard, so that the ownership
+        /// is never handed to an address that can never sign. An `OwnershipTransferStarted`
+        /// event is emitted.
+        ///
+        /// Constraint(s):
+        /// 1. The `multisig` must be a contract reporting to be a multisig with owners.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn transfer_ownership_to_contract(&mut self, multisig: AccountId) -> Result<(), WorkflowError> {
+            if !self.is_multisig(multisig) {
+                return Err(WorkflowError::NotAMultisig);
+            }
+
+            self.pending_owner = Some(multisig);
+
+            self.env().emit_event(OwnershipTransferStarted { pending_owner: multisig });
+
+            Ok(())
+        }
+
+        /// Accept the ownership as the pending owner, the roles of the owner being
+        /// transferred to the caller.
+        ///
+        /// An `OwnershipAccepted` event is emitted.
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<(), WorkflowError> {
+            let caller = self.env().caller();
+            if Some(caller) != self.pending_owner {
+                return Err(WorkflowError::CallerIsNotPendingOwner);
+            }
+
+            let previous_owner = Ownable::owner(self);
+            self.hand_over_ownership(caller);
+            self.pending_owner = None;
+
+            self.env().emit_event(OwnershipAccepted {
+                previous_owner,
+                owner: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Simply returns the multisig the ownership is being transferred to if some.
+        #[ink(message)]
+        pub fn get_pending_owner(&self) -> Option<AccountId> {
+            self.pending_owner
+        }
+
+        /// Simply returns the backup account if some.
+        #[ink(message)]
+        pub fn get_backup(&self) -> Option<AccountId> {
+            self.backup
+        }
+
+        /// Simply returns the heartbeat period (in milliseconds).
+        #[ink(message)]
+        pub fn get_heartbeat_period(&self) -> Timestamp {
+            self.heartbeat_period
+        }
+
+        /// Simply returns the timestamp of the last owner heartbeat.
+        #[ink(message)]
+        pub fn get_last_heartbeat(&self) -> Timestamp {
+            self.last_heartbeat
+        }
+
+        /// Set the faucet triggered upon identity registration, if some.
+        ///
+        /// The contract must be allowed as a caller by the faucet.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_faucet(&mut self, faucet: Option<AccountId>) -> Result<(), WorkflowError> {
+            self.env().emit_event(FaucetUpdated {
+                account: self.env().caller(),
+                old_faucet: self.faucet,
+                new_faucet: faucet,
+            });
+
+            self.faucet = faucet;
+            Ok(())
+        }
+
+        /// Set the reputation source implementing the `Reputation` trait, if some.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_reputation(&mut self, reputation: Option<AccountId>) -> Result<(), WorkflowError> {
+            self.env().emit_event(ReputationUpdated {
+                account: self.env().caller(),
+                old_reputation: self.reputation,
+                new_reputation: reputation,
+            });
+
+            self.reputation = reputation;
+            Ok(())
+        }
+
+        /// Trust the reputation attestations of another Kudos `source` deployment, signed by a
+        /// given ECDSA `key`, or stop trusting them if none.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_reputation_source(&mut self, source: AccountId, key: Option<AccountId>) -> Result<(), WorkflowError> {
+            self.env().emit_event(ReputationSourceUpdated {
+                account: self.env().caller(),
+                source,
+                old_key: self.reputation_sources.get(source),
+                new_key: key,
+            });
+
+            if let Some(key) = key {
+                self.reputation_sources.insert(source, &key);
+            } else {
+                self.reputation_sources.remove(source);
+            }
+            Ok(())
+        }
+
+        /// Import the reputation of an identity from another Kudos deployment, so that
+        /// contributors don't start from zero on every repository. Callable by anyone.
+        ///
+        /// The `signature` must be issued by the key of the trusted source deployment over the
+        /// Blake2x256 hash of the SCALE encoded `attestation`. The highest reputation imported
+        /// for an identity is kept, and complements the reputation source.
+        ///
+        /// A `ReputationImported` event is emitted.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn import_reputation(
+            &mut self,
+            attestation: ReputationAttestation,
+            signature: SignatureValue,
+        ) -> Result<(), WorkflowError> {
+            let key = self
+                .reputation_sources
+                .get(attestation.source)
+                .ok_or(WorkflowError::UnknownReputationSource)?;
+            if self.recover_signer(&Self::hash_payload(&attestation), &signature)? != key {
+                return Err(WorkflowError::InvalidSignature);
+            }
+
+            let points = attestation.points.max(self.get_imported_reputation(attestation.identity));
+            self.imported_reputations.insert(attestation.identity, &points);
+
+            self.env().emit_event(ReputationImported {
+                identity: attestation.identity,
+                source: attestation.source,
+                points: attestation.points,
+            });
+
+            Ok(())
+        }
+
+        /// Simply returns the ECDSA key of a given trusted `source` deployment if some.
+        #[ink(message)]
+        pub fn get_reputation_source(&self, source: AccountId) -> Option<AccountId> {
+            self.reputation_sources.get(source)
+        }
+
+        /// Simply returns the reputation points imported for a given `identity`.
+        #[ink(message)]
+        pub fn get_imported_reputation(&self, identity: HashValue) -> u64 {
+            self.imported_reputations.get(identity).unwrap_or_default()
+        }
+
+        /// Set the minimum reputation of the contributors approved from templates of a given `tier`, if some.
+        ///
+        /// Contributors are considered without reputation if no reputation source is set.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_tier_min_reputation(&mut self, tier: u8, min_reputation: Option<u64>) -> Result<(), WorkflowError> {
+            self.env().emit_event(TierMinReputationUpdated {
+                account: self.env().caller(),
+                tier,
+                old_min_reputation: self.tier_min_reputations.get(tier),
+                new_min_reputation: min_reputation,
+            });
+
+            if let Some(min_reputation) = min_reputation {
+                self.tier_min_reputations.insert(tier, &min_reputation);
+            } else {
+                self.tier_min_reputations.remove(tier);
+            }
+            Ok(())
+        }
+
+        /// Simply returns the minimum reputation of a given `tier` if some.
+        #[ink(message)]
+        pub fn get_tier_min_reputation(&self, tier: u8) -> Option<u64> {
+            self.tier_min_reputations.get(tier)
+        }
+
+        /// Set the community voting configuration:
+        /// - the `badge` collection whose holders vote on the proposed contributions tier, if some.
+        /// - the `voting_period` (in milliseconds) of the votes.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_voting(&mut self, badge: Option<AccountId>, voting_period: Timestamp) -> Result<(), WorkflowError> {
+            self.env().emit_event(VotingUpdated {
+                account: self.env().caller(),
+                old_badge: self.badge,
+                old_voting_period: self.voting_period,
+                new_badge: badge,
+                new_voting_period: voting_period,
+            });
+
+            self.badge = badge;
+            self.voting_period = voting_period;
+            Ok(())
+        }
+
+        /// A helper function to set the `reward` of a given votable `tier`, or remove the tier if none.
+        fn update_tier_reward(&mut self, account: AccountId, tier: u8, reward: Option<RewardAmount>) {
+            let old_reward = self.tier_rewards.get(tier);
+            let new_reward = reward.map(|reward| reward.plancks());
+            if let Some(reward) = new_reward {
+                self.tier_rewards.insert(tier, &reward);
+            } else {
+                self.tier_rewards.remove(tier);
+            }
+
+            self.env().emit_event(TierUpdated {
+                account,
+                tier,
+                old_reward,
+                new_reward,
+            });
+        }
+
+        /// Propose a given `contribution_id` to the badge holders, voting on its tier before approval.
+        ///
+        /// A `ContributionProposed` event is emitted.
+        #[ink(message)]
+        #[modifiers(only_role(APPROVER), when_not_paused)]
+        pub fn propose(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+            if self.badge.is_none() {
+                return Err(WorkflowError::BadgeNotSet);
+            }
+            if self.contributions.contains(contribution_id)
+                || self.proposals.contains(contribution_id)
+                || self.voted_rewards.contains(contribution_id)
+            {
+                return Err(WorkflowError::ContributionAlreadyApproved);
+            }
+
+            let ends_at = self
+                .env()
+                .block_timestamp()
+                .checked_add(self.voting_period)
+                .ok_or(WorkflowError::Overflow)?;
+            self.proposals.insert(
+                contribution_id,
+                &Proposal {
+                    ends_at,
+                    tallies: Vec::new(),
+                },
+            );
+
+            self.env().emit_event(ContributionProposed {
+                id: contribution_id,
+                ends_at,
+            });
+
+            Ok(())
+        }
+
+        /// Vote on the `tier` of a proposed `contribution_id`, once per badge holder.
+        ///
+        /// A `TierVoted` event is emitted.
+        #[ink(message)]
+        pub fn vote(&mut self, contribution_id: u64, tier: u8) -> Result<(), WorkflowError> {
+            let mut proposal = self
+                .proposals
+                .get(contribution_id)
+                .ok_or(WorkflowError::NoProposal)?;
+            if self.env().block_timestamp() >= proposal.ends_at {
+                return Err(WorkflowError::VotingClosed);
+            }
+            if !self.tier_rewards.contains(tier) {
+                return Err(WorkflowError::UnknownTier);
+            }
+
+            let voter = self.env().caller();
+            if self.votes.contains((contribution_id, voter)) {
+                return Err(WorkflowError::AlreadyVoted);
+            }
+            if !self.holds_badge(voter)? {
+                return Err(WorkflowError::CallerIsNotBadgeHolder);
+            }
+
+            match proposal.tallies.iter_mut().find(|(voted_tier, _)| *voted_tier == tier) {
+                Some((_, count)) => *count += 1,
+                None => proposal.tallies.push((tier, 1)),
+            }
+            self.proposals.insert(contribution_id, &proposal);
+            self.votes.insert((contribution_id, voter), &());
+
+            self.env().emit_event(TierVoted {
+                id: contribution_id,
+                voter,
+                tier,
+            });
+
+            Ok(())
+        }
+
+        /// Close the tier vote of a proposed `contribution_id` once the voting period is over.
+        ///
+        /// The most voted tier wins, the lowest one on ties, and its reward is paid upon claim
+        /// once the contribution is approved. The default reward applies if nobody voted.
+        /// A `TierVoteClosed` event is emitted.
+        #[ink(message)]
+        pub fn close_vote(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            let proposal = self
+                .proposals
+                .get(contribution_id)
+                .ok_or(WorkflowError::NoProposal)?;
+            if self.env().block_timestamp() < proposal.ends_at {
+                return Err(WorkflowError::VotingOpen);
+            }
+
+            let tier = proposal
+                .tallies
+                .iter()
+                .max_by(|(tier_a, count_a), (tier_b, count_b)| count_a.cmp(count_b).then(tier_b.cmp(tier_a)))
+                .map(|(tier, _)| *tier);
+            let reward = tier
+                .and_then(|tier| self.tier_rewards.get(tier))
+                .unwrap_or(self.reward);
+
+            self.proposals.remove(contribution_id);
+            self.voted_rewards.insert(contribution_id, &reward);
+
+            self.env().emit_event(TierVoteClosed {
+                id: contribution_id,
+                tier,
+                reward,
+            });
+
+            Ok(())
+        }
+
+        /// Simply returns the badge collection and the voting period.
+        #[ink(message)]
+        pub fn get_voting(&self) -> (Option<AccountId>, Timestamp) {
+            (self.badge, self.voting_period)
+        }
+
+        /// Simply returns the reward of a given votable `tier` if some.
+        #[ink(message)]
+        pub fn get_tier_reward(&self, tier: u8) -> Option<Balance> {
+            self.tier_rewards.get(tier)
+        }
+
+        /// Simply returns the open proposal of a given `contribution_id` if some.
+        #[ink(message)]
+        pub fn get_proposal(&self, contribution_id: u64) -> Option<Proposal> {
+            self.proposals.get(contribution_id)
+        }
+
+        /// Simply returns the voted reward of a given `contribution_id` if some.
+        #[ink(message)]
+        pub fn get_voted_reward(&self, contribution_id: u64) -> Option<Balance> {
+            self.voted_rewards.get(contribution_id)
+        }
+
+        /// A helper function to check whether an `account` holds a badge.
+        fn holds_badge(&self, account: AccountId) -> Result<bool, WorkflowError> {
+            let badge = self.badge.ok_or(WorkflowError::BadgeNotSet)?;
+
+            ink::env::call::build_call::<Environment>()
+                .call(badge)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("PSP34::balance_of"),
+                    ))
+                    .push_arg(account),
+                )
+                .returns::<u32>()
+                .try_invoke()
+                .ok()
+                .and_then(Result::ok)
+                .map(|balance| balance > 0)
+                .ok_or(WorkflowError::CallerIsNotBadgeHolder)
+        }
+
+        /// Simply returns the reputation source if some.
+        #[ink(message)]
+        pub fn get_reputation(&self) -> Option<AccountId> {
+            self.reputation
+        }
+
+        /// A helper function to set the vesting contract implementing the `Vesting` trait, if some.
+        ///
+        /// Once set, the native rewards are registered into a vesting schedule of the
+        /// contributor instead of being transferred. The contract must be allowed as a
+        /// caller by the vesting contract.
+        fn update_vesting(&mut self, account: AccountId, vesting: Option<AccountId>) {
+            self.env().emit_event(VestingUpdated {
+                account,
+                old_vesting: self.vesting,
+                new_vesting: vesting,
+            });
+
+            self.vesting = vesting;
+        }
+
+        /// Simply returns the vesting contract if some.
+        #[ink(message)]
+        pub fn get_vesting(&self) -> Option<AccountId> {
+            self.vesting
+        }
+
+        /// Set the lifecycle hook implementing the `LifecycleHook` trait (e.g. the event bridge), if some.
+        ///
+        /// Once set, the hook is notified of every contribution lifecycle transition
+        /// (approved, finalized, claimed and revoked).
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_lifecycle_hook(&mut self, lifecycle_hook: Option<AccountId>) -> Result<(), WorkflowError> {
+            self.env().emit_event(LifecycleHookUpdated {
+                account: self.env().caller(),
+                old_lifecycle_hook: self.lifecycle_hook,
+                new_lifecycle_hook: lifecycle_hook,
+            });
+
+            self.lifecycle_hook = lifecycle_hook;
+            Ok(())
+        }
+
+        /// Simply returns the lifecycle hook if some.
+        #[ink(message)]
+        pub fn get_lifecycle_hook(&self) -> Option<AccountId> {
+            self.lifecycle_hook
+        }
+
+        /// A helper function to set the insurance covering every approval, if some.
+        ///
+        /// Once set, a premium is paid out of the funds to the insurance contract upon every
+        /// approval, so that the contributor is compensated if the reward cannot be paid.
+        fn update_insurance(&mut self, account: AccountId, insurance: Option<InsuranceConfig>) {
+            self.env().emit_event(InsuranceUpdated {
+                account,
+                old_insurance: self.insurance,
+                new_insurance: insurance,
+            });
+
+            self.insurance = insurance;
+        }
+
+        /// Simply returns the insurance if some.
+        #[ink(message)]
+        pub fn get_insurance(&self) -> Option<InsuranceConfig> {
+            self.insurance
+        }
+
+        /// A helper function to set the yield adapter implementing the `YieldAdapter` trait, if some.
+        ///
+        /// Fails if funds are still parked in the current yield adapter.
+        fn update_yield_adapter(
+            &mut self,
+            account: AccountId,
+            yield_adapter: Option<AccountId>,
+        ) -> Result<(), WorkflowError> {
+            if self.parked > 0 {
+                return Err(WorkflowError::FundsParked);
+            }
+
+            self.env().emit_event(YieldAdapterUpdated {
+                account,
+                old_yield_adapter: self.yield_adapter,
+                new_yield_adapter: yield_adapter,
+            });
+
+            self.yield_adapter = yield_adapter;
+            Ok(())
+        }
+
+        /// Set the routing policy of the yield earned by the parked funds.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_yield_policy(&mut self, yield_policy: YieldPolicy) -> Result<(), WorkflowError> {
+            self.env().emit_event(YieldPolicyUpdated {
+                account: self.env().caller(),
+                old_yield_policy: self.yield_policy,
+                new_yield_policy: yield_policy,
+            });
+
+            self.yield_policy = yield_policy;
+            Ok(())
+        }
+
+        /// Park `amount` of the idle funds in the yield adapter.
+        ///
+        /// Parked funds are unwound upon claim when the contract balance is not enough.
+        #[ink(message)]
+        #[modifiers(only_role(FUNDER))]
+        pub fn park(&mut self, amount: Balance) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+            let yield_adapter = self.yield_adapter.ok_or(WorkflowError::YieldAdapterNotSet)?;
+            let parked = self.parked.checked_add(amount).ok_or(WorkflowError::Overflow)?;
+
+            let deposited = ink::env::call::build_call::<Environment>()
+                .call(yield_adapter)
+                .transferred_value(amount)
+                .exec_input(ink::env::call::ExecutionInput::new(
+                    ink::env::call::Selector::new(ink::selector_bytes!("YieldAdapter::deposit")),
+                ))
+                .returns::<Result<(), YieldAdapterError>>()
+                .try_invoke();
+            if !matches!(deposited, Ok(Ok(Ok(())))) {
+                return Err(WorkflowError::YieldAdapterFailed);
+            }
+
+            self.parked = parked;
+            Ok(())
+        }
+
+        /// Unpark `amount` of the parked funds from the yield adapter.
+        #[ink(message)]
+        #[modifiers(only_role(FUNDER))]
+        pub fn unpark(&mut self, amount: Balance) -> Result<(), WorkflowError> {
+            let parked = self.parked.checked_sub(amount).ok_or(WorkflowError::InsufficientParkedFunds)?;
+            self.yield_withdraw(amount)?;
+            self.parked = parked;
+            Ok(())
+        }
+
+        /// Harvest the yield earned by the parked funds, routed according to the yield policy.
+        ///
+        /// Returns the harvested amount.
+        #[ink(message)]
+        pub fn harvest(&mut self) -> Result<Balance, WorkflowError> {
+            let amount = self.yield_balance()?.saturating_sub(self.parked);
+            if amount == 0 {
+                return Ok(0);
+            }
+
+            self.yield_withdraw(amount)?;
+            if let YieldPolicy::Treasury(treasury) = self.yield_policy {
+                self.pay(Asset::Native, treasury, amount)?;
+            }
+
+            self.env().emit_event(YieldHarvested {
+                amount,
+                policy: self.yield_policy,
+            });
+
+            Ok(amount)
+        }
+
+        /// Simply returns the yield adapter if some.
+        #[ink(message)]
+        pub fn get_yield_adapter(&self) -> Option<AccountId> {
+            self.yield_adapter
+        }
+
+        /// Simply returns the routing policy of the earned yield.
+        #[ink(message)]
+        pub fn get_yield_policy(&self) -> YieldPolicy {
+            self.yield_policy
+        }
+
+        /// Simply returns the amount parked in the yield adapter, excluding the earned yield.
+        #[ink(message)]
+        pub fn get_parked(&self) -> Balance {
+            self.parked
+        }
+
+        /// Set the circuit breaker, pausing the contract once the reward outflow over the
+        /// sliding window exceeds the threshold, if some.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_circuit_breaker(&mut self, circuit_breaker: Option<CircuitBreaker>) -> Result<(), WorkflowError> {
+            if matches!(circuit_breaker, Some(breaker) if breaker.window == 0) {
+                return Err(WorkflowError::InvalidCircuitBreaker);
+            }
+
+            self.env().emit_event(BudgetCapUpdated {
+                account: self.env().caller(),
+                old_circuit_breaker: self.circuit_breaker,
+                new_circuit_breaker: circuit_breaker,
+            });
+
+            self.circuit_breaker = circuit_breaker;
+            Ok(())
+        }
+
+        /// Simply returns the circuit breaker if some.
+        #[ink(message)]
+        pub fn get_circuit_breaker(&self) -> Option<CircuitBreaker> {
+            self.circuit_breaker
+        }
+
+        /// Returns the estimated reward outflow over the sliding window.
+        #[ink(message)]
+        pub fn get_outflow(&self) -> Result<Balance, WorkflowError> {
+            self.outflow_after(0).map(|(_, estimated_outflow)| estimated_outflow)
+        }
+
+        /// Pause the contract, callable by the pausers or the guardian.
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), WorkflowError> {
+            let caller = Self::env().caller();
+            if Some(caller) != self.guardian && !AccessControl::has_role(self, PAUSER, Some(caller)) {
+                return Err(WorkflowError::CallerIsNotGuardian);
+            }
+
+            pausable::Internal::_pause(self)?;
+
+            self.env().emit_event(Paused { account: caller });
+
+            Ok(())
+        }
+
+        /// Unpause the contract, only the pausers can resume operations.
+        #[ink(message)]
+        #[modifiers(only_role(PAUSER))]
+        pub fn unpause(&mut self) -> Result<(), WorkflowError> {
+            pausable::Internal::_unpause(self)?;
+
+            self.env().emit_event(Unpaused {
+                account: Self::env().caller(),
+            });
+
+            Ok(())
+        }
+
+        /// Register the caller as an aspiring contributor.
+        ///
+        /// An account binds one identity only, unless multiple identities are allowed.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn register_identity(&mut self, identity: HashValue) -> Result<(), WorkflowError> {
+            self.register_identity_with_proof(identity, Vec::new())
+        }
+
+        /// Register the caller as an aspiring contributor, with a `proof` validated by the
+        /// identity verifier if set (e.g. a signature or an attestation).
+        ///
+        /// If the attestor key is set, the `proof` must be the attestor signature over the
+        /// Blake2x256 hash of the SCALE encoded `(identity, caller)`, proving the caller owns
+        /// the identity.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn register_identity_with_proof(
+            &mut self,
+            identity: HashValue,
+            proof: Vec<u8>,
+        ) -> Result<(), WorkflowError> {
+            if self.canonical_identities {
+                return Err(WorkflowError::HandleRequired);
+            }
+
+            self.register_with_proof(identity, proof)
+        }
+
+        /// Register the caller as an aspiring contributor of a given platform `identity`, keyed
+        /// by `identity_key`. See `register_identity_with_proof` for the `proof`.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn register_platform_identity(&mut self, identity: Identity, proof: Vec<u8>) -> Result<(), WorkflowError> {
+            let key = Self::identity_key(&identity);
+            if self.identity_is_known(key) {
+                return Err(WorkflowError::IdentityAlreadyRegistered);
+            }
+
+            if identity.platform != Platform::GitHub {
+                self.platforms.insert(key, &identity.platform);
+            }
+            self.register_identity_with_proof(key, proof)
+        }
+
+        /// Simply returns the platform of a given `identity`, GitHub unless registered from
+        /// another platform.
+        #[ink(message)]
+        pub fn get_platform(&self, identity: HashValue) -> Platform {
+            self.platforms.get(identity).unwrap_or_default()
+        }
+
+        /// Register the caller as an aspiring contributor from its `handle` (e.g. the Github
+        /// login), the identity being the hash of the `handle` as is. See `register_identity_with_proof`
+        /// for the `proof`.
+        ///
+        /// Constraint(s):
+        /// 1. The canonical form of the `handle` (i.e. lowercase, Github logins being case
+        /// insensitive) must not collide with a registered handle, the error including the
+        /// account the colliding handle is registered for.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn register_handle(&mut self, handle: String, proof: Vec<u8>) -> Result<(), WorkflowError> {
+            let canonical_identity = Self::hash(handle.to_ascii_lowercase().as_bytes());
+            if let Some(account) = self.canonical_accounts.get(canonical_identity) {
+                return Err(WorkflowError::IdentityCollision(account));
+            }
+
+            let identity = Self::hash(handle.as_bytes());
+            self.register_with_proof(identity, proof)?;
+            self.canonical_accounts
+                .insert(canonical_identity, &self.env().caller());
+            self.canonical_of.insert(identity, &canonical_identity);
+
+            Ok(())
+        }
+
+        /// Enable or disable the canonical identities, requiring registrations from the
+        /// handles with `register_handle`.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_canonical_identities(&mut self, canonical_identities: bool) -> Result<(), WorkflowError> {
+            self.env().emit_event(CanonicalIdentitiesUpdated {
+                account: self.env().caller(),
+                old_canonical_identities: self.canonical_identities,
+                new_canonical_identities: canonical_identities,
+            });
+
+            self.canonical_identities = canonical_identities;
+            Ok(())
+        }
+
+        /// Simply checks if the canonical identities are enabled.
+        #[ink(message)]
+        pub fn is_canonical_identities(&self) -> bool {
+            self.canonical_identities
+        }
+
+        /// Unregister an `identity` as its registered account (e.g. before losing its keys),
+        /// so that it can be registered again against a new account.
+        ///
+        /// The unclaimed rewards of the identity become claimable by the account it is registered
+        /// again for. An `IdentityUnregistered` event is emitted.
+        #[ink(message)]
+        pub fn unregister_identity(&mut self, identity: HashValue) -> Result<(), WorkflowError> {
+            let account = self.get_local_account(identity)?;
+            if account != self.env().caller() {
+                return Err(WorkflowError::CallerIsNotContributor);
+            }
+
+            self.unbind(identity, account);
+
+            Ok(())
+        }
+
+        /// Rotate a given `identity` to a `new_account` (e.g. upon a wallet migration) as its
+        /// registered account, the unclaimed rewards of the identity becoming claimable by the
+        /// new account.
+        ///
+        /// Any queued unbinding of the identity is cancelled. An `IdentityRotated` event is emitted.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn rotate_identity(&mut self, identity: HashValue, new_account: AccountId) -> Result<(), WorkflowError> {
+            let account = self.get_local_account(identity)?;
+            if account != self.env().caller() {
+                return Err(WorkflowError::CallerIsNotContributor);
+            }
+
+            self.ensure_unbound(new_account)?;
+
+            self.identities.insert(identity, &new_account);
+            if self.identity_of.get(account) == Some(identity) {
+                self.identity_of.remove(account);
+            }
+            self.identity_of.insert(new_account, &identity);
+            self.dequeue_unbinding(identity);
+            if let Some(canonical_identity) = self.canonical_of.get(identity) {
+                self.canonical_accounts.insert(canonical_identity, &new_account);
+            }
+
+            self.env().emit_event(IdentityRotated {
+                identity,
+                previous_account: account,
+                account: new_account,
+            });
+
+            Ok(())
+        }
+
+        /// Queue the unregistration of an `identity` on behalf of a contributor who lost the
+        /// keys of its account, executable with `force_unregister_identity` once the
+        /// `IdentityUnbinding` change delay has elapsed.
+        ///
+        /// The registered account can still unregister (or keep) the identity meanwhile.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn queue_identity_unbinding(&mut self, identity: HashValue) -> Result<(), WorkflowError> {
+            self.get_local_account(identity)?;
+            if self.unbinding_etas.contains(identity) {
+                return Err(WorkflowError::ChangeAlreadyQueued);
+            }
+
+            let eta = self
+                .env()
+                .block_timestamp()
+                .checked_add(self.get_change_delay(Parameter::IdentityUnbinding))
+                .ok_or(WorkflowError::Overflow)?;
+            self.unbinding_etas.insert(identity, &eta);
+            self.unbinding_positions.insert(identity, &self.unbinding_count);
+            self.unbinding_index.insert(self.unbinding_count, &identity);
+            self.unbinding_count = self
+                .unbinding_count
+                .checked_add(1)
+                .ok_or(WorkflowError::Overflow)?;
+
+            self.env().emit_event(IdentityUnbindingQueued { identity, eta });
+
+            Ok(())
+        }
+
+        /// Unregister an `identity` once its queued unbinding delay has elapsed.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn force_unregister_identity(&mut self, identity: HashValue) -> Result<(), WorkflowError> {
+            self.execute_ready_unbinding(identity)
+        }
+
+        /// A helper function to unregister an `identity` once its queued unbinding delay has elapsed.
+        fn execute_ready_unbinding(&mut self, identity: HashValue) -> Result<(), WorkflowError> {
+            let eta = self
+                .unbinding_etas
+                .get(identity)
+                .ok_or(WorkflowError::ChangeNotQueued)?;
+            if self.env().block_timestamp() < eta {
+                return Err(WorkflowError::ChangeNotReady);
+            }
+
+            let account = self.get_local_account(identity)?;
+            self.unbind(identity, account);
+
+            Ok(())
+        }
+
+        /// Simply returns the timestamp the unbinding of a given `identity` is executable at, if queued.
+        #[ink(message)]
+        pub fn get_unbinding_eta(&self, identity: HashValue) -> Option<Timestamp> {
+            self.unbinding_etas.get(identity)
+        }
+
+        /// Allow or disallow an account to register multiple identities (e.g. an organization
+        /// rewarded for several bot or team identities), one identity per account by default.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_multiple_identities(&mut self, multiple_identities: bool) -> Result<(), WorkflowError> {
+            self.env().emit_event(MultipleIdentitiesUpdated {
+                account: self.env().caller(),
+                old_multiple_identities: self.multiple_identities,
+                new_multiple_identities: multiple_identities,
+            });
+
+            self.multiple_identities = multiple_identities;
+            Ok(())
+        }
+
+        /// Simply checks if an account can register multiple identities.
+        #[ink(message)]
+        pub fn is_multiple_identities(&self) -> bool {
+            self.multiple_identities
+        }
+
+        /// Returns the locally registered identities along with their account, from a given
+        /// `offset` up to `limit` entries (capped to `MAX_CHUNK_SIZE`), so that off-chain
+        /// databases can be reconciled against the contract.
+        ///
+        /// The order is not stable across unregistrations, the last identity taking the place of
+        /// the unregistered one.
+        #[ink(message)]
+        pub fn get_identities(&self, offset: u32, limit: u32) -> Vec<(HashValue, AccountId)> {
+            Cursor::new(offset)
+                .chunk(limit, self.identity_count)
+                .indices()
+                .filter_map(|index| self.identity_index.get(index))
+                .filter_map(|identity| self.identities.get(identity).map(|account| (identity, account)))
+                .collect()
+        }
+
+        /// Simply returns the number of locally registered identities.
+        #[ink(message)]
+        pub fn get_identity_count(&self) -> u32 {
+            self.identity_count
+        }
+
+        /// Simply returns the identity registered by a given `account` if some, the last
+        /// registered one if multiple.
+        #[ink(message)]
+        pub fn get_identity_of(&self, account: AccountId) -> Option<HashValue> {
+            self.identity_of.get(account)
+        }
+
+        /// A helper function to ensure an `account` is not bound to any identity yet, unless
+        /// multiple identities per account are allowed.
+        fn ensure_unbound(&self, account: AccountId) -> Result<(), WorkflowError> {
+            if !self.multiple_identities && self.identity_of.contains(account) {
+                return Err(WorkflowError::AccountAlreadyBound);
+            }
+            Ok(())
+        }
+
+        /// A helper function to get the account of an `identity` registered in the storage.
+        fn get_local_account(&self, identity: HashValue) -> Result<AccountId, WorkflowError> {
+            if self.identity_registry.is_some() {
+                return Err(WorkflowError::IdentityNotUnregistrable);
+            }
+            self.identities
+                .get(identity)
+                .ok_or(WorkflowError::UnknownContributor)
+        }
+
+        /// A helper function to unbind an `identity` from its `account`, releasing its handle.
+        fn unbind(&mut self, identity: HashValue, account: AccountId) {
+            self.identities.remove(identity);
+            // Swap the last indexed identity into the position of the removed one
+            if let Some(position) = self.identity_positions.take(identity) {
+                self.identity_count = self.identity_count.saturating_sub(1);
+                if let Some(last) = self.identity_index.take(self.identity_count) {
+                    if last != identity {
+                        self.identity_index.insert(position, &last);
+                        self.identity_positions.insert(last, &position);
+                    }
+                }
+            }
+            if self.identity_of.get(account) == Some(identity) {
+                self.identity_of.remove(account);
+            }
+            self.attestations.remove(identity);
+            self.remark_proofs.remove(identity);
+            self.dequeue_unbinding(identity);
+            if let Some(canonical_identity) = self.canonical_of.take(identity) {
+                self.canonical_accounts.remove(canonical_identity);
+            }
+
+            self.env().emit_event(IdentityUnregistered { identity, account });
+        }
+
+        /// A helper function to remove the queued unbinding of an `identity` if some.
+        fn dequeue_unbinding(&mut self, identity: HashValue) {
+            self.unbinding_etas.remove(identity);
+            // Swap the last queued unbinding into the position of the removed one
+            if let Some(position) = self.unbinding_positions.take(identity) {
+                self.unbinding_count = self.unbinding_count.saturating_sub(1);
+                if let Some(last) = self.unbinding_index.take(self.unbinding_count) {
+                    if last != identity {
+                        self.unbinding_index.insert(position, &last);
+                        self.unbinding_positions.insert(last, &position);
+                    }
+                }
+            }
+        }
+
+        /// A helper function to register the caller with a `proof` for a given `identity`.
+        fn register_with_proof(&mut self, identity: HashValue, proof: Vec<u8>) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+
+            if self.identity_is_known(identity) {
+                return Err(WorkflowError::IdentityAlreadyRegistered);
+            }
+
+            let caller = Self::env().caller();
+            self.verify_ownership(identity, caller, &proof)?;
+            self.verify_identity(identity, caller, proof)?;
+            self.register(identity, caller)?;
+
+            Ok(())
+        }
+
+        /// Import a batch of `identities` (with their account) and `contributions` (with their
+        /// identity and whether the reward is claimed) exported from a legacy reward contract
+        /// (e.g. `asset_reward`), replaying them into the storage.
+        ///
+        /// The claimed contributions are recorded as claimed, without any payout. The unclaimed
+        /// ones are approved again, reserving their reward: the legacy funds must be transferred
+        /// first.
+        ///
+        /// The `IdentityRegistered` and `ContributionImported` events are emitted.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn import_state(
+            &mut self,
+            identities: Vec<(HashValue, AccountId)>,
+            contributions: Vec<(u64, HashValue, bool)>,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+            if identities.len() + contributions.len() > MAX_IMPORT_BATCH {
+                return Err(WorkflowError::ImportBatchTooLarge);
+            }
+
+            for (identity, account) in identities {
+                if self.identity_is_known(identity) {
+                    return Err(WorkflowError::IdentityAlreadyRegistered);
+                }
+                self.register(identity, account)?;
+            }
+
+            for (contribution_id, identity, is_reward_claimed) in contributions {
+                if is_reward_claimed {
+                    if self.contributions.contains(contribution_id) {
+                        return Err(WorkflowError::ContributionAlreadyApproved);
+                    }
+                    let contributor = self.get_account(identity).ok_or(WorkflowError::UnknownContributor)?;
+                    let contribution = Contribution::approve(contribution_id, identity, contributor, None, None);
+                    self.contributions.insert(contribution_id, &contribution.claim());
+                    self.approval_blocks.insert(contribution_id, &self.env().block_number());
+                    self.index_contribution(contribution_id, contributor)?;
+                    self.approved_count = self.approved_count.checked_add(1).ok_or(WorkflowError::Overflow)?;
+
+                    // Imported contributors are not first-time contributors anymore
+                    let person = self.person_of(identity);
+                    let claims_count = self.claims_count.get(person).unwrap_or_default();
+                    self.claims_count
+                        .insert(person, &claims_count.checked_add(1).ok_or(WorkflowError::Overflow)?);
+                } else {
+                    self.approve_contribution(contribution_id, identity, self.env().caller(), None, None, None)?;
+                }
+
+                self.env().emit_event(ContributionImported {
+                    id: contribution_id,
+                    identity,
+                    is_reward_claimed,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// A helper function to set the shared identity registry implementing the `Identity`
+        /// trait, if some.
+        ///
+        /// Once set, the contributors are resolved by the registry upon approval and claim,
+        /// and the identities can no longer be registered locally.
+        fn update_identity_registry(&mut self, account: AccountId, identity_registry: Option<AccountId>) {
+            self.env().emit_event(IdentityRegistryUpdated {
+                account,
+                old_identity_registry: self.identity_registry,
+                new_identity_registry: identity_registry,
+            });
+
+            self.identity_registry = identity_registry;
+        }
+
+        /// Simply returns the shared identity registry if some.
+        #[ink(message)]
+        pub fn get_identity_registry(&self) -> Option<AccountId> {
+            self.identity_registry
+        }
+
+        /// A helper function to set the identity verifier implementing the `IdentityVerifier`
+        /// trait, if some.
+        ///
+        /// Once set, identity registrations are validated by the verifier.
+        fn update_identity_verifier(&mut self, account: AccountId, identity_verifier: Option<AccountId>) {
+            self.env().emit_event(VerifierRotated {
+                account,
+                old_verifier: self.identity_verifier,
+                new_verifier: identity_verifier,
+            });
+
+            self.identity_verifier = identity_verifier;
+        }
+
+        /// Simply returns the identity verifier if some.
+        #[ink(message)]
+        pub fn get_identity_verifier(&self) -> Option<AccountId> {
+            self.identity_verifier
+        }
+
+        /// Request the challenge to prove the caller controls the wallet of an `identity`
+        /// through a `system.remark` extrinsic, as an alternative to `register_identity`.
+        ///
+        /// The contributor submits a remark containing the identity handle and the returned
+        /// challenge, the owner then confirms it with `confirm_remark_proof`.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn request_remark_proof(&mut self, identity: HashValue) -> Result<HashValue, WorkflowError> {
+            self.ensure_not_terminated()?;
+
+            if self.identity_is_known(identity) {
+                return Err(WorkflowError::IdentityAlreadyRegistered);
+            }
+
+            let account = Self::env().caller();
+            let challenge = Self::hash_payload(&(
+                self.env().account_id(),
+                identity,
+                account,
+                self.env().block_number(),
+            ));
+            self.remark_challenges
+                .insert(identity, &RemarkChallenge { account, challenge });
+
+            self.env().emit_event(RemarkProofRequested {
+                identity,
+                account,
+                challenge,
+            });
+
+            Ok(challenge)
+        }
+
+        /// Confirm the remark proof of an `identity`, referenced by the `block` and the extrinsic
+        /// `index` of the `system.remark` containing the identity handle and the challenge.
+        ///
+        /// The identity is then registered for the account which requested the challenge.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN), when_not_paused)]
+        pub fn confirm_remark_proof(
+            &mut self,
+            identity: HashValue,
+            block: BlockNumber,
+            index: u32,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+
+            if self.identity_is_known(identity) {
+                return Err(WorkflowError::IdentityAlreadyRegistered);
+            }
+            let remark_challenge = self
+                .remark_challenges
+                .take(identity)
+                .ok_or(WorkflowError::NoRemarkChallenge)?;
+
+            self.remark_proofs
+                .insert(identity, &RemarkProof { block, index });
+            self.register(identity, remark_challenge.account)?;
+
+            self.env().emit_event(RemarkProofConfirmed {
+                identity,
+                block,
+                index,
+            });
+
+            Ok(())
+        }
+
+        /// Simply returns the pending remark proof challenge of a given `identity` if some.
+        #[ink(message)]
+        pub fn get_remark_challenge(&self, identity: HashValue) -> Option<RemarkChallenge> {
+            self.remark_challenges.get(identity)
+        }
+
+        /// Simply returns the remark proof reference of a given `identity` if some.
+        #[ink(message)]
+        pub fn get_remark_proof(&self, identity: HashValue) -> Option<RemarkProof> {
+            self.remark_proofs.get(identity)
+        }
+
+        /// A helper function to validate the ownership `proof` of an `identity` by an `account`
+        /// with the attestor key, if set.
+        fn verify_ownership(&self, identity: HashValue, account: AccountId, proof: &[u8]) -> Result<(), WorkflowError> {
+            let Some(attestor_key) = self.attestor_key else {
+                return Ok(());
+            };
+
+            let signature = SignatureValue::try_from(proof).map_err(|_| WorkflowError::InvalidSignature)?;
+            if self.recover_signer(&Self::hash_payload(&(identity, account)), &signature)? != attestor_key {
+                return Err(WorkflowError::InvalidSignature);
+            }
+            Ok(())
+        }
+
+        /// A helper function to validate an identity registration with the identity verifier, if set.
+        fn verify_identity(&self, identity: HashValue, account: AccountId, proof: Vec<u8>) -> Result<(), WorkflowError> {
+            let Some(identity_verifier) = self.identity_verifier else {
+                return Ok(());
+            };
+
+            let verified = ink::env::call::build_call::<Environment>()
+                .call(identity_verifier)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("IdentityVerifier::verify"),
+                    ))
+                    .push_arg(identity)
+                    .push_arg(account)
+                    .push_arg(proof),
+                )
+                .returns::<Result<(), VerifierError>>()
+                .try_invoke();
+            if !matches!(verified, Ok(Ok(Ok(())))) {
+                return Err(WorkflowError::IdentityNotVerified);
+            }
+            Ok(())
+        }
+
+        /// A helper function to register an `identity` for a given `account`, approving the
+        /// contributions pre-approved for the identity.
+        fn register(&mut self, identity: HashValue, account: AccountId) -> Result<(), WorkflowError> {
+            if self.identity_registry.is_some() {
+                return Err(WorkflowError::SharedIdentityRegistrySet);
+            }
+            self.ensure_unbound(account)?;
+
+            self.identities.insert(identity, &account);
+            self.identity_of.insert(account, &identity);
+            self.identity_index.insert(self.identity_count, &identity);
+            self.identity_positions.insert(identity, &self.identity_count);
+            self.identity_count = self.identity_count.checked_add(1).ok_or(WorkflowError::Overflow)?;
+            self.attestations.insert(
+                identity,
+                &Attestation {
+                    attested_at: self.env().block_timestamp(),
+                    nonce: 0,
+                },
+            );
+
+            self.env().emit_event(IdentityRegistered {
+                identity,
+                platform: self.get_platform(identity),
+                caller: account,
+            });
+
+            self.drip_from_faucet(account);
+            self.bind_pre_approvals(identity);
+
+            Ok(())
+        }
+
+        /// A helper function to approve the contributions pre-approved for a registered
+        /// `identity`. The failed approvals (e.g. underfunded) stay pending, a
+        /// `PreApprovalDeferred` event being emitted for each of them.
+        ///
+        /// Returns the number of contributions approved.
+        fn bind_pre_approvals(&mut self, identity: HashValue) -> u32 {
+            let (bound, unbound) = core::mem::take(&mut self.unbound_approvals)
+                .into_iter()
+                .partition::<Vec<_>, _>(|(_, unbound_identity)| *unbound_identity == identity);
+            self.unbound_approvals = unbound;
+
+            let mut approved = 0;
+            for (contribution_id, _) in bound {
+                // The separation of duties applies to the pre-approver, not to the registrant
+                let approver = self.pre_approvers.get(contribution_id).unwrap_or(self.env().caller());
+                match self.approve_contribution(contribution_id, identity, approver, None, None, None) {
+                    Ok(()) => {
+                        self.pre_approvers.remove(contribution_id);
+                        approved += 1
+                    }
+                    Err(error) => {
+                        self.unbound_approvals.push((contribution_id, identity));
+
+                        self.env().emit_event(PreApprovalDeferred {
+                            contribution_id,
+                            identity,
+                            error: error.code(),
+                        });
+                    }
+                }
+            }
+            approved
+        }
+
+        /// Approve contribution. This is triggered by a workflow run.
+        ///
+        /// Fails if an oracle key is set, `approve_with_closure` must be used instead.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("approve"))?;
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+
+            self.approve_contribution(contribution_id, contributor_identity, self.env().caller(), None, None, None)
+        }
+
+        /// Approve contribution linked to a `metadata` hash of its work artifact (e.g. the hash
+        /// of the PR URL or an IPFS CID of the review summary), surfaced in the
+        /// `ContributionApproval` event and in `get_contribution`.
+        ///
+        /// Fails if an oracle key is set, `approve_with_closure` must be used instead.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_with_metadata(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+            metadata: HashValue,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("approve_with_metadata"))?;
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+
+            self.approve_contribution(contribution_id, contributor_identity, self.env().caller(), None, None, Some(metadata))
+        }
+
+        /// Approve contribution of a given platform `identity`, keyed by `identity_key`.
+        ///
+        /// Fails if an oracle key is set, `approve_with_closure` must be used instead.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_platform(&mut self, contribution_id: u64, identity: Identity) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("approve_platform"))?;
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+
+            self.approve_contribution(contribution_id, Self::identity_key(&identity), self.env().caller(), None, None, None)
+        }
+
+        /// Approve contribution with the signature of a trusted workflow signer, so that
+        /// workflow runs can approve without holding the owner key. Callable by anyone
+        /// (e.g. a relayer).
+        ///
+        /// The signature must be issued by a workflow signer over the Blake2x256 hash
+        /// of the SCALE encoded `ApprovalPayload`.
+        ///
+        /// Constraint(s):
+        /// 1. The signed approval must not have been accepted already.
+        /// 2. Fails if an oracle key is set, `approve_with_closure` must be used instead.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_with_signature(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+            nonce: u64,
+            signature: SignatureValue,
+        ) -> Result<(), WorkflowError> {
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+
+            let payload_hash = Self::hash_payload(&ApprovalPayload {
+                contract: self.env().account_id(),
+                contribution_id,
+                contributor_identity,
+                nonce,
+            });
+            if self.signed_approvals.contains(payload_hash) {
+                return Err(WorkflowError::SignatureAlreadyUsed);
+            }
+            let signer = self.recover_signer(&payload_hash, &signature)?;
+            if !self.is_signer(signer) {
+                return Err(WorkflowError::InvalidSigner);
+            }
+
+            self.approve_contribution(contribution_id, contributor_identity, signer, None, None, None)?;
+            self.signed_approvals.insert(payload_hash, &());
+
+            Ok(())
+        }
+
+        /// A helper function to add a `verifier` ECDSA key (e.g. of the Kudos backend), signing
+        /// registrations and approvals along with its key id (see `register_with_attestation`
+        /// and `approve_with_attestation`).
+        ///
+        /// Keys are rotated without service gap by adding the next key with a validity window
+        /// overlapping the current one. A `VerifierAdded` event is emitted with the key id.
+        fn add_verifier(&mut self, verifier: VerifierKey) -> Result<(), WorkflowError> {
+            let key_id = self.verifier_keys_count;
+            self.verifier_keys.insert(key_id, &verifier);
+            self.verifier_keys_count = self.verifier_keys_count.checked_add(1).ok_or(WorkflowError::Overflow)?;
+
+            self.env().emit_event(VerifierAdded {
+                key_id,
+                key: verifier.key,
+                valid_from: verifier.valid_from,
+                valid_to: verifier.valid_to,
+            });
+
+            Ok(())
+        }
+
+        /// Revoke the verifier key of a given `key_id` (e.g. once it leaked), i.e. end its
+        /// validity window at the current time if not already ended.
+        ///
+        /// A `VerifierRevoked` event is emitted.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn revoke_verifier(&mut self, key_id: u32) -> Result<(), WorkflowError> {
+            let mut verifier = self.verifier_keys.get(key_id).ok_or(WorkflowError::InvalidVerifierKey)?;
+            verifier.valid_to = verifier.valid_to.min(self.env().block_timestamp());
+            self.verifier_keys.insert(key_id, &verifier);
+
+            self.env().emit_event(VerifierRevoked {
+                account: self.env().caller(),
+                key_id,
+                valid_to: verifier.valid_to,
+            });
+
+            Ok(())
+        }
+
+        /// Simply returns the verifier key of a given `key_id` if some.
+        #[ink(message)]
+        pub fn get_verifier(&self, key_id: u32) -> Option<VerifierKey> {
+            self.verifier_keys.get(key_id)
+        }
+
+        /// Register an `identity` for a given `account` with the `signature` of a verifier key
+        /// over the Blake2x256 hash of the SCALE encoded `RegistrationPayload`, so that the
+        /// Kudos backend (or anyone relaying it) registers contributors once their OAuth flow is
+        /// verified.
+        ///
+        /// The verifier key of the `key_id` must be valid at the current time.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn register_with_attestation(
+            &mut self,
+            identity: HashValue,
+            account: AccountId,
+            key_id: u32,
+            signature: SignatureValue,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+            if self.identity_is_known(identity) {
+                return Err(WorkflowError::IdentityAlreadyRegistered);
+            }
+
+            let payload_hash = Self::hash_payload(&RegistrationPayload {
+                contract: self.env().account_id(),
+                identity,
+                account,
+                key_id,
+            });
+            self.verify_with_key(key_id, &payload_hash, &signature)?;
+            self.register(identity, account)
+        }
+
+        /// Approve contribution with the `signature` of a verifier key over the Blake2x256 hash
+        /// of the SCALE encoded `VerifiedApprovalPayload`. Callable by anyone (e.g. a relayer).
+        ///
+        /// Constraint(s):
+        /// 1. The verifier key of the `key_id` must be valid at the current time.
+        /// 2. The signed approval must not have been accepted already.
+        /// 3. Fails if an oracle key is set, `approve_with_closure` must be used instead.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_with_attestation(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+            nonce: u64,
+            key_id: u32,
+            signature: SignatureValue,
+        ) -> Result<(), WorkflowError> {
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+
+            let payload_hash = Self::hash_payload(&VerifiedApprovalPayload {
+                approval: ApprovalPayload {
+                    contract: self.env().account_id(),
+                    contribution_id,
+                    contributor_identity,
+                    nonce,
+                },
+                key_id,
+            });
+            if self.signed_approvals.contains(payload_hash) {
+                return Err(WorkflowError::SignatureAlreadyUsed);
+            }
+            let verifier = self.verify_with_key(key_id, &payload_hash, &signature)?;
+
+            self.approve_contribution(contribution_id, contributor_identity, verifier, None, None, None)?;
+            self.signed_approvals.insert(payload_hash, &());
+
+            Ok(())
+        }
+
+        /// A helper function to ensure a `signature` of a `payload_hash` is issued by the
+        /// verifier key of a given `key_id`, valid at the current time.
+        fn verify_with_key(&self, key_id: u32, payload_hash: &HashValue, signature: &SignatureValue) -> Result<AccountId, WorkflowError> {
+            let verifier = self.verifier_keys.get(key_id).ok_or(WorkflowError::InvalidVerifierKey)?;
+            let now = self.env().block_timestamp();
+            if now < verifier.valid_from || now >= verifier.valid_to {
+                return Err(WorkflowError::InvalidVerifierKey);
+            }
+            if self.recover_signer(payload_hash, signature)? != verifier.key {
+                return Err(WorkflowError::InvalidSignature);
+            }
+            Ok(verifier.key)
+        }
+
+        /// Trust a given ECDSA `signer` account to sign approvals (see `approve_with_signature`).
+        ///
+        /// A `SignerAdded` event is emitted.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn add_signer(&mut self, signer: AccountId) -> Result<(), WorkflowError> {
+            if self.is_signer(signer) {
+                return Err(WorkflowError::InvalidSigner);
+            }
+
+            self.signers.insert(signer, &true);
+            self.env().emit_event(SignerAdded { signer });
+
+            Ok(())
+        }
+
+        /// Stop trusting a given `signer` account (e.g. once its key leaked).
+        ///
+        /// A `SignerRemoved` event is emitted.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn remove_signer(&mut self, signer: AccountId) -> Result<(), WorkflowError> {
+            if !self.is_signer(signer) {
+                return Err(WorkflowError::InvalidSigner);
+            }
+
+            self.signers.remove(signer);
+            self.env().emit_event(SignerRemoved { signer });
+
+            Ok(())
+        }
+
+        /// Replace a trusted `old_signer` account by a `new_signer` one (i.e. a key rotation).
+        ///
+        /// Constraint(s):
+        /// 1. The `old_signer` must be trusted.
+        /// 2. The `new_signer` must not be trusted already.
+        ///
+        /// A `SignerRotated` event is emitted.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn rotate_signer(&mut self, old_signer: AccountId, new_signer: AccountId) -> Result<(), WorkflowError> {
+            if !self.is_signer(old_signer) || self.is_signer(new_signer) {
+                return Err(WorkflowError::InvalidSigner);
+            }
+
+            self.signers.remove(old_signer);
+            self.signers.insert(new_signer, &true);
+            self.env().emit_event(SignerRotated {
+                old_signer,
+                new_signer,
+            });
+
+            Ok(())
+        }
+
+        /// Returns `true` if a given `account` is trusted to sign approvals.
+        #[ink(message)]
+        pub fn is_signer(&self, account: AccountId) -> bool {
+            self.signers.get(account).unwrap_or(false)
+        }
+
+        /// Authorize a session `account` (e.g. a CI system key) with a given scope, or revoke
+        /// it if none, so that it approves contributions without holding the owner key.
+        ///
+        /// The reward value approved by the session is reset.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn authorize_session(&mut self, account: AccountId, session: Option<Session>) -> Result<(), WorkflowError> {
+            if let Some(session) = &session {
+                if session.selectors.len() > MAX_SESSION_SELECTORS {
+                    return Err(WorkflowError::InvalidSession);
+                }
+                self.sessions.insert(account, session);
+            } else {
+                self.sessions.remove(account);
+            }
+            self.session_spent.remove(account);
+
+            self.env().emit_event(SessionAuthorized { account, session });
+
+            Ok(())
+        }
+
+        /// Simply returns the scope of a given session `account` if some.
+        #[ink(message)]
+        pub fn get_session(&self, account: AccountId) -> Option<Session> {
+            self.sessions.get(account)
+        }
+
+        /// Simply returns the reward value approved by a given session `account`.
+        #[ink(message)]
+        pub fn get_session_spent(&self, account: AccountId) -> Balance {
+            self.session_spent.get(account).unwrap_or_default()
+        }
+
+        /// A helper function to ensure the caller is an approver, or a session account allowed
+        /// to call the message of a given `selector`.
+        fn ensure_approver(&self, selector: [u8; 4]) -> Result<(), WorkflowError> {
+            let caller = self.env().caller();
+            if AccessControl::has_role(self, APPROVER, Some(caller)) {
+                return Ok(());
+            }
+
+            let session = self
+                .sessions
+                .get(caller)
+                .ok_or(WorkflowError::AccessControlError(AccessControlError::MissingRole))?;
+            if self.env().block_number() > session.expires_at || !session.selectors.contains(&selector) {
+                return Err(WorkflowError::SessionNotAllowed);
+            }
+
+            Ok(())
+        }
+
+        /// A helper function to account a `reward` approved by the caller to its session, if the
+        /// caller approves through a session account.
+        fn charge_session(&mut self, reward: Balance) -> Result<(), WorkflowError> {
+            let caller = self.env().caller();
+            if AccessControl::has_role(self, APPROVER, Some(caller)) {
+                return Ok(());
+            }
+            let Some(session) = self.sessions.get(caller) else {
+                return Ok(());
+            };
+            let spent = self
+                .get_session_spent(caller)
+                .checked_add(reward)
+                .ok_or(WorkflowError::Overflow)?;
+            if spent > session.max_value {
+                return Err(WorkflowError::SessionLimitExceeded);
+            }
+            self.session_spent.insert(caller, &spent);
+
+            Ok(())
+        }
+
+        /// Pre-approve contribution of a contributor identity not registered yet.
+        ///
+        /// The contribution is approved once the identity is registered, so that the
+        /// contributor can be notified that a reward is awaiting its registration.
+        ///
+        /// Constraint(s):
+        /// 1. The `contributor_identity` must not be registered, `approve` must be used instead.
+        /// 2. There must be at most `MAX_UNBOUND_APPROVALS` pre-approved contributions.
+        ///
+        /// A `ContributionPreApproval` event is emitted.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn pre_approve(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("pre_approve"))?;
+            self.ensure_not_terminated()?;
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+            if self.identity_is_known(contributor_identity) {
+                return Err(WorkflowError::IdentityAlreadyRegistered);
+            }
+            if self.contributions.contains(contribution_id)
+                || self.unbound_approvals.iter().any(|(id, _)| *id == contribution_id)
+            {
+                return Err(WorkflowError::ContributionAlreadyApproved);
+            }
+            if self.unbound_approvals.len() >= MAX_UNBOUND_APPROVALS {
+                return Err(WorkflowError::TooManyUnboundApprovals);
+            }
+            // The identity is not registered yet, the reward is accounted before any multiplier
+            self.charge_session(self.listed_reward_of(contribution_id).unwrap_or(self.reward))?;
+
+            self.unbound_approvals.push((contribution_id, contributor_identity));
+            self.pre_approvers.insert(contribution_id, &self.env().caller());
+
+            self.env().emit_event(ContributionPreApproval {
+                id: contribution_id,
+                identity: contributor_identity,
+            });
+
+            Ok(())
+        }
+
+        /// Retry the approval of the contributions pre-approved for a registered `identity` whose
+        /// approval failed upon registration (e.g. once the contract is funded).
+        ///
+        /// Returns the number of contributions approved.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn retry_pre_approvals(&mut self, identity: HashValue) -> Result<u32, WorkflowError> {
+            if self.get_account(identity).is_none() {
+                return Err(WorkflowError::UnknownContributor);
+            }
+
+            Ok(self.bind_pre_approvals(identity))
+        }
+
+        /// Returns the pre-approved contributions awaiting identity registration, as
+        /// `(contribution_id, identity)` pairs, from a given `offset` up to `limit` entries
+        /// (capped to `MAX_CHUNK_SIZE`).
+        #[ink(message)]
+        pub fn list_unbound_approvals(&self, offset: u32, limit: u32) -> Vec<(u64, HashValue)> {
+            let len = u32::try_from(self.unbound_approvals.len()).unwrap_or(u32::MAX);
+            let indices = Cursor::new(offset).chunk(limit, len).indices();
+            self.unbound_approvals[indices.start as usize..indices.end as usize].to_vec()
+        }
+
+        /// Simply checks if contributions are pre-approved for a given `identity`, awaiting its
+        /// registration or the retry of their approval.
+        #[ink(message)]
+        pub fn is_awaiting_registration(&self, identity: HashValue) -> bool {
+            self.unbound_approvals
+                .iter()
+                .any(|(_, unbound_identity)| *unbound_identity == identity)
+        }
+
+        /// Approve contribution from a given template, which defines its kind, tier, expiry and tags.
+        ///
+        /// Fails if an oracle key is set, `approve_with_closure` must be used instead.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_from_template(
+            &mut self,
+            template_id: u32,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("approve_from_template"))?;
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+
+            self.approve_contribution(contribution_id, contributor_identity, self.env().caller(), Some(template_id), None, None)
+        }
+
+        /// Approve contribution as part of a given workflow `run_id`.
+        ///
+        /// The run limits bound the number of approvals and the total reward value approved
+        /// per run, each approval accounting for its actual reward. Identical re-approvals
+        /// are not accounted for.
+        ///
+        /// Fails if an oracle key is set, `approve_with_closure` must be used instead.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_in_run(
+            &mut self,
+            run_id: u64,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("approve_in_run"))?;
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+            if let Some(contribution) = self.contributions.get(contribution_id) {
+                if contribution.identity == contributor_identity {
+                    return Ok(());
+                }
+            }
+
+            let reward = self.approval_reward(contribution_id, contributor_identity, None)?;
+            self.charge_run(run_id, reward)?;
+            self.approve_contribution(contribution_id, contributor_identity, self.env().caller(), None, None, None)
+        }
+
+        /// A helper function to account an approved `reward` to a given workflow `run_id`,
+        /// within the run limits.
+        fn charge_run(&mut self, run_id: u64, reward: Balance) -> Result<(), WorkflowError> {
+            let usage = self.runs.get(run_id).unwrap_or_default();
+            let usage = RunUsage {
+                approvals: usage.approvals.checked_add(1).ok_or(WorkflowError::Overflow)?,
+                value: usage.value.checked_add(reward).ok_or(WorkflowError::Overflow)?,
+            };
+            if let Some(limits) = self.run_limits {
+                if usage.approvals > limits.max_approvals || usage.value > limits.max_value {
+                    return Err(WorkflowError::RunLimitExceeded);
+                }
+            }
+            self.runs.insert(run_id, &usage);
+
+            Ok(())
+        }
+
+        /// Set the limits applied to every workflow run, unlimited if unset.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_run_limits(&mut self, run_limits: Option<RunLimits>) -> Result<(), WorkflowError> {
+            self.env().emit_event(RunLimitsUpdated {
+                account: self.env().caller(),
+                old_run_limits: self.run_limits,
+                new_run_limits: run_limits,
+            });
+
+            self.run_limits = run_limits;
+            Ok(())
+        }
+
+        /// Simply returns the limits applied to every workflow run if some.
+        #[ink(message)]
+        pub fn get_run_limits(&self) -> Option<RunLimits> {
+            self.run_limits
+        }
+
+        /// Approve a trial contribution (e.g. a "good first issue"), rewarded with the fixed
+        /// trial reward.
+        ///
+        /// Trial approvals skip the dispute window and the finality delay, and expire after the
+        /// trial duration. The number of trials approved per identity is capped.
+        ///
+        /// Fails if an oracle key is set, `approve_with_closure` must be used instead.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_trial(&mut self, contribution_id: u64, contributor_identity: HashValue) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("approve_trial"))?;
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+            let trials = self.trials.ok_or(WorkflowError::TrialsDisabled)?;
+
+            self.approve_contribution(contribution_id, contributor_identity, self.env().caller(), None, Some(trials), None)
+        }
+
+        /// Set the trial contributions configuration, disabling trials if none.
+        ///
+        /// The configuration only applies to the trials approved afterwards.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_trials(&mut self, trials: Option<TrialConfig>) -> Result<(), WorkflowError> {
+            self.env().emit_event(TrialsUpdated {
+                account: self.env().caller(),
+                old_trials: self.trials,
+                new_trials: trials,
+            });
+
+            self.trials = trials;
+            Ok(())
+        }
+
+        /// Simply returns the trial contributions configuration if some.
+        #[ink(message)]
+        pub fn get_trials(&self) -> Option<TrialConfig> {
+            self.trials
+        }
+
+        /// Simply returns the number of trial contributions approved for a given `identity`.
+        #[ink(message)]
+        pub fn get_trials_count(&self, identity: HashValue) -> u32 {
+            self.trials_count.get(identity).unwrap_or_default()
+        }
+
+        /// Simply returns the approvals of a given workflow `run_id`.
+        #[ink(message)]
+        pub fn get_run(&self, run_id: u64) -> RunUsage {
+            self.runs.get(run_id).unwrap_or_default()
+        }
+
+        /// Create a contribution template, returning its ID.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn create_template(&mut self, template: Template) -> Result<u32, WorkflowError> {
+            if template.tags.len() > MAX_TEMPLATE_TAGS {
+                return Err(WorkflowError::TooManyTags);
+            }
+
+            let id = self.templates_count;
+            self.templates_count = id.checked_add(1).ok_or(WorkflowError::Overflow)?;
+            self.templates.insert(id, &template);
+
+            self.env().emit_event(TemplateCreated { id, template });
+
+            Ok(id)
+        }
+
+        /// Remove a contribution template, contributions already approved from it are unchanged.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn remove_template(&mut self, template_id: u32) -> Result<(), WorkflowError> {
+            let template = self.templates.get(template_id).ok_or(WorkflowError::UnknownTemplate)?;
+            self.templates.remove(template_id);
+
+            self.env().emit_event(TemplateRemoved {
+                account: self.env().caller(),
+                id: template_id,
+                old_template: template,
+            });
+
+            Ok(())
+        }
+
+        /// Simply returns the contribution template of a given `template_id` if some.
+        #[ink(message)]
+        pub fn get_template(&self, template_id: u32) -> Option<Template> {
+            self.templates.get(template_id)
+        }
+
+        /// Approve contribution along with the oracle statement that the linked issue
+        /// is closed as completed.
+        ///
+        /// The signature must be issued by the oracle key over the Blake2x256 hash
+        /// of the SCALE encoded `ClosureStatement`, whose hash is stored.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_with_closure(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+            statement: ClosureStatement,
+            signature: SignatureValue,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("approve_with_closure"))?;
+            let oracle_key = self.oracle_key.ok_or(WorkflowError::OracleKeyNotSet)?;
+            if statement.contract != self.env().account_id()
+                || statement.contribution_id != contribution_id
+            {
+                return Err(WorkflowError::InvalidClosureStatement);
+            }
+            if statement.state != IssueState::ClosedAsCompleted {
+                return Err(WorkflowError::IssueNotCompleted);
+            }
+
+            let statement_hash = Self::hash_payload(&statement);
+            if self.recover_signer(&statement_hash, &signature)? != oracle_key {
+                return Err(WorkflowError::InvalidSignature);
+            }
+
+            self.approve_contribution(contribution_id, contributor_identity, self.env().caller(), None, None, None)?;
+            self.closure_statements
+                .insert(contribution_id, &statement_hash);
+
+            Ok(())
+        }
+
+        /// Approve contribution along with the oracle statement about its metrics, the reward
+        /// being computed by the payout formula.
+        ///
+        /// The signature must be issued by the oracle key over the Blake2x256 hash
+        /// of the SCALE encoded `MetricsStatement`.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_with_metrics(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+            statement: MetricsStatement,
+            signature: SignatureValue,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("approve_with_metrics"))?;
+            let oracle_key = self.oracle_key.ok_or(WorkflowError::OracleKeyNotSet)?;
+            let formula = self
+                .payout_formula
+                .clone()
+                .ok_or(WorkflowError::PayoutFormulaNotSet)?;
+            if statement.contract != self.env().account_id()
+                || statement.contribution_id != contribution_id
+                || statement.metrics.len() != formula.coefficients.len()
+            {
+                return Err(WorkflowError::InvalidMetricsStatement);
+            }
+
+            let statement_hash = Self::hash_payload(&statement);
+            if self.recover_signer(&statement_hash, &signature)? != oracle_key {
+                return Err(WorkflowError::InvalidSignature);
+            }
+
+            let reward = formula
+                .coefficients
+                .iter()
+                .zip(statement.metrics.iter())
+                .try_fold(formula.base, |reward, (coefficient, metric)| {
+                    coefficient
+                        .checked_mul(Balance::from(*metric))
+                        .and_then(|amount| reward.checked_add(amount))
+                })
+                .unwrap_or(Balance::MAX)
+                .min(formula.max_reward);
+
+            // Identical re-approvals keep the reward of the first approval
+            if !self.contributions.contains(contribution_id) {
+                self.formula_rewards.insert(contribution_id, &reward);
+            }
+            self.approve_contribution(contribution_id, contributor_identity, self.env().caller(), None, None, None)
+        }
+
+        /// Approve contribution of a given `difficulty` tier (e.g. the hash of the issue label),
+        /// paying the reward of the tier instead of the default one.
+        ///
+        /// Constraint(s):
+        /// 1. The difficulty tier must have a reward.
+        /// 2. Fails if an oracle key is set, `approve_with_closure` must be used instead.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn approve_with_difficulty(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+            difficulty: HashValue,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_approver(ink::selector_bytes!("approve_with_difficulty"))?;
+            if self.oracle_key.is_some() {
+                return Err(WorkflowError::ClosureProofRequired);
+            }
+            let reward = self
+                .difficulty_rewards
+                .get(difficulty)
+                .ok_or(WorkflowError::UnknownTier)?;
+
+            // Identical re-approvals keep the reward of the first approval
+            if !self.contributions.contains(contribution_id) {
+                self.tiered_rewards.insert(contribution_id, &reward);
+            }
+            self.approve_contribution(contribution_id, contributor_identity, self.env().caller(), None, None, None)
+        }
+
+        /// A helper function to set the `reward` of a given `difficulty` tier (e.g. the hash of
+        /// the "good first issue" or "hard bug" label), or remove the tier if none.
+        fn update_difficulty_reward(
+            &mut self,
+            account: AccountId,
+            difficulty: HashValue,
+            reward: Option<RewardAmount>,
+        ) {
+            let old_reward = self.difficulty_rewards.get(difficulty);
+            let new_reward = reward.map(|reward| reward.plancks());
+            if let Some(reward) = new_reward {
+                self.difficulty_rewards.insert(difficulty, &reward);
+            } else {
+                self.difficulty_rewards.remove(difficulty);
+            }
+
+            self.env().emit_event(DifficultyRewardUpdated {
+                account,
+                difficulty,
+                old_reward,
+                new_reward,
+            });
+        }
+
+        /// Simply returns the reward of a given `difficulty` tier if some.
+        #[ink(message)]
+        pub fn get_difficulty_reward(&self, difficulty: HashValue) -> Option<Balance> {
+            self.difficulty_rewards.get(difficulty)
+        }
+
+        /// Set the payout formula applied to the contributions approved with metrics, if some.
+        ///
+        /// Constraint(s):
+        /// 1. There must be at most `MAX_FORMULA_METRICS` coefficients.
+        /// 2. The base and the coefficients must not exceed the maximum reward.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_payout_formula(&mut self, payout_formula: Option<PayoutFormula>) -> Result<(), WorkflowError> {
+            if let Some(formula) = &payout_formula {
+                if formula.coefficients.len() > MAX_FORMULA_METRICS
+                    || formula.base > formula.max_reward
+                    || formula.coefficients.iter().any(|coefficient| *coefficient > formula.max_reward)
+                {
+                    return Err(WorkflowError::InvalidPayoutFormula);
+                }
+            }
+
+            self.env().emit_event(PayoutFormulaUpdated {
+                account: self.env().caller(),
+                old_payout_formula: self.payout_formula.clone(),
+                new_payout_formula: payout_formula.clone(),
+            });
+
+            self.payout_formula = payout_formula;
+            Ok(())
+        }
+
+        /// Simply returns the payout formula if some.
+        #[ink(message)]
+        pub fn get_payout_formula(&self) -> Option<PayoutFormula> {
+            self.payout_formula.clone()
+        }
+
+        /// Simply returns the reward computed by the payout formula for a given `contribution_id` if some.
+        #[ink(message)]
+        pub fn get_formula_reward(&self, contribution_id: u64) -> Option<Balance> {
+            self.formula_rewards.get(contribution_id)
+        }
+
+        /// Simply returns the closure statement hash a given `contribution_id` was approved with if some.
+        #[ink(message)]
+        pub fn get_closure_statement(&self, contribution_id: u64) -> Option<HashValue> {
+            self.closure_statements.get(contribution_id)
+        }
+
+        /// Simply returns the oracle key if some.
+        #[ink(message)]
+        pub fn get_oracle_key(&self) -> Option<AccountId> {
+            self.oracle_key
+        }
+
+        /// A helper function to set the approval challenges configuration:
+        /// - the `bond` required to challenge an approval.
+        /// - the `dispute_window` (in milliseconds) approvals can be challenged for, zero disabling challenges.
+        /// - the `challenger_share` (in basis points) of the reward earned by the challenger of an upheld challenge.
+        ///
+        /// The dispute window only applies to the contributions approved afterwards.
+        fn update_challenge_config(
+            &mut self,
+            account: AccountId,
+            bond: Balance,
+            dispute_window: Timestamp,
+            challenger_share: u32,
+        ) {
+            self.env().emit_event(ChallengeConfigUpdated {
+                account,
+                old_bond: self.challenge_bond,
+                old_dispute_window: self.dispute_window,
+                old_challenger_share: self.challenger_share,
+                new_bond: bond,
+                new_dispute_window: dispute_window,
+                new_challenger_share: challenger_share,
+            });
+
+            self.challenge_bond = bond;
+            self.dispute_window = dispute_window;
+            self.challenger_share = challenger_share;
+        }
+
+        /// A helper function to set the arbitrator resolving the challenges, the owner if unset.
+        fn update_arbitrator(&mut self, account: AccountId, arbitrator: Option<AccountId>) {
+            self.env().emit_event(ArbitratorUpdated {
+                account,
+                old_arbitrator: self.arbitrator,
+                new_arbitrator: arbitrator,
+            });
+
+            self.arbitrator = arbitrator;
+        }
+
+        /// Challenge the approval of a given `contribution_id` by posting the challenge bond.
+        ///
+        /// Constraint(s):
+        /// 1. The transferred value must be equal to the challenge bond.
+        /// 2. The contribution must be in its dispute window.
+        /// 3. The contribution must not be already challenged.
+        ///
+        /// An `ApprovalChallenged` event is emitted.
+        #[ink(message, payable)]
+        pub fn challenge(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            let bond = self.env().transferred_value();
+            if bond != self.challenge_bond {
+                return Err(WorkflowError::InvalidBond);
+            }
+
+            self.get_approved_contribution(contribution_id)?;
+            match self.disputable_until.get(contribution_id) {
+                Some(until) if self.env().block_timestamp() < until => (),
+                _ => return Err(WorkflowError::DisputeWindowClosed),
+            }
+            if self.challenges.contains(contribution_id) {
+                return Err(WorkflowError::ContributionChallenged);
+            }
+
+            let challenge_bonds = self
+                .challenge_bonds
+                .checked_add(bond)
+                .ok_or(WorkflowError::Overflow)?;
+            let challenger = Self::env().caller();
+            self.challenges
+                .insert(contribution_id, &Challenge { challenger, bond, is_veto: false });
+            self.challenge_bonds = challenge_bonds;
+
+            self.env().emit_event(ApprovalChallenged {
+                contribution_id,
+                challenger,
+                bond,
+            });
+
+            Ok(())
+        }
+
+        /// Veto the approval of a given `contribution_id`, callable by the funders of the
+        /// earmarked deposits backing it.
+        ///
+        /// The veto does not block the payment outright but sends the approval to arbitration,
+        /// as an unbonded challenge. A contribution can only be vetoed once, during its dispute window.
+        ///
+        /// An `ApprovalVetoed` event is emitted.
+        #[ink(message)]
+        pub fn veto(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            let funder = Self::env().caller();
+            if !self
+                .get_earmarks_of(contribution_id)
+                .into_iter()
+                .filter_map(|index| self.earmarks.get(index))
+                .any(|earmark| earmark.funder == funder)
+            {
+                return Err(WorkflowError::CallerIsNotFunder);
+            }
+
+            self.get_approved_contribution(contribution_id)?;
+            match self.disputable_until.get(contribution_id) {
+                Some(until) if self.env().block_timestamp() < until => (),
+                _ => return Err(WorkflowError::DisputeWindowClosed),
+            }
+            if self.vetoes.contains(contribution_id) {
+                return Err(WorkflowError::AlreadyVetoed);
+            }
+            if self.challenges.contains(contribution_id) {
+                return Err(WorkflowError::ContributionChallenged);
+            }
+
+            self.challenges.insert(
+                contribution_id,
+                &Challenge {
+                    challenger: funder,
+                    bond: 0,
+                    is_veto: true,
+                },
+            );
+            self.vetoes.insert(contribution_id, &());
+
+            self.env().emit_event(ApprovalVetoed {
+                contribution_id,
+                funder,
+            });
+
+            Ok(())
+        }
+
+        /// Resolve the challenge of a given `contribution_id`, callable by the arbitrator.
+        ///
+        /// If `upheld`, the approval is revoked, and the challenger gets back the bond plus
+        /// the challenger share of the reserved reward, funder vetoes not being rewarded.
+        /// Otherwise the bond is forfeited to the reward pool, and the reward can be claimed
+        /// once the dispute window is over.
+        ///
+        /// A `ChallengeResolved` event is emitted.
+        #[ink(message)]
+        pub fn resolve_challenge(&mut self, contribution_id: u64, upheld: bool) -> Result<(), WorkflowError> {
+            let caller = Self::env().caller();
+            if Some(caller) != self.arbitrator.or(self.ownable.owner) {
+                return Err(WorkflowError::CallerIsNotArbitrator);
+            }
+            let challenge = self
+                .challenges
+                .get(contribution_id)
+                .ok_or(WorkflowError::NoChallenge)?;
+
+            // The challenger share is paid out of the reward reserved for the revoked contribution
+            let payout = if upheld && !challenge.is_veto {
+                checked_mul_div(
+                    self.get_reservation(contribution_id),
+                    Balance::from(self.challenger_share),
+                    Balance::from(BASIS_POINTS),
+                )
+                .and_then(|share| share.checked_add(challenge.bond))
+                .ok_or(WorkflowError::Overflow)?
+            } else {
+                0
+            };
+
+            if upheld {
+                self.approved_count = self.approved_count.checked_sub(1).ok_or(WorkflowError::Overflow)?;
+                self.unclaimed_count = self.unclaimed_count.checked_sub(1).ok_or(WorkflowError::Overflow)?;
+                self.contributions.remove(contribution_id);
+                self.approved_rewards.remove(contribution_id);
+                self.approval_blocks.remove(contribution_id);
+                self.disputable_until.remove(contribution_id);
+                self.restore_earmarks(contribution_id)?;
+                self.release_reservation(contribution_id);
+                self.notify_lifecycle(contribution_id, Transition::Revoked);
+            }
+            self.challenges.remove(contribution_id);
+            self.challenge_bonds = self
+                .challenge_bonds
+                .checked_sub(challenge.bond)
+                .ok_or(WorkflowError::Overflow)?;
+            if payout > 0 {
+                self.pay(Asset::Native, challenge.challenger, payout)?;
+            }
+
+            self.env().emit_event(ChallengeResolved {
+                contribution_id,
+                upheld,
+                payout,
+            });
+
+            Ok(())
+        }
+
+        /// Simply returns the pending challenge of a given `contribution_id` if some.
+        #[ink(message)]
+        pub fn get_challenge(&self, contribution_id: u64) -> Option<Challenge> {
+            self.challenges.get(contribution_id)
+        }
+
+        /// Simply returns the end of the dispute window of a given `contribution_id` if some.
+        #[ink(message)]
+        pub fn get_disputable_until(&self, contribution_id: u64) -> Option<Timestamp> {
+            self.disputable_until.get(contribution_id)
+        }
+
+        /// Simply returns the arbitrator if some.
+        #[ink(message)]
+        pub fn get_arbitrator(&self) -> Option<AccountId> {
+            self.arbitrator
+        }
+
+        /// Set the number of blocks after which anyone can finalize an approval, zero
+        /// disabling finality.
+        ///
+        /// The finality depth only applies to the contributions approved afterwards.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_finality_depth(&mut self, finality_depth: BlockNumber) -> Result<(), WorkflowError> {
+            self.env().emit_event(FinalityDepthUpdated {
+                account: self.env().caller(),
+                old_finality_depth: self.finality_depth,
+                new_finality_depth: finality_depth,
+            });
+
+            self.finality_depth = finality_depth;
+            Ok(())
+        }
+
+        /// Finalize the approval of a given `contribution_id`, required before claiming.
+        ///
+        /// Constraint(s):
+        /// 1. The approval must be pending finality.
+        /// 2. The finality depth must be reached, unless the caller is the owner.
+        ///
+        /// A `ContributionFinalized` event is emitted.
+        #[ink(message)]
+        pub fn finalize(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            let finalizable_at = self
+                .finalizable_at
+                .get(contribution_id)
+                .ok_or(WorkflowError::NotPendingFinality)?;
+            if self.env().block_number() < finalizable_at
+                && !AccessControl::has_role(self, ADMIN, Some(self.env().caller()))
+            {
+                return Err(WorkflowError::FinalityDepthNotReached);
+            }
+
+            self.finalize_approval(contribution_id);
+
+            Ok(())
+        }
+
+        /// A helper function to finalize the approval of a given `contribution_id`.
+        fn finalize_approval(&mut self, contribution_id: u64) {
+            self.finalizable_at.remove(contribution_id);
+
+            self.env().emit_event(ContributionFinalized { id: contribution_id });
+            self.notify_lifecycle(contribution_id, Transition::Finalized);
+        }
+
+        /// Simply returns the finality depth.
+        #[ink(message)]
+        pub fn get_finality_depth(&self) -> BlockNumber {
+            self.finality_depth
+        }
+
+        /// Simply returns the block number from which the approval of a given `contribution_id`
+        /// can be finalized by anyone, if pending finality.
+        #[ink(message)]
+        pub fn get_finalizable_at(&self, contribution_id: u64) -> Option<BlockNumber> {
+            self.finalizable_at.get(contribution_id)
+        }
+
+        /// A helper function to set the number of blocks the approved rewards can be claimed
+        /// for, without deadline if zero.
+        ///
+        /// The claim window only applies to the contributions approved afterwards.
+        fn update_claim_window(&mut self, account: AccountId, claim_window: BlockNumber) {
+            self.env().emit_event(ExpiryPolicyUpdated {
+                account,
+                old_claim_window: self.claim_window,
+                new_claim_window: claim_window,
+            });
+
+            self.claim_window = claim_window;
+        }
+
+        /// Simply returns the number of blocks the approved rewards can be claimed for.
+        #[ink(message)]
+        pub fn get_claim_window(&self) -> BlockNumber {
+            self.claim_window
+        }
+
+        /// Simply returns the block number after which a given unclaimed `contribution_id`
+        /// can be expired, if some.
+        #[ink(message)]
+        pub fn get_claim_deadline(&self, contribution_id: u64) -> Option<BlockNumber> {
+            self.claim_deadlines.get(contribution_id)
+        }
+
+        /// Expire a given unclaimed `contribution_id` once its claim deadline (or its expiry)
+        /// passed, releasing its reserved reward back to the pool. A challenged contribution
+        /// cannot expire until the challenge is resolved.
+        ///
+        /// A `ContributionExpired` event is emitted.
+        #[ink(message)]
+        #[modifiers(only_role(APPROVER))]
+        pub fn expire(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            let contribution = self.get_approved_contribution(contribution_id)?;
+            if contribution.is_reward_claimed {
+                return Err(WorkflowError::AlreadyClaimed);
+            }
+            if !self.is_past_claim_deadline(&contribution) {
+                return Err(WorkflowError::ContributionNotExpired);
+            }
+            if self.challenges.contains(contribution_id) {
+                return Err(WorkflowError::ContributionChallenged);
+            }
+
+            self.expire_contribution(contribution_id)
+        }
+
+        /// Sweep the next contributions (up to `MAX_CHUNK_SIZE`) left to sweep, resuming from
+        /// the previous sweep and starting over once the end is reached:
+        /// - the unclaimed contributions past their claim deadline (or expiry) are expired,
+        ///   unless challenged.
+        /// - the approvals past their finality depth are finalized.
+        /// - the removed contributions, and the claimed ones once final, are pruned from the
+        ///   contributions left to sweep, so that the sweeps only scan the live contributions.
+        ///
+        /// The next queued changes and identity unbindings (up to `MAX_CHUNK_SIZE` each) are
+        /// swept the same way, the ready ones being executed as with `execute_change` and
+        /// `force_unregister_identity`.
+        ///
+        /// The caller (i.e. a keeper) is paid the keeper bounty per processed item, out of the
+        /// free balance, so that the maintenance doesn't depend on the owner running cron jobs.
+        /// Only the items changing state are paid, the bounty of an expiration being capped to
+        /// the reward it releases, and the pruned index entries are not paid.
+        ///
+        /// Returns the number of processed items. A `KeeperSwept` event is emitted.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn keeper_sweep(&mut self) -> Result<u32, WorkflowError> {
+            // The chunk is swept backwards, so that the entries swapped into the pruned ones
+            // have already been swept
+            let chunk = self.keeper_cursor.chunk(MAX_CHUNK_SIZE, self.sweep_index_len);
+            let mut processed = 0u32;
+            let mut bounty: Balance = 0;
+            for index in chunk.indices().rev() {
+                let Some(contribution_id) = self.sweep_index.get(index) else {
+                    continue;
+                };
+                let Some(contribution) = self.contributions.get(contribution_id) else {
+                    self.prune_sweep_entry(index);
+                    processed += 1;
+                    continue;
+                };
+                if !contribution.is_reward_claimed
+                    && self.is_past_claim_deadline(&contribution)
+                    && !self.challenges.contains(contribution.id)
+                {
+                    let released = self.get_reservation(contribution.id);
+                    self.expire_contribution(contribution.id)?;
+                    self.prune_sweep_entry(index);
+                    bounty = bounty.saturating_add(self.keeper_bounty.min(released));
+                    processed += 1;
+                } else if matches!(
+                    self.finalizable_at.get(contribution.id),
+                    Some(finalizable_at) if self.env().block_number() >= finalizable_at
+                ) {
+                    self.finalize_approval(contribution.id);
+                    if contribution.is_reward_claimed {
+                        self.prune_sweep_entry(index);
+                    }
+                    bounty = bounty.saturating_add(self.keeper_bounty);
+                    processed += 1;
+                } else if contribution.is_reward_claimed && !self.finalizable_at.contains(contribution.id) {
+                    self.prune_sweep_entry(index);
+                    processed += 1;
+                }
+            }
+            self.keeper_cursor = match chunk.remaining() {
+                0 => Cursor::default(),
+                _ => chunk.next(),
+            };
+
+            // The executed items are swapped out of their index, the items swapped in being
+            // swept by the next sweeps
+            let chunk = self.change_cursor.chunk(MAX_CHUNK_SIZE, self.change_count);
+            let parameters: Vec<Parameter> = chunk
+                .indices()
+                .filter_map(|index| self.change_index.get(index))
+                .collect();
+            for parameter in parameters {
+                if self.execute_ready_change(parameter).is_ok() {
+                    bounty = bounty.saturating_add(self.keeper_bounty);
+                    processed += 1;
+                }
+            }
+            self.change_cursor = match chunk.remaining() {
+                0 => Cursor::default(),
+                _ => chunk.next(),
+            };
+
+            let chunk = self.unbinding_cursor.chunk(MAX_CHUNK_SIZE, self.unbinding_count);
+            let identities: Vec<HashValue> = chunk
+                .indices()
+                .filter_map(|index| self.unbinding_index.get(index))
+                .collect();
+            for identity in identities {
+                if self.execute_ready_unbinding(identity).is_ok() {
+                    bounty = bounty.saturating_add(self.keeper_bounty);
+                    processed += 1;
+                }
+            }
+            self.unbinding_cursor = match chunk.remaining() {
+                0 => Cursor::default(),
+                _ => chunk.next(),
+            };
+
+            let keeper = self.env().caller();
+            let bounty = bounty.min(self.free_balance()?);
+            self.pay(Asset::Native, keeper, bounty)?;
+
+            self.env().emit_event(KeeperSwept {
+                keeper,
+                processed,
+                bounty,
+            });
+
+            Ok(processed)
+        }
+
+        /// A helper function to prune the entry at a given `index` of the contributions left to
+        /// sweep, swapping the last entry into its position.
+        fn prune_sweep_entry(&mut self, index: u32) {
+            self.sweep_index_len = self.sweep_index_len.saturating_sub(1);
+            if let Some(last) = self.sweep_index.take(self.sweep_index_len) {
+                if index != self.sweep_index_len {
+                    self.sweep_index.insert(index, &last);
+                }
+            }
+        }
+
+        /// A helper function to set the `bounty` paid to keepers per item processed by `keeper_sweep`.
+        fn update_keeper_bounty(&mut self, account: AccountId, bounty: Balance) {
+            self.env().emit_event(FeeUpdated {
+                account,
+                old_fee: self.keeper_bounty,
+                new_fee: bounty,
+            });
+
+            self.keeper_bounty = bounty;
+        }
+
+        /// Simply returns the bounty paid to keepers per processed item.
+        #[ink(message)]
+        pub fn get_keeper_bounty(&self) -> Balance {
+            self.keeper_bounty
+        }
+
+        /// A helper function to expire a given unclaimed `contribution_id`, releasing its
+        /// reserved reward and giving its allocated earmarks back.
+        fn expire_contribution(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            let released = self.get_reservation(contribution_id);
+            self.contributions.remove(contribution_id);
+            self.approved_rewards.remove(contribution_id);
+            self.approval_blocks.remove(contribution_id);
+            self.claim_deadlines.remove(contribution_id);
+            self.disputable_until.remove(contribution_id);
+            self.finalizable_at.remove(contribution_id);
+            self.spot_checks.remove(contribution_id);
+            self.approved_count = self.approved_count.checked_sub(1).ok_or(WorkflowError::Overflow)?;
+            self.unclaimed_count = self.unclaimed_count.checked_sub(1).ok_or(WorkflowError::Overflow)?;
+            self.restore_earmarks(contribution_id)?;
+            self.release_reservation(contribution_id);
+            self.notify_lifecycle(contribution_id, Transition::Expired);
+
+            self.env().emit_event(ContributionExpired {
+                id: contribution_id,
+                released,
+            });
+
+            Ok(())
+        }
+
+        /// Set the spot checks configuration:
+        /// - the `rate` (in basis points) of approvals flagged for a spot check.
+        /// - the `auditors` the spot checks are assigned to, the owner if empty.
+        ///
+        /// The configuration only applies to the contributions approved afterwards.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_spot_checks(&mut self, rate: u32, auditors: Vec<AccountId>) -> Result<(), WorkflowError> {
+            if rate > BASIS_POINTS {
+                return Err(WorkflowError::InvalidShare);
+            }
+            if auditors.len() > MAX_AUDITORS {
+                return Err(WorkflowError::TooManyAuditors);
+            }
+
+            self.env().emit_event(SpotChecksUpdated {
+                account: self.env().caller(),
+                old_rate: self.spot_check_rate,
+                old_auditors: self.auditors.clone(),
+                new_rate: rate,
+                new_auditors: auditors.clone(),
+            });
+
+            self.spot_check_rate = rate;
+            self.auditors = auditors;
+            Ok(())
+        }
+
+        /// Confirm the spot check of a given `contribution_id`, allowing it to be claimed.
+        ///
+        /// Only callable by the assigned auditor or the owner.
+        /// A `SpotCheckConfirmed` event is emitted.
+        #[ink(message)]
+        pub fn confirm_spot_check(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            let auditor = self
+                .spot_checks
+                .get(contribution_id)
+                .ok_or(WorkflowError::NoSpotCheck)?;
+            let caller = self.env().caller();
+            if caller != auditor && !AccessControl::has_role(self, ADMIN, Some(caller)) {
+                return Err(WorkflowError::CallerIsNotAuditor);
+            }
+
+            self.spot_checks.remove(contribution_id);
+
+            self.env().emit_event(SpotCheckConfirmed {
+                id: contribution_id,
+                auditor: caller,
+            });
+
+            Ok(())
+        }
+
+        /// Simply returns the spot checks rate (in basis points) and auditors.
+        #[ink(message)]
+        pub fn get_spot_checks(&self) -> (u32, Vec<AccountId>) {
+            (self.spot_check_rate, self.auditors.clone())
+        }
+
+        /// Simply returns the assigned auditor of a given `contribution_id` if pending a spot check.
+        #[ink(message)]
+        pub fn get_spot_check(&self, contribution_id: u64) -> Option<AccountId> {
+            self.spot_checks.get(contribution_id)
+        }
+
+        /// A helper function to flag an approval for a spot check, returning the assigned auditor.
+        ///
+        /// The draw is derived from the approval and the current block, so it is deterministic
+        /// and verifiable but not predictable by the contributor ahead of the approval.
+        fn spot_check_auditor(&self, contribution_id: u64, identity: HashValue) -> Option<AccountId> {
+            if self.spot_check_rate == 0 {
+                return None;
+            }
+
+            let seed = Self::hash_payload(&(
+                contribution_id,
+                identity,
+                self.env().block_number(),
+                self.env().block_timestamp(),
+            ));
+            let draw = u64::from_le_bytes(seed[..8].try_into().ok()?);
+            if draw % u64::from(BASIS_POINTS) >= u64::from(self.spot_check_rate) {
+                return None;
+            }
+
+            match self.auditors.len() {
+                0 => Ownable::owner(self),
+                count => self.auditors.get((draw / u64::from(BASIS_POINTS)) as usize % count).copied(),
+            }
+        }
+
+        /// A helper function to compute the reward of a contribution approved for a given
+        /// `contributor_identity`, the `trial` reward if some.
+        fn approval_reward(
+            &self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+            trial: Option<TrialConfig>,
+        ) -> Result<Balance, WorkflowError> {
+            if let Some(trial) = trial {
+                return Ok(trial.reward.plancks());
+            }
+            self.multiplied_reward(contributor_identity, self.approval_base_reward(contribution_id, None))
+        }
+
+        /// A helper function to compute the reward of a contribution being approved before the
+        /// first contribution multiplier.
+        fn approval_base_reward(&self, contribution_id: u64, trial: Option<TrialConfig>) -> Balance {
+            if let Some(trial) = trial {
+                return trial.reward.plancks();
+            }
+            let escalated = self
+                .get_offered_reward(contribution_id)
+                .filter(|_| self.escalation.is_some());
+            self.listed_reward_of(contribution_id)
+                .or(escalated)
+                .unwrap_or(self.reward)
+        }
+
+        /// A helper function to approve a contribution.
+        fn approve_contribution(
+            &mut self,
+            contribution_id: u64,
+            contributor_identity: HashValue,
+            approver: AccountId,
+            template_id: Option<u32>,
+            trial: Option<TrialConfig>,
+            metadata: Option<HashValue>,
+        ) -> Result<(), WorkflowError> {
+            self.ensure_not_terminated()?;
+
+            if let Some(contribution) = self.contributions.get(contribution_id) {
+                // Identical re-approvals (e.g. retried workflow runs) are no-ops.
+                if contribution.identity == contributor_identity {
+                    return Ok(());
+                }
+                return Err(WorkflowError::ContributionAlreadyApproved);
+            }
+            if self.unbound_approvals.iter().any(|(id, _)| *id == contribution_id) {
+                return Err(WorkflowError::ContributionAlreadyApproved);
+            }
+            if self.proposals.contains(contribution_id) {
+                return Err(WorkflowError::VotingOpen);
+            }
+            self.ensure_allowed_assets(&self.basket)?;
+
+            let contributor = match self.get_account(contributor_identity) {
+                Some(contributor) => contributor,
+                None => return Err(WorkflowError::UnknownContributor),
+            };
+            if self.identity_is_expired(contributor_identity) {
+                return Err(WorkflowError::IdentityExpired);
+            }
+            self.ensure_not_self_approval(approver, contributor, contributor_identity)?;
+            let trials_count = self.get_trials_count(contributor_identity);
+            if matches!(trial, Some(trial) if trials_count >= trial.max_per_identity) {
+                return Err(WorkflowError::TrialCapReached);
+            }
+
+            let expires_at = match template_id {
+                Some(template_id) => {
+                    let template = self
+                        .templates
+                        .get(template_id)
+                        .ok_or(WorkflowError::UnknownTemplate)?;
+                    if let Some(min_reputation) = self.tier_min_reputations.get(template.tier) {
+                        if self.reputation_of(contributor_identity).unwrap_or(0) < min_reputation {
+                            return Err(WorkflowError::InsufficientReputation);
+                        }
+                    }
+                    match template.expiry {
+                        Some(expiry) => Some(
+                            self.env()
+                                .block_timestamp()
+                                .checked_add(expiry)
+                                .ok_or(WorkflowError::Overflow)?,
+                        ),
+                        None => None,
+                    }
+                }
+                None => None,
+            };
+            let expires_at = match trial {
+                Some(trial) => Some(
+                    self.env()
+                        .block_timestamp()
+                        .checked_add(trial.duration)
+                        .ok_or(WorkflowError::Overflow)?,
+                ),
+                None => expires_at,
+            };
+
+            // Trials are neither disputable nor pending finality
+            let disputable_until = match self.dispute_window {
+                0 => None,
+                _ if trial.is_some() => None,
+                window => Some(
+                    self.env()
+                        .block_timestamp()
+                        .checked_add(window)
+                        .ok_or(WorkflowError::Overflow)?,
+                ),
+            };
+
+            let finalizable_at = match self.finality_depth {
+                0 => None,
+                _ if trial.is_some() => None,
+                depth => Some(
+                    self.env()
+                        .block_number()
+                        .checked_add(depth)
+                        .ok_or(WorkflowError::Overflow)?,
+                ),
+            };
+
+            let contribution = Contribution::approve(
+                contribution_id,
+                contributor_identity,
+                contributor,
+                template_id,
+                expires_at,
+            )
+            .with_metadata(metadata);
+
+            // Reserve the native reward so that the approved contribution is always claimable,
+            // out of the funds earmarked for the contribution first. Nothing is written before
+            // the funds are checked, so that a failed approval leaves no trace.
+            let offered = self.get_offered_reward(contribution_id);
+            let escalated = offered.filter(|_| self.escalation.is_some());
+            let base = self.approval_base_reward(contribution_id, trial);
+            let reward = self.approval_reward(contribution_id, contributor_identity, trial)?;
+            let reservation = self.native_payout(reward)?;
+            let escrowed = self.token_payouts(reward)?;
+            let from_earmarks = reservation.min(self.earmarked_for(contribution_id));
+            let premium = self.premium_of(reward)?;
+            if (reservation - from_earmarks).saturating_add(premium) > self.free_balance()? {
+                return Err(WorkflowError::InsufficientFunds);
+            }
+            // Signed approvals and bound pre-approvals are not accounted to the relaying caller
+            if approver == self.env().caller() {
+                self.charge_session(reward)?;
+            }
+            self.insure(contribution_id, contributor, reward, premium)?;
+
+            // Lock the escalated reward offered for the open issue at assignment time
+            if offered.is_some() {
+                self.open_issues.remove(contribution_id);
+            }
+            if let Some(escalated) = escalated {
+                self.escalated_rewards.insert(contribution_id, &escalated);
+            }
+            self.allocate_earmarks(contribution_id, from_earmarks);
+            self.reservations.insert(contribution_id, &reservation);
+            self.reserved = self.reserved.checked_add(reservation).ok_or(WorkflowError::Overflow)?;
+            self.approved_rewards.insert(contribution_id, &(base, reward));
+            for (asset, amount) in &escrowed {
+                let escrow = self
+                    .escrows
+                    .get(asset)
+                    .unwrap_or(0)
+                    .checked_add(*amount)
+                    .ok_or(WorkflowError::Overflow)?;
+                self.escrows.insert(asset, &escrow);
+            }
+            if !escrowed.is_empty() {
+                self.escrow_allocations.insert(contribution_id, &escrowed);
+            }
+
+            self.contributions.insert(contribution_id, &contribution);
+            self.approval_blocks.insert(contribution_id, &self.env().block_number());
+            self.index_contribution(contribution_id, contributor)?;
+            if self.claim_window > 0 {
+                let deadline = self
+                    .env()
+                    .block_number()
+                    .checked_add(self.claim_window)
+                    .ok_or(WorkflowError::Overflow)?;
+                self.claim_deadlines.insert(contribution_id, &deadline);
+            }
+            if let Some(trial) = trial {
+                self.trial_rewards.insert(contribution_id, &trial.reward.plancks());
+                self.trials_count
+                    .insert(contributor_identity, &trials_count.checked_add(1).ok_or(WorkflowError::Overflow)?);
+            }
+            if let Some(disputable_until) = disputable_until {
+                self.disputable_until.insert(contribution_id, &disputable_until);
+            }
+            if let Some(finalizable_at) = finalizable_at {
+                self.finalizable_at.insert(contribution_id, &finalizable_at);
+            }
+            self.approved_count = self.approved_count.checked_add(1).ok_or(WorkflowError::Overflow)?;
+            self.unclaimed_count = self.unclaimed_count.checked_add(1).ok_or(WorkflowError::Overflow)?;
+
+            self.env().emit_event(ContributionApproval {
+                id: contribution_id,
+                contributor,
+                platform: self.get_platform(contributor_identity),
+                pending_finality: finalizable_at.is_some(),
+                metadata,
+            });
+            self.notify_lifecycle(contribution_id, Transition::Approved);
+
+            if let Some(auditor) = self.spot_check_auditor(contribution_id, contributor_identity) {
+                self.spot_checks.insert(contribution_id, &auditor);
+
+                self.env().emit_event(SpotCheckFlagged {
+                    id: contribution_id,
+                    auditor,
+                });
+            }
+            self.warn_low_funds()?;
+
+            Ok(())
+        }
+
+        /// Check the ability to claim for a given `contribution_id`.
+        #[ink(message)]
+        pub fn can_claim(&self, contribution_id: u64) -> Result<bool, WorkflowError> {
+            self.ensure_can_claim(contribution_id)?;
+
+            Ok(true)
+        }
+
+        /// Claim reward for a given `contribution_id`.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn claim(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            if self.compliance_mode {
+                return Err(WorkflowError::PaymentReferenceRequired);
+            }
+
+            let contribution = self.ensure_can_claim(contribution_id)?;
+            let reward = self.reward_of(&contribution)?;
+            self.claim_reward(contribution, reward, &[], None, None)
+        }
+
+        /// Claim the rewards of several `contribution_ids` at once, the native rewards being
+        /// transferred once per recipient. A `RewardClaimed` event is emitted per contribution.
+        ///
+        /// If the circuit breaker trips along the way, the remaining contributions are left
+        /// unclaimed. Returns the number of contributions claimed.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn claim_many(&mut self, contribution_ids: Vec<u64>) -> Result<u32, WorkflowError> {
+            if self.compliance_mode {
+                return Err(WorkflowError::PaymentReferenceRequired);
+            }
+            if contribution_ids.len() > MAX_CLAIM_BATCH {
+                return Err(WorkflowError::ClaimBatchTooLarge);
+            }
+
+            let mut batch = Vec::new();
+            // The native payout batched per contribution, recorded if its batch payout fails
+            let mut batched = Vec::new();
+            let batched_to = |batch: &Vec<(AccountId, Balance)>, recipient: AccountId| {
+                batch
+                    .iter()
+                    .find(|(account, _)| *account == recipient)
+                    .map_or(0, |(_, amount)| *amount)
+            };
+            let mut claimed = 0;
+            for contribution_id in contribution_ids {
+                if Pausable::paused(self) {
+                    break;
+                }
+                let contribution = self.ensure_can_claim(contribution_id)?;
+                let contributor = contribution.contributor;
+                let reward = self.reward_of(&contribution)?;
+                let before = batched_to(&batch, contributor);
+                self.claim_reward(contribution, reward, &[], Some(&mut batch), None)?;
+                batched.push((contribution_id, contributor, batched_to(&batch, contributor).saturating_sub(before)));
+                claimed += 1;
+            }
+
+            for (recipient, amount) in batch {
+                if self.pay_reward(Asset::Native, recipient, amount).is_err() {
+                    self.credit_payout(recipient, amount)?;
+                    for (contribution_id, _, amount) in batched.iter().filter(|(_, contributor, _)| *contributor == recipient) {
+                        self.record_failed_payout(*contribution_id, *amount)?;
+                    }
+                }
+            }
+
+            Ok(claimed)
+        }
+
+        /// Finalize the approval of a given `contribution_id` if pending finality, then pay its
+        /// reward to the contributor account, so that any account (e.g. a keeper) can deliver the
+        /// rewards of contributors who never send a transaction.
+        ///
+        /// Every claim constraint applies (e.g. finalized approval, closed dispute window, not
+        /// challenged nor expired). The `ContributionFinalized` (if pending finality) and
+        /// `RewardClaimed` events are emitted.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn finalize_and_pay(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            if self.compliance_mode {
+                return Err(WorkflowError::PaymentReferenceRequired);
+            }
+
+            let contribution = self.get_approved_contribution(contribution_id)?;
+            let contributor = self.contributor_of(&contribution)?;
+            if self.finalizable_at.contains(contribution_id) {
+                self.finalize(contribution_id)?;
+            }
+
+            let contribution = self.ensure_can_claim_for(contributor, contribution_id)?;
+            let reward = self.reward_of(&contribution)?;
+            self.claim_reward(contribution, reward, &[], None, None)
+        }
+
+        /// Claim reward for a given `contribution_id` on behalf of a given `contributor`, by a
+        /// proxy the `Claim` class is delegated to (e.g. a hot key). The reward is paid to the
+        /// contributor.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn proxy_claim(&mut self, contributor: AccountId, contribution_id: u64) -> Result<(), WorkflowError> {
+            if self.compliance_mode {
+                return Err(WorkflowError::PaymentReferenceRequired);
+            }
+            if self.get_proxy(contributor, self.env().caller()) < Some(MessageClass::Claim) {
+                return Err(WorkflowError::CallerIsNotProxy);
+            }
+
+            let contribution = self.ensure_can_claim_for(contributor, contribution_id)?;
+            let reward = self.reward_of(&contribution)?;
+            self.claim_reward(contribution, reward, &[], None, None)
+        }
+
+        /// Delegate the calls of a given `class` (and the lower ones) to a `proxy`, or revoke the
+        /// delegation if none.
+        ///
+        /// Only the `Query` and `Claim` classes can be delegated.
+        #[ink(message)]
+        pub fn set_proxy(&mut self, proxy: AccountId, class: Option<MessageClass>) -> Result<(), WorkflowError> {
+            let account = self.env().caller();
+            match class {
+                Some(MessageClass::Admin) => return Err(WorkflowError::InvalidProxyClass),
+                Some(class) => {
+                    self.proxies.insert((account, proxy), &class);
+                }
+                None => self.proxies.remove((account, proxy)),
+            }
+
+            self.env().emit_event(ProxyUpdated { account, proxy, class });
+
+            Ok(())
+        }
+
+        /// Simply returns the class of calls delegated by a given `account` to a `proxy` if some.
+        #[ink(message)]
+        pub fn get_proxy(&self, account: AccountId, proxy: AccountId) -> Option<MessageClass> {
+            self.proxies.get((account, proxy))
+        }
+
+        /// Returns the description and remediation hint of the `WorkflowError` of a given
+        /// `code` (i.e. its SCALE variant index) if some, so that wallets can show actionable
+        /// messages to contributors when claims fail.
+        #[ink(message)]
+        pub fn describe_error(&self, code: u8) -> Option<ErrorDescription> {
+            WorkflowError::describe(code).map(|(name, description, hint)| ErrorDescription {
+                name: String::from(name),
+                description: String::from(description),
+                hint: String::from(hint),
+            })
+        }
+
+        /// Perform `n` (capped to `MAX_CHUNK_SIZE`) representative storage writes then reads of
+        /// a contribution record, and return the gas consumed by the writes and by the reads,
+        /// to calibrate the weight assumptions of the chains the contracts are deployed to.
+        ///
+        /// The records are written outside of the contract storage layout and cleared
+        /// afterwards. Dev-only, compiled with the `benchmark` feature.
+        #[cfg(feature = "benchmark")]
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn benchmark_probe(&mut self, n: u32) -> Result<(u64, u64), WorkflowError> {
+            let indices = Cursor::default().chunk(n, n).indices();
+            let record = Contribution::approve(0, [0; 32], self.env().caller(), None, None);
+
+            let gas = ink::env::gas_left::<Environment>();
+            for index in indices.clone() {
+                ink::env::set_contract_storage(&(BENCHMARK_KEY, index), &record);
+            }
+            let writes = gas.saturating_sub(ink::env::gas_left::<Environment>());
+
+            let gas = ink::env::gas_left::<Environment>();
+            for index in indices.clone() {
+                let _ = ink::env::get_contract_storage::<_, Contribution>(&(BENCHMARK_KEY, index));
+            }
+            let reads = gas.saturating_sub(ink::env::gas_left::<Environment>());
+
+            for index in indices {
+                ink::env::clear_contract_storage(&(BENCHMARK_KEY, index));
+            }
+
+            Ok((writes, reads))
+        }
+
+        /// Returns the class of the message of a given `selector`, if classified.
+        #[ink(message)]
+        pub fn message_class(&self, selector: [u8; 4]) -> Option<MessageClass> {
+            MESSAGE_CLASSES
+                .iter()
+                .find(|(_, selectors)| selectors.contains(&selector))
+                .map(|(class, _)| *class)
+        }
+
+        /// Claim reward for a given `contribution_id`, split across several recipient accounts
+        /// of the contributor given as `(account, percent)` pairs (e.g. a tax wallet and a
+        /// spending wallet).
+        ///
+        /// Constraint(s):
+        /// 1. The percents must add up to 100.
+        /// 2. There must be at most `MAX_SPLITS` recipients.
+        ///
+        /// The rounding remainder is paid according to the dust policy.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn claim_split(&mut self, contribution_id: u64, splits: Vec<(AccountId, u8)>) -> Result<(), WorkflowError> {
+            if self.compliance_mode {
+                return Err(WorkflowError::PaymentReferenceRequired);
+            }
+
+            let total = splits
+                .iter()
+                .map(|(_, percent)| u32::from(*percent))
+                .sum::<u32>();
+            if splits.is_empty() || splits.len() > MAX_SPLITS || total != 100 {
+                return Err(WorkflowError::InvalidSplits);
+            }
+
+            let contribution = self.ensure_can_claim(contribution_id)?;
+            let reward = self.reward_of(&contribution)?;
+            self.claim_reward(contribution, reward, &splits, None, None)
+        }
+
+        /// Claim reward for a given `contribution_id` funded in both the native currency and a
+        /// PSP22 token (i.e. a basket of these two assets), paid along a chosen `native_share`
+        /// (in basis points) instead of the basket shares.
+        ///
+        /// The payout of each asset is bounded by its available escrow, failing with
+        /// `InsufficientFunds` otherwise:
+        /// - the token part by the token reward escrowed for the contribution, i.e. the native
+        ///   share is at least the one of the basket.
+        /// - the native part by the reserved reward and the free balance.
+        ///
+        /// The unchosen remainder of the token reward is released back to the pool.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn claim_with_ratio(&mut self, contribution_id: u64, native_share: u32) -> Result<(), WorkflowError> {
+            if self.compliance_mode {
+                return Err(WorkflowError::PaymentReferenceRequired);
+            }
+
+            let contribution = self.ensure_can_claim(contribution_id)?;
+            let reward = self.reward_of(&contribution)?;
+            self.claim_reward(contribution, reward, &[], None, Some(native_share))
+        }
+
+        /// Claim reward for a given `contribution_id`, boosted by consuming the `boost_id` NFT
+        /// of the boost collection held by the contributor.
+        ///
+        /// The NFT is burned by the contract, so the boost collection must allow it to burn
+        /// the tokens of the contributors. The boost exceeding the reserved reward is paid out
+        /// of the free balance, failing with `InsufficientFunds` otherwise.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn claim_with_boost(&mut self, contribution_id: u64, boost_id: Id) -> Result<(), WorkflowError> {
+            if self.compliance_mode {
+                return Err(WorkflowError::PaymentReferenceRequired);
+            }
+
+            let boost = self.boost.ok_or(WorkflowError::BoostNotSet)?;
+            let contribution = self.ensure_can_claim(contribution_id)?;
+            let reward = checked_mul_div(
+                self.reward_of(&contribution)?,
+                Balance::from(boost.multiplier),
+                Balance::from(BASIS_POINTS),
+            )
+            .ok_or(WorkflowError::Overflow)?;
+            // The boost is not reserved, hence paid out of the free balance only
+            let boosted = self
+                .native_payout(reward)?
+                .saturating_sub(self.get_reservation(contribution_id));
+            if boosted > self.free_balance()? {
+                return Err(WorkflowError::InsufficientFunds);
+            }
+
+            let burned = ink::env::call::build_call::<Environment>()
+                .call(boost.collection)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("PSP34Burnable::burn"),
+                    ))
+                    .push_arg(contribution.contributor)
+                    .push_arg(boost_id),
+                )
+                .returns::<Result<(), PSP34Error>>()
+                .try_invoke();
+            if !matches!(burned, Ok(Ok(Ok(())))) {
+                return Err(WorkflowError::BoostBurnFailed);
+            }
+
+            self.claim_reward(contribution, reward, &[], None, None)
+        }
+
+        /// Claim reward for a given `contribution_id` with a payment reference, required in
+        /// compliance mode:
+        /// - a non-empty `memo` of at most `MAX_MEMO_LENGTH` bytes.
+        /// - the `tax_reference` pre-registered for the contributor identity.
+        ///
+        /// A `PaymentReferenced` event is emitted along with the `RewardClaimed` event.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn claim_with_memo(
+            &mut self,
+            contribution_id: u64,
+            memo: String,
+            tax_reference: HashValue,
+        ) -> Result<(), WorkflowError> {
+            let contribution = self.ensure_can_claim(contribution_id)?;
+            if memo.is_empty()
+                || memo.len() > MAX_MEMO_LENGTH
+                || self.tax_references.get(contribution.identity) != Some(tax_reference)
+            {
+                return Err(WorkflowError::PaymentReferenceRequired);
+            }
+
+            let reward = self.reward_of(&contribution)?;
+            self.claim_reward(contribution, reward, &[], None, None)?;
+
+            self.env().emit_event(PaymentReferenced {
+                contribution_id,
+                memo,
+                tax_reference,
+            });
+
+            Ok(())
+        }
+
+        /// Register the invoice or tax `reference` hash of a given contributor `identity`,
+        /// or remove it if none.
+        ///
+        /// Only callable by the account registered for the identity.
+        #[ink(message)]
+        pub fn register_tax_reference(
+            &mut self,
+            identity: HashValue,
+            reference: Option<HashValue>,
+        ) -> Result<(), WorkflowError> {
+            if self.get_account(identity) != Some(self.env().caller()) {
+                return Err(WorkflowError::CallerIsNotContributor);
+            }
+
+            if let Some(reference) = reference {
+                self.tax_references.insert(identity, &reference);
+            } else {
+                self.tax_references.remove(identity);
+            }
+            Ok(())
+        }
+
+        /// Enable or disable the compliance mode, requiring claims to include a payment reference.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_compliance_mode(&mut self, compliance_mode: bool) -> Result<(), WorkflowError> {
+            self.env().emit_event(ComplianceModeUpdated {
+                account: self.env().caller(),
+                old_compliance_mode: self.compliance_mode,
+                new_compliance_mode: compliance_mode,
+            });
+
+            self.compliance_mode = compliance_mode;
+            Ok(())
+        }
+
+        /// Simply checks if the compliance mode is enabled.
+        #[ink(message)]
+        pub fn is_compliance_mode(&self) -> bool {
+            self.compliance_mode
+        }
+
+        /// Simply returns the tax reference hash of a given contributor `identity` if some.
+        #[ink(message)]
+        pub fn get_tax_reference(&self, identity: HashValue) -> Option<HashValue> {
+            self.tax_references.get(identity)
+        }
+
+        /// A helper function to set the boost NFT collection and the reward multiplier (in
+        /// basis points) it grants, if some.
+        fn update_boost(&mut self, account: AccountId, boost: Option<Boost>) {
+            self.env().emit_event(BoostUpdated {
+                account,
+                old_boost: self.boost,
+                new_boost: boost,
+            });
+
+            self.boost = boost;
+        }
+
+        /// Simply returns the boost NFT collection and multiplier if some.
+        #[ink(message)]
+        pub fn get_boost(&self) -> Option<Boost> {
+            self.boost
+        }
+
+        /// A helper function to pay the `reward` of a claimable `contribution`.
+        ///
+        /// The payout is split across the `splits` recipients if any, the contributor otherwise.
+        ///
+        /// If a `batch` is given, the native payouts are aggregated into it per recipient
+        /// instead of being paid, the caller paying the batch once complete.
+        ///
+        /// If a `native_share` is given, the basket payouts follow this ratio instead.
+        fn claim_reward(
+            &mut self,
+            contribution: Contribution,
+            reward: Balance,
+            splits: &[(AccountId, u8)],
+            mut batch: Option<&mut Vec<(AccountId, Balance)>>,
+            native_share: Option<u32>,
+        ) -> Result<(), WorkflowError> {
+            let contribution_id = contribution.id;
+            let (days_late, compensation) = self.late_compensation_of(contribution_id, reward)?;
+            // The late compensation and the first claim bonus are not reserved, hence paid out
+            // of the free balance only
+            let available = self.available_beyond_reservation(contribution_id, reward)?;
+            let compensation = compensation.min(available);
+            let bonus = self
+                .first_claim_bonus_of(&contribution)?
+                .min(available - compensation);
+            let reward = reward
+                .checked_add(compensation)
+                .and_then(|reward| reward.checked_add(bonus))
+                .ok_or(WorkflowError::Overflow)?;
+            let epoch = self.current_epoch();
+            let epoch_allocated = self
+                .epoch_allocations
+                .get(epoch)
+                .unwrap_or_default()
+                .checked_add(reward)
+                .ok_or(WorkflowError::Overflow)?;
+            let (outflow, estimated_outflow) = self.outflow_after(reward)?;
+            let payouts = match native_share {
+                Some(native_share) => self.ratio_payouts(contribution_id, reward, native_share)?,
+                None if self.basket.is_empty() => Vec::new(),
+                None => self.basket_payouts(reward)?,
+            };
+
+            // Unwind the parked funds if the native payout exceeds the contract balance, along
+            // with the payouts of the batch not paid yet
+            let batched = batch
+                .as_ref()
+                .map_or(0, |batch| batch.iter().map(|(_, amount)| amount).sum::<Balance>());
+            let native_payout = match native_share {
+                Some(_) => payouts
+                    .iter()
+                    .filter(|(asset, _)| *asset == Asset::Native)
+                    .map(|(_, amount)| *amount)
+                    .sum(),
+                None => self.native_payout(reward)?,
+            }
+            .saturating_add(batched);
+            let shortfall = native_payout.saturating_sub(self.env().balance()).min(self.parked);
+            if shortfall > 0 {
+                self.yield_withdraw(shortfall)?;
+                self.parked -= shortfall;
+            }
+
+            // Perform the reward claim
+            if self.basket.is_empty() {
+                for (recipient, amount) in self.split_payout(contribution.contributor, reward, splits)? {
+                    match batch.as_mut() {
+                        Some(batch) => match batch.iter_mut().find(|(account, _)| *account == recipient) {
+                            Some((_, total)) => *total = total.checked_add(amount).ok_or(WorkflowError::Overflow)?,
+                            None => batch.push((recipient, amount)),
+                        },
+                        None => {
+                            if self.pay_reward(Asset::Native, recipient, amount).is_err() {
+                                self.credit_payout(recipient, amount)?;
+                                if recipient == contribution.contributor {
+                                    self.record_failed_payout(contribution_id, amount)?;
+                                }
+                            }
+                        }
+                    }
+                }
+            } else {
+                // Pay what succeeds, the failed payouts are withdrawable later
+                for (asset, asset_amount) in payouts {
+                    for (recipient, amount) in self.split_payout(contribution.contributor, asset_amount, splits)? {
+                        if self.pay_reward(asset, recipient, amount).is_err() {
+                            self.defer_payout(recipient, asset, amount)?;
+                            if asset == Asset::Native && recipient == contribution.contributor {
+                                self.record_failed_payout(contribution_id, amount)?;
+                            }
+                        }
+                    }
+                }
+            }
+
+            self.unclaimed_count = self.unclaimed_count.checked_sub(1).ok_or(WorkflowError::Overflow)?;
+            self.contributions.insert(contribution_id, &contribution.claim());
+            self.release_reservation(contribution_id);
+            self.notify_lifecycle(contribution_id, Transition::Claimed);
+            self.epoch_allocations.insert(epoch, &epoch_allocated);
+            let person = self.person_of(contribution.identity);
+            let claims_count = self.get_claims_count(contribution.identity);
+            self.claims_count
+                .insert(person, &claims_count.checked_add(1).ok_or(WorkflowError::Overflow)?);
+            self.last_claims.insert(person, &self.env().block_timestamp());
+            self.extend_streak(contribution.identity, epoch);
+            let stats = self.get_stats(contribution.identity);
+            self.stats.insert(contribution.identity, &stats.record(reward, self.env().block_number()));
+            self.cooldown_exemptions.remove(contribution_id);
+
+            // Only attach the pre-registered signature if it matches the actual payout
+            let payload = self.credential_payload(&contribution, reward);
+            let signature = self
+                .credential_signatures
+                .take(contribution_id)
+                .filter(|signature| self.is_platform_signature(&payload, signature));
+            let credential = Credential {
+                payload,
+                claimed_at: self.env().block_number(),
+                signature,
+            };
+            self.credentials.insert(contribution_id, &credential);
+
+            self.env().emit_event(RewardClaimed {
+                contribution_id,
+                contributor: contribution.contributor,
+                reward,
+                bonus,
+            });
+
+            if self.late_since.take(contribution_id).is_some() {
+                self.late_compensations.insert(contribution_id, &compensation);
+
+                self.env().emit_event(LatePayoutCompensated {
+                    contribution_id,
+                    days_late,
+                    compensation,
+                });
+            }
+
+            // Trip the circuit breaker once the outflow exceeds the threshold
+            self.outflow = outflow;
+            if let Some(breaker) = self.circuit_breaker {
+                if estimated_outflow > breaker.max_outflow && !Pausable::paused(self) {
+                    pausable::Internal::_pause(self)?;
+
+                    self.env().emit_event(CircuitBreakerTripped {
+                        outflow: estimated_outflow,
+                        max_outflow: breaker.max_outflow,
+                    });
+                }
+            }
+            self.warn_low_funds()?;
+
+            Ok(())
+        }
+
+        /// Enforce or not the separation of duties, approvals failing with `SelfApprovalForbidden`
+        /// when the approver is the contributor (or one of its linked identities), so that
+        /// maintainers cannot pay themselves without a second signer.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_separation_of_duties(&mut self, enforced: bool) -> Result<(), WorkflowError> {
+            self.env().emit_event(SeparationOfDutiesUpdated {
+                account: self.env().caller(),
+                old_enforced: self.separation_of_duties,
+                new_enforced: enforced,
+            });
+
+            self.separation_of_duties = enforced;
+            Ok(())
+        }
+
+        /// Simply checks if the separation of duties is enforced.
+        #[ink(message)]
+        pub fn is_separation_of_duties(&self) -> bool {
+            self.separation_of_duties
+        }
+
+        /// A helper function to ensure the `approver` (i.e. the caller, the signer of a signed
+        /// approval or the pre-approver) is neither the `contributor` nor a person linked to the
+        /// `contributor_identity` while the separation of duties is enforced.
+        fn ensure_not_self_approval(
+            &self,
+            approver: AccountId,
+            contributor: AccountId,
+            contributor_identity: HashValue,
+        ) -> Result<(), WorkflowError> {
+            if !self.separation_of_duties {
+                return Ok(());
+            }
+            let person = self.person_of(contributor_identity);
+            if approver == contributor
+                || matches!(self.identity_of.get(approver), Some(identity) if self.person_of(identity) == person)
+            {
+                return Err(WorkflowError::SelfApprovalForbidden);
+            }
+            Ok(())
+        }
+
+        /// Set the free balance `threshold` below which approvals and claims emit a `LowFunds`
+        /// event (e.g. to alert the maintainers through indexers), disabled if zero.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_low_funds_threshold(&mut self, threshold: Balance) -> Result<(), WorkflowError> {
+            self.env().emit_event(LowFundsThresholdUpdated {
+                account: self.env().caller(),
+                old_threshold: self.low_funds_threshold,
+                new_threshold: threshold,
+            });
+
+            self.low_funds_threshold = threshold;
+            Ok(())
+        }
+
+        /// Simply returns the free balance threshold below which a `LowFunds` event is emitted.
+        #[ink(message)]
+        pub fn get_low_funds_threshold(&self) -> Balance {
+            self.low_funds_threshold
+        }
+
+        /// A helper function to emit a `LowFunds` event if the free balance is below the low
+        /// funds threshold.
+        fn warn_low_funds(&self) -> Result<(), WorkflowError> {
+            let balance = self.free_balance()?;
+            if balance < self.low_funds_threshold {
+                self.env().emit_event(LowFunds {
+                    outstanding: self.outstanding_obligations()?,
+                    balance,
+                });
+            }
+            Ok(())
+        }
+
+        /// Report that the claim of a given `contribution_id` cannot be paid because of the
+        /// contract underfunding, starting the accrual of the late payout compensation.
+        ///
+        /// Only callable by the contributor, while the contract balance is lower than the reward.
+        /// A `LatePayoutReported` event is emitted.
+        #[ink(message)]
+        pub fn report_late_payout(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            let contribution = self.ensure_can_claim(contribution_id)?;
+            if self.late_since.contains(contribution_id) {
+                return Err(WorkflowError::LatePayoutAlreadyReported);
+            }
+
+            let reward = self.reward_of(&contribution)?;
+            let balance = self.env().balance().saturating_add(self.parked);
+            if balance >= reward {
+                return Err(WorkflowError::NotUnderfunded);
+            }
+
+            self.late_since.insert(contribution_id, &self.env().block_timestamp());
+
+            self.env().emit_event(LatePayoutReported {
+                contribution_id,
+                reward,
+                balance,
+            });
+
+            Ok(())
+        }
+
+        /// Set the late payout compensation:
+        /// - the `rate` (in basis points of the reward) accrued per day late.
+        /// - the `cap` (in basis points of the reward) of the compensation.
+        ///
+        /// The compensation is not reserved, hence capped to the free balance upon claim.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_late_compensation(&mut self, rate: u32, cap: u32) -> Result<(), WorkflowError> {
+            if cap > BASIS_POINTS {
+                return Err(WorkflowError::InvalidShare);
+            }
+
+            self.env().emit_event(LateCompensationUpdated {
+                account: self.env().caller(),
+                old_rate: self.late_compensation_rate,
+                old_cap: self.late_compensation_cap,
+                new_rate: rate,
+                new_cap: cap,
+            });
+
+            self.late_compensation_rate = rate;
+            self.late_compensation_cap = cap;
+            Ok(())
+        }
+
+        /// Set the recipient of the rounding remainders of the pro-rata payouts.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_dust_policy(&mut self, dust_policy: DustPolicy) -> Result<(), WorkflowError> {
+            self.env().emit_event(DustPolicyUpdated {
+                account: self.env().caller(),
+                old_dust_policy: self.dust_policy,
+                new_dust_policy: dust_policy,
+            });
+
+            self.dust_policy = dust_policy;
+            Ok(())
+        }
+
+        /// Simply returns the recipient of the rounding remainders of the pro-rata payouts.
+        #[ink(message)]
+        pub fn get_dust_policy(&self) -> DustPolicy {
+            self.dust_policy
+        }
+
+        /// Simply returns the late payout compensation rate and cap (in basis points).
+        #[ink(message)]
+        pub fn get_late_compensation(&self) -> (u32, u32) {
+            (self.late_compensation_rate, self.late_compensation_cap)
+        }
+
+        /// Returns the late payout compensation of a given `contribution_id`: the accrued one
+        /// if the claim is late, the paid one if claimed, zero otherwise.
+        #[ink(message)]
+        pub fn get_late_compensation_of(&self, contribution_id: u64) -> Result<Balance, WorkflowError> {
+            if let Some(compensation) = self.late_compensations.get(contribution_id) {
+                return Ok(compensation);
+            }
+            let Some(contribution) = self.contributions.get(contribution_id) else {
+                return Ok(0);
+            };
+
+            let reward = self.reward_of(&contribution)?;
+            Ok(self.late_compensation_of(contribution_id, reward)?.1)
+        }
+
+        /// A helper function to set the payout basket applied upon claim (e.g. 70% of a PSP22
+        /// token and 30% native).
+        ///
+        /// Fails if an asset of the basket was disallowed since the change was queued.
+        fn update_basket(&mut self, account: AccountId, basket: Vec<BasketShare>) -> Result<(), WorkflowError> {
+            self.ensure_allowed_assets(&basket)?;
+
+            let old_basket = core::mem::replace(&mut self.basket, basket.clone());
+            self.env().emit_event(BasketUpdated {
+                account,
+                old_basket,
+                new_basket: basket,
+            });
+
+            Ok(())
+        }
+
+        /// A helper function to validate a payout `basket`.
+        ///
+        /// The shares (in basis points) must add up to `BASIS_POINTS`, an empty basket
+        /// meaning the reward is paid in native currency only. Every asset must be allowed.
+        fn validate_basket(&self, basket: &[BasketShare]) -> Result<(), WorkflowError> {
+            self.ensure_allowed_assets(basket)?;
+            if !basket.is_empty() {
+                let mut total: u32 = 0;
+                for (index, share) in basket.iter().enumerate() {
+                    if share.share == 0 || basket[..index].iter().any(|other| other.asset == share.asset) {
+                        return Err(WorkflowError::InvalidBasket);
+                    }
+                    total = total.saturating_add(share.share);
+                }
+                if basket.len() > MAX_BASKET_ASSETS || total != BASIS_POINTS {
+                    return Err(WorkflowError::InvalidBasket);
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Allow or disallow an `asset` (e.g. a reviewed PSP22 token) in the payout basket, the
+        /// native currency being always allowed.
+        ///
+        /// Disallowing an asset of the basket blocks the approvals until the basket is updated.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_allowed_asset(&mut self, asset: Asset, allowed: bool) -> Result<(), WorkflowError> {
+            self.env().emit_event(AllowedAssetUpdated {
+                account: self.env().caller(),
+                asset,
+                old_allowed: self.is_allowed_asset(asset),
+                new_allowed: allowed,
+            });
+
+            if allowed {
+                self.allowed_assets.insert(asset, &());
+            } else {
+                self.allowed_assets.remove(asset);
+            }
+
+            Ok(())
+        }
+
+        /// Simply checks if a given `asset` is allowed in the payout basket.
+        #[ink(message)]
+        pub fn is_allowed_asset(&self, asset: Asset) -> bool {
+            asset == Asset::Native || self.allowed_assets.contains(asset)
+        }
+
+        /// A helper function to ensure every asset of a `basket` is allowed.
+        fn ensure_allowed_assets(&self, basket: &[BasketShare]) -> Result<(), WorkflowError> {
+            if !basket.iter().all(|share| self.is_allowed_asset(share.asset)) {
+                return Err(WorkflowError::AssetNotAllowed);
+            }
+            Ok(())
+        }
+
+        /// Withdraw the pending payout of a given `asset`, recorded when a payout failed.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn withdraw_pending(&mut self, asset: Asset) -> Result<(), WorkflowError> {
+            self.withdraw_pending_to(asset, Self::env().caller())
+        }
+
+        /// Withdraw the pending payout of a given `asset` to another account `to` (e.g. if the
+        /// contributor account cannot receive transfers anymore).
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn withdraw_pending_to(&mut self, asset: Asset, to: AccountId) -> Result<(), WorkflowError> {
+            let caller = Self::env().caller();
+            let amount = self
+                .pending_payouts
+                .get((caller, asset))
+                .ok_or(WorkflowError::NoPendingPayout)?;
+
+            self.pay_reward(asset, to, amount)?;
+            self.pending_payouts.remove((caller, asset));
+            let pending_total = self.pending_totals.get(asset).unwrap_or(0);
+            self.pending_totals
+                .insert(asset, &pending_total.saturating_sub(amount));
+
+            Ok(())
+        }
+
+        /// Simply returns the payout basket.
+        #[ink(message)]
+        pub fn get_basket(&self) -> Vec<BasketShare> {
+            self.basket.clone()
+        }
+
+        /// Simply returns the pending payout of a given `contributor` and `asset`.
+        #[ink(message)]
+        pub fn get_pending_payout(&self, contributor: AccountId, asset: Asset) -> Balance {
+            self.pending_payouts.get((contributor, asset)).unwrap_or(0)
+        }
+
+        /// Simply returns the native payout of a given `contribution_id` credited to its
+        /// contributor instead of being paid, zero if paid.
+        #[ink(message)]
+        pub fn get_failed_payout(&self, contribution_id: u64) -> Balance {
+            self.failed_payouts.get(contribution_id).unwrap_or(0)
+        }
+
+        /// Hand the failed native payout of a given `contribution_id` over to the insurance pool
+        /// compensating the contributor, up to a `max` amount.
+        ///
+        /// The amount taken over (capped by the still pending payout of the contributor) is
+        /// removed from the pending payout, so that the contributor cannot withdraw it anymore.
+        /// Returns the amount taken over. A `FailedPayoutTakenOver` event is emitted.
+        ///
+        /// Only callable by the insurance pool.
+        #[ink(message)]
+        pub fn take_over_failed_payout(&mut self, contribution_id: u64, max: Balance) -> Result<Balance, WorkflowError> {
+            if self.insurance.map(|insurance| insurance.pool) != Some(self.env().caller()) {
+                return Err(WorkflowError::CallerIsNotInsurance);
+            }
+            let contribution = self
+                .contributions
+                .get(contribution_id)
+                .ok_or(WorkflowError::UnknownContribution)?;
+
+            let failed = self.get_failed_payout(contribution_id);
+            let pending = self.get_pending_payout(contribution.contributor, Asset::Native);
+            let amount = failed.min(pending).min(max);
+            if failed > amount {
+                self.failed_payouts.insert(contribution_id, &(failed - amount));
+            } else {
+                self.failed_payouts.remove(contribution_id);
+            }
+            if pending > amount {
+                self.pending_payouts
+                    .insert((contribution.contributor, Asset::Native), &(pending - amount));
+            } else {
+                self.pending_payouts.remove((contribution.contributor, Asset::Native));
+            }
+            let pending_total = self.pending_totals.get(Asset::Native).unwrap_or(0);
+            self.pending_totals
+                .insert(Asset::Native, &pending_total.saturating_sub(amount));
+
+            self.env().emit_event(FailedPayoutTakenOver {
+                contribution_id,
+                contributor: contribution.contributor,
+                amount,
+            });
+
+            Ok(amount)
+        }
+
+        /// Returns the amount of a given `asset` escrowed for the approved but unclaimed
+        /// rewards and the pending payouts.
+        #[ink(message)]
+        pub fn get_escrow(&self, asset: Asset) -> Result<Balance, WorkflowError> {
+            let escrow = match asset {
+                Asset::Native => self.reserved,
+                Asset::Psp22(_) => self.escrows.get(asset).unwrap_or(0),
+            };
+
+            escrow
+                .checked_add(self.pending_totals.get(asset).unwrap_or(0))
+                .ok_or(WorkflowError::Overflow)
+        }
+
+        /// Simply returns the total native reward reserved for the unclaimed contributions.
+        #[ink(message)]
+        pub fn get_reserved(&self) -> Balance {
+            self.reserved
+        }
+
+        /// Simply returns the native reward reserved for a given unclaimed `contribution_id`.
+        #[ink(message)]
+        pub fn get_reservation(&self, contribution_id: u64) -> Balance {
+            self.reservations.get(contribution_id).unwrap_or(0)
+        }
+
+        /// Exempt or not a given `contribution_id` from the claim cooldown.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_cooldown_exemption(&mut self, contribution_id: u64, exempted: bool) -> Result<(), WorkflowError> {
+            let old_exempted = self.cooldown_exemptions.contains(contribution_id);
+            if exempted {
+                self.cooldown_exemptions.insert(contribution_id, &());
+            } else {
+                self.cooldown_exemptions.remove(contribution_id);
+            }
+
+            self.env().emit_event(CooldownExemptionUpdated {
+                account: self.env().caller(),
+                contribution_id,
+                old_exempted,
+                new_exempted: exempted,
+            });
+
+            Ok(())
+        }
+
+        /// Simply returns the minimum delay (in milliseconds) between two claims of the same identity.
+        #[ink(message)]
+        pub fn get_claim_cooldown(&self) -> Timestamp {
+            self.claim_cooldown
+        }
+
+        /// Simply returns the last claim timestamp of the person of a given `identity` if some.
+        #[ink(message)]
+        pub fn get_last_claim(&self, identity: HashValue) -> Option<Timestamp> {
+            self.last_claims.get(self.person_of(identity))
+        }
+
+        /// Returns the claim streak of a given `identity` if some, the current streak being
+        /// reset if no reward was claimed in the previous epoch nor the current one.
+        #[ink(message)]
+        pub fn get_streak(&self, identity: HashValue) -> Option<Streak> {
+            self.streaks
+                .get(identity)
+                .map(|streak| streak.at(self.current_epoch()))
+        }
+
+        /// Link a given `identity` to a `person` (e.g. the hash of a handle chosen by the owner)
+        /// once asserted to belong to the same person as other identities, or unlink it if none.
+        ///
+        /// Linked identities share their claims count, cap and cooldown, the counters of the
+        /// `identity` being merged into the person ones upon link.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn link_identity(&mut self, identity: HashValue, person: Option<HashValue>) -> Result<(), WorkflowError> {
+            let old_person = self.persons.get(identity);
+            if let Some(person) = person {
+                if self.persons.contains(identity) || self.persons.contains(person) {
+                    return Err(WorkflowError::IdentityAlreadyLinked);
+                }
+
+                let claims_count = self
+                    .claims_count
+                    .take(identity)
+                    .unwrap_or_default()
+                    .checked_add(self.claims_count.get(person).unwrap_or_default())
+                    .ok_or(WorkflowError::Overflow)?;
+                self.claims_count.insert(person, &claims_count);
+                if let Some(last_claim) = self.last_claims.take(identity) {
+                    if self.last_claims.get(person).unwrap_or_default() < last_claim {
+                        self.last_claims.insert(person, &last_claim);
+                    }
+                }
+                self.persons.insert(identity, &person);
+            } else {
+                self.persons.remove(identity);
+            }
+
+            self.env().emit_event(IdentityLinked {
+                account: self.env().caller(),
+                identity,
+                old_person,
+                new_person: person,
+            });
+
+            Ok(())
+        }
+
+        /// Set the maximum number of claimed rewards per person, unlimited if zero.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_claims_cap(&mut self, claims_cap: u32) -> Result<(), WorkflowError> {
+            self.env().emit_event(ClaimsCapUpdated {
+                account: self.env().caller(),
+                old_claims_cap: self.claims_cap,
+                new_claims_cap: claims_cap,
+            });
+
+            self.claims_cap = claims_cap;
+            Ok(())
+        }
+
+        /// Simply returns the maximum number of claimed rewards per person.
+        #[ink(message)]
+        pub fn get_claims_cap(&self) -> u32 {
+            self.claims_cap
+        }
+
+        /// Returns the person a given `identity` is linked to, the identity itself if unlinked.
+        #[ink(message)]
+        pub fn person_of(&self, identity: HashValue) -> HashValue {
+            self.persons.get(identity).unwrap_or(identity)
+        }
+
+        /// Queue a sensitive parameter `change`, executable once the parameter delay has elapsed.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn queue_change(&mut self, change: ParameterChange) -> Result<(), WorkflowError> {
+            self.validate_change(&change)?;
+
+            let parameter = change.parameter();
+            if self.pending_changes.contains(parameter) {
+                return Err(WorkflowError::ChangeAlreadyQueued);
+            }
+
+            let eta = self
+                .env()
+                .block_timestamp()
+                .checked_add(self.get_change_delay(parameter))
+                .ok_or(WorkflowError::Overflow)?;
+            self.pending_changes.insert(
+                parameter,
+                &PendingChange {
+                    change: change.clone(),
+                    eta,
+                    account: self.env().caller(),
+                },
+            );
+            self.change_positions.insert(parameter, &self.change_count);
+            self.change_index.insert(self.change_count, &parameter);
+            self.change_count = self
+                .change_count
+                .checked_add(1)
+                .ok_or(WorkflowError::Overflow)?;
+
+            self.env().emit_event(ChangeQueued { change, eta });
+
+            Ok(())
+        }
+
+        /// Execute the queued change of a given `parameter` once its delay has elapsed.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn execute_change(&mut self, parameter: Parameter) -> Result<(), WorkflowError> {
+            self.execute_ready_change(parameter)
+        }
+
+        /// A helper function to execute the queued change of a given `parameter` once its
+        /// delay has elapsed.
+        fn execute_ready_change(&mut self, parameter: Parameter) -> Result<(), WorkflowError> {
+            let pending = self
+                .pending_changes
+                .get(parameter)
+                .ok_or(WorkflowError::ChangeNotQueued)?;
+            if self.env().block_timestamp() < pending.eta {
+                return Err(WorkflowError::ChangeNotReady);
+            }
+
+            // The change is attributed to the account which queued it, not to the executing
+            // caller (e.g. a keeper)
+            let account = pending.account;
+            match pending.change.clone() {
+                ParameterChange::PlatformKey(platform_key) => self.platform_key = platform_key,
+                ParameterChange::FirstContributionMultiplier(multiplier) => {
+                    self.first_contribution_multiplier = multiplier
+                }
+                ParameterChange::OracleKey(oracle_key) => self.oracle_key = oracle_key,
+                ParameterChange::IdentityValidity(validity) => self.identity_validity = validity,
+                ParameterChange::ClaimCooldown(cooldown) => self.claim_cooldown = cooldown,
+                ParameterChange::AttestorKey(attestor_key) => self.attestor_key = attestor_key,
+                ParameterChange::Reward(reward) => self.update_reward(account, reward)?,
+                ParameterChange::TierReward(tier, reward) => self.update_tier_reward(account, tier, reward),
+                ParameterChange::DifficultyReward(difficulty, reward) => {
+                    self.update_difficulty_reward(account, difficulty, reward)
+                }
+                ParameterChange::IdentityVerifier(verifier) => self.update_identity_verifier(account, verifier),
+                ParameterChange::Verifier(verifier) => self.add_verifier(verifier)?,
+                ParameterChange::ClaimWindow(claim_window) => self.update_claim_window(account, claim_window),
+                ParameterChange::ChallengeConfig {
+                    bond,
+                    dispute_window,
+                    challenger_share,
+                } => self.update_challenge_config(account, bond, dispute_window, challenger_share),
+                ParameterChange::KeeperBounty(bounty) => self.update_keeper_bounty(account, bounty),
+                ParameterChange::CodeHash(code_hash) => self.upgrade_code(code_hash)?,
+                ParameterChange::Vesting(vesting) => self.update_vesting(account, vesting),
+                ParameterChange::IdentityRegistry(identity_registry) => {
+                    self.update_identity_registry(account, identity_registry)
+                }
+                ParameterChange::YieldAdapter(yield_adapter) => self.update_yield_adapter(account, yield_adapter)?,
+                ParameterChange::Basket(basket) => self.update_basket(account, basket)?,
+                ParameterChange::Arbitrator(arbitrator) => self.update_arbitrator(account, arbitrator),
+                ParameterChange::Insurance(insurance) => self.update_insurance(account, insurance),
+                ParameterChange::Boost(boost) => self.update_boost(account, boost),
+            }
+            self.dequeue_change(parameter);
+
+            self.env().emit_event(ChangeExecuted {
+                change: pending.change,
+            });
+
+            Ok(())
+        }
+
+        /// Cancel the queued change of a given `parameter`.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn cancel_change(&mut self, parameter: Parameter) -> Result<(), WorkflowError> {
+            if !self.pending_changes.contains(parameter) {
+                return Err(WorkflowError::ChangeNotQueued);
+            }
+            self.dequeue_change(parameter);
+
+            self.env().emit_event(ChangeCancelled { parameter });
+
+            Ok(())
+        }
+
+        /// A helper function to remove the queued change of a given `parameter`.
+        fn dequeue_change(&mut self, parameter: Parameter) {
+            self.pending_changes.remove(parameter);
+            // Swap the last queued change into the position of the removed one
+            if let Some(position) = self.change_positions.take(parameter) {
+                self.change_count = self.change_count.saturating_sub(1);
+                if let Some(last) = self.change_index.take(self.change_count) {
+                    if last != parameter {
+                        self.change_index.insert(position, &last);
+                        self.change_positions.insert(last, &position);
+                    }
+                }
+            }
+        }
+
+        /// Increase the minimum delay (in milliseconds) of a given `parameter` changes.
+        ///
+        /// Delays can only be increased, so that they cannot be bypassed.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_change_delay(&mut self, parameter: Parameter, delay: Timestamp) -> Result<(), WorkflowError> {
+            let old_delay = self.get_change_delay(parameter);
+            if delay < old_delay {
+                return Err(WorkflowError::InvalidDelay);
+            }
+
+            self.env().emit_event(ChangeDelayUpdated {
+                account: self.env().caller(),
+                parameter,
+                old_delay,
+                new_delay: delay,
+            });
+
+            self.change_delays.insert(parameter, &delay);
+            Ok(())
+        }
+
+        /// Simply returns the queued change of a given `parameter` if some.
+        #[ink(message)]
+        pub fn get_pending_change(&self, parameter: Parameter) -> Option<PendingChange> {
+            self.pending_changes.get(parameter)
+        }
+
+        /// Simply returns the minimum delay (in milliseconds) of a given `parameter` changes.
+        #[ink(message)]
+        pub fn get_change_delay(&self, parameter: Parameter) -> Timestamp {
+            self.change_delays
+                .get(parameter)
+                .unwrap_or(DEFAULT_CHANGE_DELAY)
+        }
+
+        /// Register the platform signature of the approved `contribution` credential.
+        ///
+        /// The signature must be issued by the platform key over the Blake2x256 hash
+        /// of the SCALE encoded `CredentialPayload`.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn register_credential_signature(
+            &mut self,
+            contribution_id: u64,
+            signature: SignatureValue,
+        ) -> Result<(), WorkflowError> {
+            let contribution = self.get_approved_contribution(contribution_id)?;
+            if contribution.is_reward_claimed {
+                return Err(WorkflowError::AlreadyClaimed);
+            }
+
+            let platform_key = self.platform_key.ok_or(WorkflowError::PlatformKeyNotSet)?;
+            let payload = self.credential_payload(&contribution, self.reward_of(&contribution)?);
+            if self.recover_signer(&Self::hash_payload(&payload), &signature)? != platform_key {
+                return Err(WorkflowError::InvalidSignature);
+            }
+
+            self.env().emit_event(CredentialSignatureRegistered {
+                account: self.env().caller(),
+                contribution_id,
+                old_signature: self.credential_signatures.get(contribution_id),
+                new_signature: signature.to_vec(),
+            });
+
+            self.credential_signatures
+                .insert(contribution_id, &signature.to_vec());
+            Ok(())
+        }
+
+        /// Refresh the registration of a given `identity`, bound to the caller.
+        ///
+        /// If a platform key is set, the `signature` must be issued by the platform key
+        /// over the Blake2x256 hash of the SCALE encoded `AttestationPayload`.
+        #[ink(message)]
+        #[modifiers(when_not_paused)]
+        pub fn reattest(
+            &mut self,
+            identity: HashValue,
+            signature: Option<SignatureValue>,
+        ) -> Result<(), WorkflowError> {
+            let caller = Self::env().caller();
+            match self.get_account(identity) {
+                Some(account) if account == caller => (),
+                Some(_) => return Err(WorkflowError::CallerIsNotContributor),
+                None => return Err(WorkflowError::UnknownContributor),
+            }
+
+            let nonce = self
+                .attestations
+                .get(identity)
+                .map_or(0, |attestation| attestation.nonce);
+            if let Some(platform_key) = self.platform_key {
+                let signature = signature.ok_or(WorkflowError::SignatureRequired)?;
+                let payload = AttestationPayload {
+                    contract: self.env().account_id(),
+                    identity,
+                    account: caller,
+                    nonce,
+                };
+                if self.recover_signer(&Self::hash_payload(&payload), &signature)? != platform_key {
+                    return Err(WorkflowError::InvalidSignature);
+                }
+            }
+
+            self.attestations.insert(
+                identity,
+                &Attestation {
+                    attested_at: self.env().block_timestamp(),
+                    nonce: nonce.checked_add(1).ok_or(WorkflowError::Overflow)?,
+                },
+            );
+
+            self.env()
+                .emit_event(IdentityReattested { identity, caller });
+
+            Ok(())
+        }
+
+        /// Simply returns the latest attestation of a given `identity` if some.
+        #[ink(message)]
+        pub fn get_attestation(&self, identity: HashValue) -> Option<Attestation> {
+            self.attestations.get(identity)
+        }
+
+        /// Simply returns the duration (in milliseconds) an identity registration is valid for if some.
+        #[ink(message)]
+        pub fn get_identity_validity(&self) -> Option<Timestamp> {
+            self.identity_validity
+        }
+
+        /// Simply checks if the registration of a given `identity` has expired.
+        #[ink(message)]
+        pub fn identity_is_expired(&self, identity: HashValue) -> bool {
+            match (self.identity_validity, self.attestations.get(identity)) {
+                (Some(validity), Some(attestation)) => {
+                    self.env().block_timestamp() >= attestation.attested_at.saturating_add(validity)
+                }
+                _ => false,
+            }
+        }
+
+        /// Verify that no more than the approved reward could have ever left for a given
+        /// `contribution_id`, recomputing it from the claim receipt, the reservation and the status.
+        ///
+        /// The invariant holds if either:
+        /// 1. the contribution is not claimed and has no receipt, only its reservation can be paid.
+        /// 2. the contribution is claimed, has a receipt and nothing is reserved anymore.
+        #[ink(message)]
+        pub fn verify_no_double_payment(&self, contribution_id: u64) -> Result<PaymentAudit, WorkflowError> {
+            let contribution = self
+                .contributions
+                .get(contribution_id)
+                .ok_or(WorkflowError::UnknownContribution)?;
+            let claimed = contribution.is_reward_claimed;
+            let paid = self
+                .credentials
+                .get(contribution_id)
+                .map(|credential| credential.payload.reward);
+            let reserved = self.get_reservation(contribution_id);
+
+            Ok(PaymentAudit {
+                claimed,
+                paid,
+                reserved,
+                holds: match (claimed, paid) {
+                    (false, None) => true,
+                    (true, Some(_)) => reserved == 0,
+                    _ => false,
+                },
+            })
+        }
+
+        /// Simply returns the claim credential of a given `contribution_id` if some.
+        #[ink(message)]
+        pub fn get_credential(&self, contribution_id: u64) -> Option<Credential> {
+            self.credentials.get(contribution_id)
+        }
+
+        /// Simply returns the platform key if some.
+        #[ink(message)]
+        pub fn get_platform_key(&self) -> Option<AccountId> {
+            self.platform_key
+        }
+
+        /// Simply returns the attestor key if some.
+        #[ink(message)]
+        pub fn get_attestor_key(&self) -> Option<AccountId> {
+            self.attestor_key
+        }
+
+        /// Set the bonus (in basis points of the reward) paid on top of the first claim of an
+        /// identity, i.e. without any claim in its statistics, to onboard new contributors.
+        ///
+        /// The bonus is not reserved upon approval, hence capped to the free balance upon claim.
+        /// It does not stack with the first contribution multiplier, the larger of the two
+        /// applying.
+        #[ink(message)]
+        #[modifiers(only_role(ADMIN))]
+        pub fn set_first_claim_bonus(&mut self, bonus: u32) -> Result<(), WorkflowError> {
+            if bonus > BASIS_POINTS {
+                return Err(WorkflowError::InvalidShare);
+            }
+
+            self.env().emit_event(FirstClaimBonusUpdated {
+                account: self.env().caller(),
+                old_bonus: self.first_claim_bonus,
+                new_bonus: bonus,
+            });
+
+            self.first_claim_bonus = bonus;
+            Ok(())
+        }
+
+        /// Simply returns the bonus (in basis points of the reward) paid on top of the first claim
+        /// of an identity.
+        #[ink(message)]
+        pub fn get_first_claim_bonus(&self) -> u32 {
+            self.first_claim_bonus
+        }
+
+        /// Simply returns the reward multiplier (in basis points) applied to a contributor first claim.
+        #[ink(message)]
+        pub fn get_first_contribution_multiplier(&self) -> u32 {
+            self.first_contribution_multiplier
+        }
+
+        /// Simply returns the number of rewards claimed by the person of a given identity.
+        #[ink(message)]
+        pub fn get_claims_count(&self, identity: HashValue) -> u32 {
+            self.claims_count.get(self.person_of(identity)).unwrap_or_default()
+        }
+
+        /// Simply returns the workflow hash.
+        #[ink(message)]
+        pub fn get_workflow(&self) -> HashValue {
+            self.workflow
+        }
+
+        /// Simply returns the guardian if some.
+        #[ink(message)]
+        pub fn get_guardian(&self) -> Option<AccountId> {
+            self.guardian
+        }
+
+        /// Simply returns the faucet triggered upon identity registration if some.
+        #[ink(message)]
+        pub fn get_faucet(&self) -> Option<AccountId> {
+            self.faucet
+        }
+
+        /// Simply returns the number of approved contributions.
+        #[ink(message)]
+        pub fn get_approved_count(&self) -> u32 {
+            self.approved_count
+        }
+
+        /// Simply returns the reward amount.
+        #[ink(message)]
+        pub fn get_reward(&self) -> Balance {
+            self.reward
+        }
+
+        /// Returns the approved contributions in approval order, from a given `offset` up to
+        /// `limit` entries (capped to `MAX_CHUNK_SIZE`), so that explorers don't have to replay
+        /// all events to render the history.
+        ///
+        /// Removed contributions (e.g. expired or upheld challenges) are skipped, a page may
+        /// then hold fewer entries.
+        #[ink(message)]
+        pub fn get_contributions(&self, offset: u32, limit: u32) -> Vec<Contribution> {
+            Cursor::new(offset)
+                .chunk(limit, self.contribution_index_len)
+                .indices()
+                .filter_map(|index| self.contribution_index.get(index))
+                .filter_map(|contribution_id| self.contributions.get(contribution_id))
+                .collect()
+        }
+
+        /// Returns the contributions approved for a given contributor `account`, from a given
+        /// `offset` up to `limit` entries (capped to `MAX_CHUNK_SIZE`).
+        ///
+        /// Removed contributions (e.g. expired or upheld challenges) are skipped, a page may
+        /// then hold fewer entries.
+        #[ink(message)]
+        pub fn get_contributions_by_contributor(&self, account: AccountId, offset: u32, limit: u32) -> Vec<Contribution> {
+            let len = self.contributor_index_lens.get(account).unwrap_or_default();
+            Cursor::new(offset)
+                .chunk(limit, len)
+                .indices()
+                .filter_map(|index| self.contributor_index.get((account, index)))
+                .filter_map(|contribution_id| self.contributions.get(contribution_id))
+                .collect()
+        }
+
+        /// Returns the approved contributions flattened as `ExportRecord`s, in approval order,
+        /// from a given `offset` up to `limit` entries (capped to `MAX_CHUNK_SIZE`), so that
+        /// spreadsheet-bound tooling doesn't have to decode nested options and enums.
+        ///
+        /// Removed contributions (e.g. expired or upheld challenges) are skipped, a page may
+        /// then hold fewer entries.
+        #[ink(message)]
+        pub fn export_contributions(&self, offset: u32, limit: u32) -> Vec<ExportRecord> {
+            self.get_contributions(offset, limit)
+                .iter()
+                .map(|contribution| self.export_record(contribution))
+                .collect()
+        }
+
+        /// A helper function to flatten a given `contribution` as an `ExportRecord`.
+        fn export_record(&self, contribution: &Contribution) -> ExportRecord {
+            let id = contribution.id;
+            let credential = self.credentials.get(id);
+            let status_code = if contribution.is_reward_claimed {
+                STATUS_CLAIMED
+            } else if self.finalizable_at.contains(id)
+                || self.spot_checks.contains(id)
+                || self.challenges.contains(id)
+                || matches!(self.disputable_until.get(id), Some(until) if self.env().block_timestamp() < until)
+            {
+                STATUS_PENDING
+            } else {
+                STATUS_CLAIMABLE
+            };
+            let amount = match &credential {
+                Some(credential) => credential.payload.reward,
+                None => self.reward_of(contribution).unwrap_or_default(),
+            };
+            let asset_code = if self.basket.iter().all(|share| share.asset == Asset::Native) {
+                ASSET_CODE_NATIVE
+            } else {
+                ASSET_CODE_BASKET
+            };
+
+            (
+                id,
+                status_code,
+                contribution.contributor,
+                amount,
+                asset_code,
+                self.approval_blocks.get(id).unwrap_or_default(),
+                credential.map(|credential| credential.claimed_at).unwrap_or_default(),
+            )
+        }
+
+        /// A helper function to index an approved `contribution_id` of a given `contributor`.
+        fn index_contribution(&mut self, contribution_id: u64, contributor: AccountId) -> Result<(), WorkflowError> {
+            self.contribution_index.insert(self.contribution_index_len, &contribution_id);
+            self.contribution_index_len = self
+                .contribution_index_len
+                .checked_add(1)
+                .ok_or(WorkflowError::Overflow)?;
+            self.sweep_index.insert(self.sweep_index_len, &contribution_id);
+            self.sweep_index_len = self.sweep_index_len.checked_add(1).ok_or(WorkflowError::Overflow)?;
+
+            let len = self.contributor_index_lens.get(contributor).unwrap_or_default();
+            self.contributor_index.insert((contributor, len), &contribution_id);
+            self.contributor_index_lens
+                .insert(contributor, &len.checked_add(1).ok_or(WorkflowError::Overflow)?);
+
+            Ok(())
+        }
+
+        /// Simply returns the aprroved `contribution` of a given `contribution_id` if some.
+        #[ink(message)]
+        pub fn get_contribution(&self, contribution_id: u64) -> Option<Contribution> {
+            self.contributions.get(contribution_id)
+        }
+
+        /// Simply returns the total amount deposited by a given funder.
+        #[ink(message)]
+        pub fn get_deposit(&self, funder: AccountId) -> Balance {
+            self.deposits.get(funder).unwrap_or_default()
+        }
+
+        /// Simply returns the total amount deposited by all the funders.
+        #[ink(message)]
+        pub fn get_total_funded(&self) -> Balance {
+            self.total_funded
+        }
+
+        /// Returns the balance available for new approvals, i.e. not reserved for the
+        /// approved rewards, the pending payouts and the challenge bonds, the parked funds included.
+        #[ink(message)]
+        pub fn get_available_balance(&self) -> Result<Balance, WorkflowError> {
+            self.free_balance()
+        }
+
+        /// Returns the native rewards approved but not claimed yet (i.e. reserved), the pending
+        /// native payouts and the bonds of the pending challenges.
+        #[ink(message)]
+        pub fn get_outstanding_obligations(&self) -> Result<Balance, WorkflowError> {
+            self.outstanding_obligations()
+        }
+
+        /// Returns the balance not reserved for the outstanding obligations nor earmarked, the
+        /// parked funds included (i.e. the same as `get_available_balance`).
+        #[ink(message)]
+        pub fn get_free_balance(&self) -> Result<Balance, WorkflowError> {
+            self.free_balance()
+        }
+
+        /// Returns `true` if the balance, the parked funds included, covers the outstanding
+        /// obligations and the earmarked funds, so that contributors can verify before starting
+        /// work that their reward is actually covered.
+        #[ink(message)]
+        pub fn is_solvent(&self) -> Result<bool, WorkflowError> {
+            let liabilities = self
+                .outstanding_obligations()?
+                .checked_add(self.earmarked)
+                .ok_or(WorkflowError::Overflow)?;
+            Ok(self.env().balance().saturating_add(self.parked) >= liabilities)
+        }
+
+        /// Simply returns the funds received without context.
+        #[ink(message)]
+        pub fn get_unattributed_funds(&self) -> Balance {
+            self.unattributed_funds
+        }
+
+        /// Simply returns whether funds received without context are rejected.
+        #[ink(message)]
+        pub fn is_strict_funding(&self) -> bool {
+            self.strict_funding
+        }
+
+        /// Returns the accounting statement of a given `funder` for a given `epoch`.
+        ///
+        /// The rewards paid out during the epoch are attributed pro-rata to the funder
+        /// share of the total deposits.
+        #[ink(message)]
+        pub fn statement_of(&self, funder: AccountId, epoch: u32) -> Statement {
+            let statement = self.statements.get((funder, epoch)).unwrap_or_default();
+            let allocated = checked_mul_div(
+                self.epoch_allocations.get(epoch).unwrap_or_default(),
+                self.get_deposit(funder),
+                self.total_funded,
+            )
+            .unwrap_or_default();
+            Statement { allocated, ..statement }
+        }
+
+        /// Simply returns the current accounting epoch.
+        #[ink(message)]
+        pub fn get_current_epoch(&self) -> u32 {
+            self.current_epoch()
+        }
+
+        /// Simply returns the refund state if the contract is terminated.
+        #[ink(message)]
+        pub fn get_refund(&self) -> Option<Refund> {
+            self.refund
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(
+                interface_id,
+                interface_ids::INTROSPECTION | interface_ids::WORKFLOW_V1
+            )
+        }
+
+        /// Simply returns the `AccountId` of a given identity, resolved by the shared identity
+        /// registry if set.
+        #[ink(message)]
+        pub fn get_account(&self, identity: HashValue) -> Option<AccountId> {
+            let Some(identity_registry) = self.identity_registry else {
+                return self.identities.get(identity);
+            };
+
+            ink::env::call::build_call::<Environment>()
+                .call(identity_registry)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("Identity::account_of"),
+                    ))
+                    .push_arg(identity),
+                )
+                .returns::<Option<AccountId>>()
+                .try_invoke()
+                .ok()
+                .and_then(Result::ok)
+                .flatten()
+        }
+
+        /// A helper function to ensure a contributor can claim the reward.
+        pub fn ensure_can_claim(
+            &self,
+            contribution_id: u64,
+        ) -> Result<Contribution, WorkflowError> {
+            self.ensure_can_claim_for(Self::env().caller(), contribution_id)
+        }
+
+        /// A helper function to ensure a given `contributor` can claim the reward.
+        fn ensure_can_claim_for(
+            &self,
+            contributor: AccountId,
+            contribution_id: u64,
+        ) -> Result<Contribution, WorkflowError> {
+            // Check if the contribution is approved, the contributor being resolved again
+            let mut contribution = self.get_approved_contribution(contribution_id)?;
+            contribution.contributor = self.contributor_of(&contribution)?;
+
+            // Verify the caller is the contributor and the reward has not been claimed yet
+            contribution.ensure_claimable_by(&contributor)?;
+
+            // Check if the approval is finalized and spot checked
+            if self.finalizable_at.contains(contribution_id) {
+                return Err(WorkflowError::ApprovalNotFinalized);
+            }
+            if self.spot_checks.contains(contribution_id) {
+                return Err(WorkflowError::SpotCheckPending);
+            }
+
+            // Check if the approval is not challenged nor still challengeable
+            if self.challenges.contains(contribution_id) {
+                return Err(WorkflowError::ContributionChallenged);
+            }
+            if let Some(until) = self.disputable_until.get(contribution_id) {
+                if self.env().block_timestamp() < until {
+                    return Err(WorkflowError::DisputeWindowOpen);
+                }
+            }
+
+            // Check if the contribution has not expired
+            if self.is_past_claim_deadline(&contribution) {
+                return Err(WorkflowError::ContributionExpired);
+            }
+
+            // Check if the contributor is below the claims cap
+            if self.claims_cap > 0 && self.get_claims_count(contribution.identity) >= self.claims_cap {
+                return Err(WorkflowError::ClaimsCapReached);
+            }
+
+            // Check if the contributor is out of the claim cooldown
+            if let Some(last_claim) = self.get_last_claim(contribution.identity) {
+                if self.env().block_timestamp() < last_claim.saturating_add(self.claim_cooldown)
+                    && !self.cooldown_exemptions.contains(contribution_id)
+                {
+                    return Err(WorkflowError::ClaimCooldown);
+                }
+            }
+
+            Ok(contribution)
+        }
+
+        /// A helper function to get an approved contribution.
+        fn get_approved_contribution(&self, contribution_id: u64) -> Result<Contribution, WorkflowError> {
+            match self.contributions.get(contribution_id) {
+                Some(contribution) => Ok(contribution),
+                None if self.approved_count == 0 => Err(WorkflowError::NoContributionApprovedYet),
+                None => Err(WorkflowError::UnknownContribution),
+            }
+        }
+
+        /// A helper function to resolve the current account of the contributor of a `contribution`:
+        /// the account bound to its identity (e.g. after a rotation) by the shared identity
+        /// registry if set, locally otherwise, the approved account if unbound.
+        fn contributor_of(&self, contribution: &Contribution) -> Result<AccountId, WorkflowError> {
+            if self.identity_registry.is_some() {
+                return self
+                    .get_account(contribution.identity)
+                    .ok_or(WorkflowError::UnknownContributor);
+            }
+            Ok(self
+                .identities
+                .get(contribution.identity)
+                .unwrap_or(contribution.contributor))
+        }
+
+        /// A helper function to check whether the reward of a given contribution cannot be
+        /// claimed anymore, either past its expiry or past its claim deadline.
+        fn is_past_claim_deadline(&self, contribution: &Contribution) -> bool {
+            contribution.ensure_not_expired(self.env().block_timestamp()).is_err()
+                || matches!(
+                    self.claim_deadlines.get(contribution.id),
+                    Some(deadline) if self.env().block_number() > deadline
+                )
+        }
+
+        /// A helper function to compute the reward paid for a given contribution, the one
+        /// approved (and reserved) if some, so that later reward changes don't apply to it.
+        fn reward_of(&self, contribution: &Contribution) -> Result<Balance, WorkflowError> {
+            if let Some((_, reward)) = self.approved_rewards.get(contribution.id) {
+                return Ok(reward);
+            }
+            if let Some(reward) = self.trial_rewards.get(contribution.id) {
+                return Ok(reward);
+            }
+            self.multiplied_reward(contribution.identity, self.base_reward_of(contribution))
+        }
+
+        /// A helper function to apply the first contribution multiplier to the `reward` of a
+        /// given contributor `identity`.
+        fn multiplied_reward(&self, identity: HashValue, reward: Balance) -> Result<Balance, WorkflowError> {
+            if !self.is_first_contribution(identity) {
+                return Ok(reward);
+            }
+
+            checked_mul_div(
+                reward,
+                Balance::from(self.first_contribution_multiplier),
+                Balance::from(BASIS_POINTS),
+            )
+            .ok_or(WorkflowError::Overflow)
+        }
+
+        /// A helper function to compute the reward of a given contribution before the first
+        /// contribution multiplier.
+        fn base_reward_of(&self, contribution: &Contribution) -> Balance {
+            if let Some((base, _)) = self.approved_rewards.get(contribution.id) {
+                return base;
+            }
+            self.listed_reward_of(contribution.id).unwrap_or(self.reward)
+        }
+
+        /// A helper function to read the reward specific to a given `contribution_id` (e.g. a
+        /// trial, formula, voted, tiered or escalated reward), if some.
+        fn listed_reward_of(&self, contribution_id: u64) -> Option<Balance> {
+            self.trial_rewards
+                .get(contribution_id)
+                .or(self.formula_rewards.get(contribution_id))
+                .or(self.voted_rewards.get(contribution_id))
+                .or(self.tiered_rewards.get(contribution_id))
+                .or(self.escalated_rewards.get(contribution_id))
+        }
+
+        /// A helper function to compute the first claim bonus of a given contribution, zero if
+        /// its person (i.e. any of its linked identities) already claimed.
+        ///
+        /// The bonus does not stack with the first contribution multiplier: it only tops up the
+        /// part of the reward the multiplier already added, the larger of the two applying.
+        fn first_claim_bonus_of(&self, contribution: &Contribution) -> Result<Balance, WorkflowError> {
+            if self.get_claims_count(contribution.identity) > 0 {
+                return Ok(0);
+            }
+
+            let base = self.base_reward_of(contribution);
+            let multiplied = self.reward_of(contribution)?.saturating_sub(base);
+            let bonus = checked_mul_div(base, Balance::from(self.first_claim_bonus), Balance::from(BASIS_POINTS))
+                .ok_or(WorkflowError::Overflow)?;
+            Ok(bonus.saturating_sub(multiplied))
+        }
+
+        /// A helper function to compute the free balance available to the native payout of a
+        /// `reward` beyond the reservation of a given `contribution_id`.
+        fn available_beyond_reservation(&self, contribution_id: u64, reward: Balance) -> Result<Balance, WorkflowError> {
+            let beyond = self
+                .native_payout(reward)?
+                .saturating_sub(self.get_reservation(contribution_id));
+            Ok(self.free_balance()?.saturating_sub(beyond))
+        }
+
+        /// A helper function to check whether an `identity` has never been rewarded.
+        ///
+        /// If a reputation source is set, identities with reputation points are not
+        /// considered as first-time contributors, falling back on the local claims
+        /// count if the reputation source cannot be read.
+        fn is_first_contribution(&self, identity: HashValue) -> bool {
+            if self.get_claims_count(identity) > 0 {
+                return false;
+            }
+            !matches!(self.reputation_of(identity), Some(points) if points > 0)
+        }
+
+        /// A helper function to read the reputation points of an `identity` from the
+        /// reputation source, if set and readable.
+        fn reputation_of(&self, identity: HashValue) -> Option<u64> {
+            let imported = self.imported_reputations.get(identity);
+            let Some(reputation) = self.reputation else {
+                return imported;
+            };
+
+            let points = ink::env::call::build_call::<Environment>()
+                .call(reputation)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("Reputation::reputation_of"),
+                    ))
+                    .push_arg(identity),
+                )
+                .returns::<u64>()
+                .try_invoke()
+                .ok()
+                .and_then(Result::ok);
+            points.max(imported)
+        }
+
+        /// A helper function to validate a parameter change before queuing it.
+        fn validate_change(&self, change: &ParameterChange) -> Result<(), WorkflowError> {
+            match change {
+                ParameterChange::FirstContributionMultiplier(multiplier) if *multiplier < BASIS_POINTS => {
+                    Err(WorkflowError::InvalidMultiplier)
+                }
+                ParameterChange::Verifier(verifier) if verifier.valid_from >= verifier.valid_to => {
+                    Err(WorkflowError::InvalidVerifierKey)
+                }
+                ParameterChange::ChallengeConfig { challenger_share, .. } if *challenger_share > BASIS_POINTS => {
+                    Err(WorkflowError::InvalidShare)
+                }
+                ParameterChange::Insurance(Some(insurance)) if insurance.premium_rate > BASIS_POINTS => {
+                    Err(WorkflowError::InvalidShare)
+                }
+                ParameterChange::Boost(Some(boost)) if boost.multiplier < BASIS_POINTS => {
+                    Err(WorkflowError::InvalidMultiplier)
+                }
+                ParameterChange::Basket(basket) => self.validate_basket(basket),
+                _ => Ok(()),
+            }
+        }
+
+        /// A helper function to compute the insurance premium of a `reward`, zero if uninsured.
+        fn premium_of(&self, reward: Balance) -> Result<Balance, WorkflowError> {
+            let Some(insurance) = self.insurance else {
+                return Ok(0);
+            };
+            checked_mul_div(reward, Balance::from(insurance.premium_rate), Balance::from(BASIS_POINTS))
+                .ok_or(WorkflowError::Overflow)
+        }
+
+        /// A helper function to cover the `reward` of an approved `contribution_id` owed to a
+        /// `contributor` by the insurance if set, paying the `premium`.
+        fn insure(
+            &self,
+            contribution_id: u64,
+            contributor: AccountId,
+            reward: Balance,
+            premium: Balance,
+        ) -> Result<(), WorkflowError> {
+            let Some(insurance) = self.insurance else {
+                return Ok(());
+            };
+
+            let covered = ink::env::call::build_call::<Environment>()
+                .call(insurance.pool)
+                .transferred_value(premium)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("Insurance::cover"),
+                    ))
+                    .push_arg(contribution_id)
+                    .push_arg(contributor)
+                    .push_arg(reward),
+                )
+                .returns::<Result<(), InsuranceError>>()
+                .try_invoke();
+            if !matches!(covered, Ok(Ok(Ok(())))) {
+                return Err(WorkflowError::InsuranceFailed);
+            }
+            Ok(())
+        }
+
+        /// A helper function to drip native funds from the faucet to a newly registered `account`.
+        ///
+        /// Any faucet failure (e.g. rate limiting or empty faucet) is ignored, so that
+        /// registration never depends on the faucet.
+        fn drip_from_faucet(&self, account: AccountId) {
+            let Some(faucet) = self.faucet else {
+                return;
+            };
+
+            let _ = ink::env::call::build_call::<Environment>()
+                .call(faucet)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("Faucet::drip"),
+                    ))
+                    .push_arg(account),
+                )
+                .returns::<Result<(), FaucetError>>()
+                .try_invoke();
+        }
+
+        /// A helper function to ensure the contract has not been terminated.
+        fn ensure_not_terminated(&self) -> Result<(), WorkflowError> {
+            if self.refund.is_some() {
+                return Err(WorkflowError::ContractTerminated);
+            }
+            Ok(())
+        }
+
+        /// A helper function to record a deposit from a given funder.
+        fn record_deposit(&mut self, funder: AccountId, amount: Balance) -> Result<(), WorkflowError> {
+            if amount == 0 {
+                return Ok(());
+            }
+
+            let deposit = self.deposits.get(funder).unwrap_or_default();
+            let new_deposit = deposit.checked_add(amount).ok_or(WorkflowError::Overflow)?;
+            let total_funded = self
+                .total_funded
+                .checked_add(amount)
+                .ok_or(WorkflowError::Overflow)?;
+
+            let key = (funder, self.current_epoch());
+            let mut statement = self.statements.get(key).unwrap_or_default();
+            statement.deposited = statement
+                .deposited
+                .checked_add(amount)
+                .ok_or(WorkflowError::Overflow)?;
+
+            if !self.indexed_funders.contains(funder) {
+                self.funders.insert(self.funders_count, &funder);
+                self.funders_count = self.funders_count.checked_add(1).ok_or(WorkflowError::Overflow)?;
+                self.indexed_funders.insert(funder, &());
+            }
+            self.deposits.insert(funder, &new_deposit);
+            self.total_funded = total_funded;
+            self.statements.insert(key, &statement);
+
+            self.env().emit_event(FundsDeposited {
+                funder,
+                amount,
+                total_funded,
+            });
+
+            Ok(())
+        }
+
+        /// A helper function to compute the outflow tracking after a reward `amount` is paid,
+        /// along with the estimated outflow over the sliding window.
+        ///
+        /// The previous window outflow is weighted by its overlap with the sliding window.
+        fn outflow_after(&self, amount: Balance) -> Result<(Outflow, Balance), WorkflowError> {
+            let Some(breaker) = self.circuit_breaker else {
+                return Ok((self.outflow, 0));
+            };
+
+            let now = self.env().block_timestamp();
+            let mut outflow = self.outflow;
+            let elapsed_windows = now.saturating_sub(outflow.window_start) / breaker.window;
+            if elapsed_windows > 0 {
+                outflow.previous = if elapsed_windows == 1 { outflow.current } else { 0 };
+                outflow.current = 0;
+                outflow.window_start += elapsed_windows * breaker.window;
+            }
+            outflow.current = outflow
+                .current
+                .checked_add(amount)
+                .ok_or(WorkflowError::Overflow)?;
+
+            let overlap = breaker.window - now.saturating_sub(outflow.window_start).min(breaker.window);
+            let estimated_outflow = checked_mul_div(
+                outflow.previous,
+                Balance::from(overlap),
+                Balance::from(breaker.window),
+            )
+            .and_then(|previous| previous.checked_add(outflow.current))
+            .ok_or(WorkflowError::Overflow)?;
+
+            Ok((outflow, estimated_outflow))
+        }
+
+        /// A helper function to read the contract deposit in the yield adapter, including the earned yield.
+        fn yield_balance(&self) -> Result<Balance, WorkflowError> {
+            let yield_adapter = self.yield_adapter.ok_or(WorkflowError::YieldAdapterNotSet)?;
+
+            ink::env::call::build_call::<Environment>()
+                .call(yield_adapter)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("YieldAdapter::balance_of"),
+                    ))
+                    .push_arg(self.env().account_id()),
+                )
+                .returns::<Balance>()
+                .try_invoke()
+                .ok()
+                .and_then(Result::ok)
+                .ok_or(WorkflowError::YieldAdapterFailed)
+        }
+
+        /// A helper function to withdraw `amount` from the yield adapter.
+        fn yield_withdraw(&self, amount: Balance) -> Result<(), WorkflowError> {
+            let yield_adapter = self.yield_adapter.ok_or(WorkflowError::YieldAdapterNotSet)?;
+
+            let withdrawn = ink::env::call::build_call::<Environment>()
+                .call(yield_adapter)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("YieldAdapter::withdraw"),
+                    ))
+                    .push_arg(amount),
+                )
+                .returns::<Result<(), YieldAdapterError>>()
+                .try_invoke();
+            if !matches!(withdrawn, Ok(Ok(Ok(())))) {
+                return Err(WorkflowError::YieldAdapterFailed);
+            }
+            Ok(())
+        }
+
+        /// A helper function to split an `amount` across the `splits` recipients, or to pay it
+        /// to the `contributor` if no splits.
+        ///
+        /// The rounding remainder is paid according to the dust policy.
+        fn split_payout(
+            &self,
+            contributor: AccountId,
+            amount: Balance,
+            splits: &[(AccountId, u8)],
+        ) -> Result<Vec<(AccountId, Balance)>, WorkflowError> {
+            if splits.is_empty() {
+                return Ok(ink::prelude::vec![(contributor, amount)]);
+            }
+
+            let mut recipients = splits.iter().map(|(recipient, _)| *recipient).collect::<Vec<_>>();
+            let mut weights = splits
+                .iter()
+                .map(|(_, percent)| Balance::from(*percent))
+                .collect::<Vec<_>>();
+            let remainder_index = match self.dust_policy {
+                DustPolicy::Recipient => 0,
+                DustPolicy::Treasury(treasury) => {
+                    recipients.push(treasury);
+                    weights.push(0);
+                    splits.len()
+                }
+            };
+
+            let shares = split_pro_rata(amount, &weights, remainder_index).ok_or(WorkflowError::Overflow)?;
+            Ok(recipients.into_iter().zip(shares).collect())
+        }
+
+        /// A helper function to compute the days late and the compensation accrued by the
+        /// reported underfunded claim of a given `contribution_id`.
+        fn late_compensation_of(&self, contribution_id: u64, reward: Balance) -> Result<(u64, Balance), WorkflowError> {
+            let Some(since) = self.late_since.get(contribution_id) else {
+                return Ok((0, 0));
+            };
+
+            let days_late = self.env().block_timestamp().saturating_sub(since) / DAY;
+            let rate = u64::from(self.late_compensation_rate)
+                .saturating_mul(days_late)
+                .min(u64::from(self.late_compensation_cap));
+            let compensation = checked_mul_div(reward, Balance::from(rate), Balance::from(BASIS_POINTS))
+                .ok_or(WorkflowError::Overflow)?;
+            Ok((days_late, compensation))
+        }
+
+        /// A helper function to compute the current accounting epoch.
+        fn current_epoch(&self) -> u32 {
+            (self.env().block_timestamp() / EPOCH_DURATION) as u32
+        }
+
+        /// Simply returns the claim statistics of a given `identity` (i.e. the claims count, the
+        /// total rewarded and the first and last claim blocks), empty if it never claimed.
+        #[ink(message)]
+        pub fn get_stats(&self, identity: HashValue) -> ContributorStats {
+            self.stats.get(identity).unwrap_or_default()
+        }
+
+        /// A helper function to extend the claim streak of an `identity` with a claim in a given `epoch`.
+        fn extend_streak(&mut self, identity: HashValue, epoch: u32) {
+            let streak = self.streaks.get(identity).unwrap_or_default();
+            self.streaks.insert(identity, &streak.extend(epoch));
+        }
+
+        /// A helper function to compute the native rewards approved but not claimed yet,
+        /// along with the bonds of the pending challenges.
+        fn outstanding_obligations(&self) -> Result<Balance, WorkflowError> {
+            self.get_escrow(Asset::Native)?
+                .checked_add(self.challenge_bonds)
+                .ok_or(WorkflowError::Overflow)
+        }
+
+        /// A helper function to compute the balance not reserved for the native obligations
+        /// nor earmarked, the parked funds included.
+        fn free_balance(&self) -> Result<Balance, WorkflowError> {
+            Ok(self
+                .env()
+                .balance()
+                .saturating_add(self.parked)
+                .saturating_sub(self.outstanding_obligations()?)
+                .saturating_sub(self.earmarked))
+        }
+
+        /// A helper function to compute the earmarked amount available to a given `contribution_id`.
+        fn earmarked_for(&self, contribution_id: u64) -> Balance {
+            self.get_earmarks_of(contribution_id)
+                .into_iter()
+                .filter_map(|index| self.earmarks.get(index))
+                .fold(0, |total: Balance, earmark| total.saturating_add(earmark.remaining))
+        }
+
+        /// A helper function to allocate an `amount` of the deposits earmarked for a given
+        /// `contribution_id`, in the earmarking order.
+        fn allocate_earmarks(&mut self, contribution_id: u64, amount: Balance) {
+            let mut remaining = amount;
+            let mut allocations = Vec::new();
+            for index in self.get_earmarks_of(contribution_id) {
+                if remaining == 0 {
+                    break;
+                }
+                let Some(earmark) = self.earmarks.get(index) else {
+                    continue;
+                };
+                let allocated = remaining.min(earmark.remaining);
+                remaining -= allocated;
+                self.earmarks.insert(
+                    index,
+                    &Earmark {
+                        remaining: earmark.remaining - allocated,
+                        ..earmark
+                    },
+                );
+                if allocated > 0 {
+                    allocations.push((index, allocated));
+                }
+            }
+            self.earmarked = self.earmarked.saturating_sub(amount - remaining);
+            if !allocations.is_empty() {
+                self.earmark_allocations.insert(contribution_id, &allocations);
+            }
+        }
+
+        /// A helper function to give the earmarked amounts allocated to a given unpaid
+        /// `contribution_id` back to their earmarks, its issue being closed so that the
+        /// funders can withdraw them.
+        fn restore_earmarks(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
+            for (index, allocated) in self.earmark_allocations.take(contribution_id).unwrap_or_default() {
+                let Some(earmark) = self.earmarks.get(index) else {
+                    continue;
+                };
+                let remaining = earmark.remaining.checked_add(allocated).ok_or(WorkflowError::Overflow)?;
+                self.earmarks.insert(index, &Earmark { remaining, ..earmark });
+                self.earmarked = self.earmarked.checked_add(allocated).ok_or(WorkflowError::Overflow)?;
+            }
+            self.closed_issues.insert(contribution_id, &());
+
+            Ok(())
+        }
+
+        /// A helper function to release the reward reserved (and escrowed) for a given
+        /// `contribution_id`.
+        fn release_reservation(&mut self, contribution_id: u64) {
+            let reservation = self.reservations.take(contribution_id).unwrap_or(0);
+            self.reserved = self.reserved.saturating_sub(reservation);
+            self.earmark_allocations.remove(contribution_id);
+            for (asset, amount) in self.escrow_allocations.take(contribution_id).unwrap_or_default() {
+                let escrow = self.escrows.get(asset).unwrap_or(0).saturating_sub(amount);
+                self.escrows.insert(asset, &escrow);
+            }
+        }
+
+        /// A helper function to compute the PSP22 parts of a `reward`, none without basket.
+        fn token_payouts(&self, reward: Balance) -> Result<Vec<(Asset, Balance)>, WorkflowError> {
+            if self.basket.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            Ok(self
+                .basket_payouts(reward)?
+                .into_iter()
+                .filter(|(asset, amount)| *asset != Asset::Native && *amount > 0)
+                .collect())
+        }
+
+        /// A helper function to compute the native part of a `reward`.
+        fn native_payout(&self, reward: Balance) -> Result<Balance, WorkflowError> {
+            if self.basket.is_empty() {
+                return Ok(reward);
+            }
+
+            Ok(self
+                .basket_payouts(reward)?
+                .iter()
+                .filter(|(asset, _)| *asset == Asset::Native)
+                .map(|(_, amount)| *amount)
+                .sum())
+        }
+
+        /// A helper function to split a `reward` according to the payout basket.
+        ///
+        /// The rounding remainder is added to the last asset payout.
+        fn basket_payouts(&self, reward: Balance) -> Result<Vec<(Asset, Balance)>, WorkflowError> {
+            let weights = self
+                .basket
+                .iter()
+                .map(|share| Balance::from(share.share))
+                .collect::<Vec<_>>();
+            let amounts =
+                split_pro_rata(reward, &weights, weights.len().saturating_sub(1)).ok_or(WorkflowError::Overflow)?;
+            Ok(self.basket.iter().map(|share| share.asset).zip(amounts).collect())
+        }
+
+        /// A helper function to split a `reward` between the native currency and the PSP22 token
+        /// of a two-asset basket along a given `native_share`, bounded by the escrow of each asset
+        /// for a given `contribution_id`.
+        fn ratio_payouts(
+            &self,
+            contribution_id: u64,
+            reward: Balance,
+            native_share: u32,
+        ) -> Result<Vec<(Asset, Balance)>, WorkflowError> {
+            if native_share > BASIS_POINTS {
+                return Err(WorkflowError::InvalidPayoutRatio);
+            }
+            if self.basket.len() != 2 {
+                return Err(WorkflowError::InvalidBasket);
+            }
+            let payouts = self.basket_payouts(reward)?;
+            let (&[(Asset::Native, native_part), (token, _)] | &[(token, _), (Asset::Native, native_part)]) =
+                payouts.as_slice()
+            else {
+                return Err(WorkflowError::InvalidBasket);
+            };
+
+            let native = checked_mul_div(reward, Balance::from(native_share), Balance::from(BASIS_POINTS))
+                .ok_or(WorkflowError::Overflow)?;
+            let available = self
+                .get_reservation(contribution_id)
+                .checked_add(self.free_balance()?)
+                .ok_or(WorkflowError::Overflow)?;
+            if native < native_part || native > available {
+                return Err(WorkflowError::InsufficientFunds);
+            }
+
+            Ok(ink::prelude::vec![(Asset::Native, native), (token, reward - native)])
+        }
+
+        /// A helper function to pay an `amount` of a given `asset`.
+        ///
+        /// PSP22 payouts into contract accounts are notified to the recipient `before_received`
+        /// hook once transferred (see `notify_receiver`).
+        fn pay(&self, asset: Asset, to: AccountId, amount: Balance) -> Result<(), WorkflowError> {
+            if amount == 0 {
+                return Ok(());
+            }
+
+            let paid = match asset {
+                Asset::Native => self.env().transfer(to, amount).is_ok(),
+                Asset::Psp22(token) => matches!(
+                    ink::env::call::build_call::<Environment>()
+                        .call(token)
+                        .exec_input(
+                            ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                                ink::selector_bytes!("PSP22::transfer"),
+                            ))
+                            .push_arg(to)
+                            .push_arg(amount)
+                            .push_arg(Vec::<u8>::new()),
+                        )
+                        .returns::<Result<(), PSP22Error>>()
+                        .try_invoke(),
+                    Ok(Ok(Ok(())))
+                ),
+            };
+            if !paid {
+                return Err(WorkflowError::PaymentFailed);
+            }
+            if let Asset::Psp22(token) = asset {
+                self.notify_receiver(token, to, amount);
+            }
+            Ok(())
+        }
+
+        /// A helper function to call the PSP22 receiver hook of a contract recipient `to`,
+        /// once an `amount` of a given `token` is transferred to it.
+        ///
+        /// Unlike the safe transfer check of the token, the hook is called by this contract
+        /// (passed as both the operator and the sender) after the transfer, so that the
+        /// recipient can account for the payout: its result is ignored, the tokens being
+        /// already received. The `token` is passed as the transfer data.
+        fn notify_receiver(&self, token: AccountId, to: AccountId, amount: Balance) {
+            if !self.env().is_contract(&to) {
+                return;
+            }
+
+            let contract = self.env().account_id();
+            let _ = ink::env::call::build_call::<Environment>()
+                .call(to)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("PSP22Receiver::before_received"),
+                    ))
+                    .push_arg(contract)
+                    .push_arg(contract)
+                    .push_arg(amount)
+                    .push_arg(scale::Encode::encode(&token)),
+                )
+                .returns::<Result<(), PSP22ReceiverError>>()
+                .try_invoke();
+        }
+
+        /// A helper function to pay a reward `amount` of a given `asset`, routing the native
+        /// rewards into the vesting contract if set.
+        fn pay_reward(&self, asset: Asset, to: AccountId, amount: Balance) -> Result<(), WorkflowError> {
+            let Some(vesting) = self.vesting.filter(|_| asset == Asset::Native && amount > 0) else {
+                return self.pay(asset, to, amount);
+            };
+
+            let vested = ink::env::call::build_call::<Environment>()
+                .call(vesting)
+                .transferred_value(amount)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("Vesting::vest"),
+                    ))
+                    .push_arg(to),
+                )
+                .returns::<Result<(), VestingError>>()
+                .try_invoke();
+            if !matches!(vested, Ok(Ok(Ok(())))) {
+                return Err(WorkflowError::PaymentFailed);
+            }
+            Ok(())
+        }
+
+        /// A helper function to notify the lifecycle hook, if set, of a `transition` of a given
+        /// `contribution_id`.
+        ///
+        /// The notification is best effort: a failing hook never blocks the workflow.
+        fn notify_lifecycle(&self, contribution_id: u64, transition: Transition) {
+            let Some(lifecycle_hook) = self.lifecycle_hook else {
+                return;
+            };
+
+            let _ = ink::env::call::build_call::<Environment>()
+                .call(lifecycle_hook)
+                .exec_input(
+                    ink::env::call::ExecutionInput::new(ink::env::call::Selector::new(
+                        ink::selector_bytes!("LifecycleHook::notify"),
+                    ))
+                    .push_arg(contribution_id)
+                    .push_arg(transition),
+                )
+                .returns::<Result<(), LifecycleHookError>>()
+                .try_invoke();
+        }
+
+        /// A helper function to record a failed payout as pending for the `contributor`.
+        fn defer_payout(&mut self, contributor: AccountId, asset: Asset, amount: Balance) -> Result<(), WorkflowError> {
+            let pending = self
+                .get_pending_payout(contributor, asset)
+                .checked_add(amount)
+                .ok_or(WorkflowError::Overflow)?;
+            let pending_total = self
+                .pending_totals
+                .get(asset)
+                .unwrap_or(0)
+                .checked_add(amount)
+                .ok_or(WorkflowError::Overflow)?;
+            self.pending_payouts.insert((contributor, asset), &pending);
+            self.pending_totals.insert(asset, &pending_total);
+
+            self.env().emit_event(PayoutDeferred {
+                contributor,
+                asset,
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// A helper function to record the native payout of a given `contribution_id` credited to
+        /// its contributor instead of being paid, the evidence of an insured failure.
+        fn record_failed_payout(&mut self, contribution_id: u64, amount: Balance) -> Result<(), WorkflowError> {
+            let failed = self
+                .get_failed_payout(contribution_id)
+                .checked_add(amount)
+                .ok_or(WorkflowError::Overflow)?;
+            self.failed_payouts.insert(contribution_id, &failed);
+            Ok(())
+        }
+
+        /// A helper function to credit a native payout which cannot be transferred to the
+        /// internal ledger, withdrawable later with `withdraw_pending_to`.
+        fn credit_payout(&mut self, contributor: AccountId, amount: Balance) -> Result<(), WorkflowError> {
+            self.defer_payout(contributor, Asset::Native, amount)?;
+
+            self.env().emit_event(CreditedInsteadOfPaid { contributor, amount });
+
+            Ok(())
+        }
+
+        /// A helper function to probe whether a given `account` is a multisig contract
+        /// with owners, any probe failure counting as not a multisig.
+        fn is_multisig(&self, account: AccountId) -> bool {
+            if !self.env().is_contract(&account) {
+                return false;
+            }
+
+            let probe = |selector: [u8; 4]| {
+                ink::env::call::build_call::<Environment>()
+                    .call(account)
+                    .exec_input(ink::env::call::ExecutionInput::new(
+                        ink::env::call::Selector::new(selector),
+                    ))
+            };
+            let is_multisig = probe(ink::selector_bytes!("Multisig::is_multisig"))
+                .returns::<bool>()
+                .try_invoke();
+            if !matches!(is_multisig, Ok(Ok(true))) {
+                return false;
+            }
+            let owners = probe(ink::selector_bytes!("Multisig::owners"))
+                .returns::<Vec<AccountId>>()
+                .try_invoke();
+            matches!(owners, Ok(Ok(owners)) if !owners.is_empty())
+        }
+
+        /// A helper function to hand the ownership and the roles of the owner over to a
+        /// given `account`.
+        fn hand_over_ownership(&mut self, account: AccountId) {
+            if let Some(owner) = Ownable::owner(self) {
+                for role in ROLES {
+                    if AccessControl::has_role(self, role, Some(owner)) {
+                        access_control::Internal::_do_revoke_role(self, role, Some(owner));
+                    }
+                }
+            }
+            ownable::Internal::_init_with_owner(self, account);
+            self.grant_roles(account);
+        }
+
+        /// A helper function to grant all the roles to a given `account`.
+        fn grant_roles(&mut self, account: AccountId) {
+            for role in ROLES {
+                access_control::Internal::_setup_role(self, role, Some(account));
+            }
+        }
+
+        /// A helper function to detect whether an aspiring contributor identity has been registered in the storage.
+        pub fn identity_is_known(&self, identity: HashValue) -> bool {
+            self.get_account(identity).is_some()
+        }
+
+        /// A helper function to build the credential payload of a `contribution`.
+        fn credential_payload(&self, contribution: &Contribution, reward: Balance) -> CredentialPayload {
+            CredentialPayload {
+                contract: self.env().account_id(),
+                contribution_id: contribution.id,
+                contributor: contribution.contributor,
+                reward,
+            }
+        }
+
+        /// A helper function to check a signature of a credential payload by the platform key.
+        fn is_platform_signature(&self, payload: &CredentialPayload, signature: &[u8]) -> bool {
+            let Ok(signature) = SignatureValue::try_from(signature) else {
+                return false;
+            };
+            self.platform_key.is_some()
+                && self.recover_signer(&Self::hash_payload(payload), &signature).ok() == self.platform_key
+        }
+
+        /// A helper function to recover the ECDSA account that signed a given `message_hash`.
+        fn recover_signer(
+            &self,
+            message_hash: &HashValue,
+            signature: &SignatureValue,
+        ) -> Result<AccountId, WorkflowError> {
+            let public_key = self
+                .env()
+                .ecdsa_recover(signature, message_hash)
+                .map_err(|_| WorkflowError::InvalidSignature)?;
+            let mut account = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+            Ok(AccountId::from(account))
+        }
+
+        /// A helper function to hash a SCALE encoded payload to be signed.
+        pub fn hash_payload<T: scale::Encode>(payload: &T) -> HashValue {
+            let mut hash_value = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_encoded::<Blake2x256, _>(payload, &mut hash_value);
+            hash_value
+        }
+
+        /// A helper function to derive the storage key of a platform `identity`: the `id_hash`
+        /// for GitHub (i.e. the identities registered before multi-platform support), the Blake2x256
+        /// hash of the SCALE encoded identity otherwise, so that identities never collide across
+        /// platforms.
+        pub fn identity_key(identity: &Identity) -> HashValue {
+            match identity.platform {
+                Platform::GitHub => identity.id_hash,
+                _ => Self::hash_payload(identity),
+            }
+        }
+
+        /// A helper function to hash bytes (e.g. identities or workflow file sha).
+        pub fn hash(input: &[u8]) -> HashValue {
+            let mut hash_value = <Sha2x256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Sha2x256>(input, &mut hash_value);
+            hash_value
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB -> contributor
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        use ink::env::test::EmittedEvent;
+        type Event = <SingleToken as ::ink::reflect::ContractEventBase>::Type;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let contract = create_contract(1u128);
+            assert_eq!(contract.get_workflow(), [0; 32]);
+            assert_eq!(contract.get_reward(), 1u128);
+            assert_eq!(contract.get_contribution(1u64), None);
+        }
+
+        #[ink::test]
+        fn register_identity_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let bob_identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.register_identity(bob_identity),
+                Ok(())
+            );
+
+            // Validate `IdentityRegistered` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(9, emitted_events.len());
+            let decoded_events = decode_events(emitted_events);
+            if let Event::IdentityRegistered(IdentityRegistered { identity, platform, caller }) = decoded_events[8] {
+                assert_eq!(identity, bob_identity);
+                assert_eq!(platform, Platform::GitHub);
+                assert_eq!(caller, accounts.bob);
+            } else {
+                panic!("encountered unexpected event kind: expected a IdentityRegistered event")
+            }
+
+            let maybe_account = contract.get_account(bob_identity);
+            assert_eq!(
+                maybe_account,
+                Some(accounts.bob)
+            );
+        }
+
+        #[ink::test]
+        fn handles_colliding_case_insensitively_are_rejected() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            assert_eq!(contract.set_canonical_identities(true), Ok(()));
+            assert!(contract.is_canonical_identities());
+
+            set_next_caller(accounts.bob);
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            assert_eq!(contract.register_identity(bobby), Err(WorkflowError::HandleRequired));
+            assert_eq!(contract.register_handle(String::from("bobby"), Vec::new()), Ok(()));
+            assert_eq!(contract.get_account(bobby), Some(accounts.bob));
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.register_handle(String::from("Bobby"), Vec::new()),
+                Err(WorkflowError::IdentityCollision(accounts.bob))
+            );
+            assert_eq!(contract.get_account(SingleToken::hash("Bobby".as_bytes())), None);
+            assert_eq!(contract.register_handle(String::from("Charly"), Vec::new()), Ok(()));
+        }
+
+        #[ink::test]
+        fn identity_verifier_is_set_through_the_queue() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            apply_change(&mut contract, ParameterChange::IdentityVerifier(Some(accounts.django)));
+            assert_eq!(contract.get_identity_verifier(), Some(accounts.django));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.queue_change(ParameterChange::IdentityVerifier(None)),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+        }
+
+        #[ink::test]
+        fn remark_proof_registers_identity() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            assert_eq!(
+                contract.confirm_remark_proof(identity, 10, 2),
+                Err(WorkflowError::NoRemarkChallenge)
+            );
+
+            set_next_caller(accounts.bob);
+            let challenge = contract.request_remark_proof(identity).unwrap();
+            assert_eq!(
+                contract.get_remark_challenge(identity),
+                Some(RemarkChallenge { account: accounts.bob, challenge })
+            );
+            assert_eq!(
+                contract.confirm_remark_proof(identity, 10, 2),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.confirm_remark_proof(identity, 10, 2), Ok(()));
+            assert_eq!(contract.get_account(identity), Some(accounts.bob));
+            assert_eq!(
+                contract.get_remark_proof(identity),
+                Some(RemarkProof { block: 10, index: 2 })
+            );
+            assert_eq!(contract.get_remark_challenge(identity), None);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.request_remark_proof(identity),
+                Err(WorkflowError::IdentityAlreadyRegistered)
+            );
+        }
+
+        #[ink::test]
+        fn already_registered_identity_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            assert_eq!(
+                contract.register_identity(identity),
+                Err(WorkflowError::IdentityAlreadyRegistered)
+            );
+        }
+
+        #[ink::test]
+        fn approve_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve(contribution_id, identity),
+                Ok(())
+            );
+
+            // Validate `ContributionApproval` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(10, emitted_events.len());
+            let decoded_events = decode_events(emitted_events);
+            if let Event::ContributionApproval(ContributionApproval { id, contributor, platform, pending_finality, metadata }) = decoded_events[9] {
+                assert_eq!(id, contribution_id);
+                assert_eq!(contributor, accounts.bob);
+                assert_eq!(platform, Platform::GitHub);
+                assert!(!pending_finality);
+                assert_eq!(metadata, None);
+            } else {
+                panic!("encountered unexpected event kind: expected a ContributionApproval event")
+            }
+
+            let maybe_contribution = contract.get_contribution(contribution_id);
+            assert_eq!(
+                maybe_contribution,
+                Some(Contribution {id: contribution_id, identity, contributor: accounts.bob, is_reward_claimed: false, template_id: None, expires_at: None, metadata: None})
+            );
+        }
+
+        #[ink::test]
+        fn only_contract_owner_can_approve() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            assert_eq!(
+                contract.approve(contribution_id, identity),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+        }
+
+        #[ink::test]
+        fn approver_role_can_be_granted_and_revoked() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            assert!(AccessControl::has_role(&contract, APPROVER, Some(accounts.alice)));
+            assert_eq!(
+                AccessControl::grant_role(&mut contract, APPROVER, Some(accounts.charlie)),
+                Err(AccessControlError::MissingRole)
+            );
+
+            // A maintainer is granted the approval rights only
+            set_next_caller(accounts.alice);
+            assert_eq!(AccessControl::grant_role(&mut contract, APPROVER, Some(accounts.charlie)), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.approve(1u64, identity), Ok(()));
+            assert_eq!(
+                contract.set_faucet(None),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+            assert_eq!(
+                contract.unpause(),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(AccessControl::revoke_role(&mut contract, APPROVER, Some(accounts.charlie)), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.approve(2u64, identity),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let events = decode_events(emitted_events);
+            assert!(events.iter().any(|event| matches!(
+                event,
+                Event::RoleGranted(RoleGranted { role, grantee, grantor })
+                    if *role == APPROVER && *grantee == Some(accounts.charlie) && *grantor == Some(accounts.alice)
+            )));
+            assert!(matches!(
+                events[events.len() - 1],
+                Event::RoleRevoked(RoleRevoked { role, account, sender })
+                    if role == APPROVER && account == Some(accounts.charlie) && sender == accounts.alice
+            ));
+        }
+
+        #[ink::test]
+        fn already_approved_contribution_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            let identity2 = SingleToken::hash("bobby2".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+
+            assert_eq!(
+                contract.approve(contribution_id, identity2),
+                Err(WorkflowError::ContributionAlreadyApproved)
+            );
+        }
+
+        #[ink::test]
+        fn identical_approval_is_idempotent() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(contribution_id, identity), Ok(()));
+            let emitted_events_count = ink::env::test::recorded_events().count();
+
+            assert_eq!(contract.approve(contribution_id, identity), Ok(()));
+            assert_eq!(ink::env::test::recorded_events().count(), emitted_events_count);
+            assert_eq!(contract.get_approved_count(), 1);
+        }
+
+        #[ink::test]
+        fn approve_unknown_contributor_identity_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            let identity2 = SingleToken::hash("bobby2".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.approve(contribution_id, identity2),
+                Err(WorkflowError::UnknownContributor)
+            );
+        }
+
+        #[ink::test]
+        fn can_claim_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+            
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.can_claim(contribution_id),
+                Ok(true)
+            );
+        }
+
+        #[ink::test]
+        fn claim_works() {
+            let accounts = default_accounts();
+            let single_reward = 1u128;
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let issue_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(issue_id, identity);
+            
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(issue_id), Ok(()));
+            assert_eq!(
+                get_balance(accounts.bob),
+                bob_initial_balance + contract.reward
+            );
+
+            let maybe_contribution = contract.get_contribution(issue_id);
+            assert_eq!(
+                maybe_contribution,
+                Some(Contribution {id: issue_id, identity, contributor: accounts.bob, is_reward_claimed: true, template_id: None, expires_at: None, metadata: None})
+            );
+
+            // Validate `RewardClaimed` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            assert_eq!(11, emitted_events.len());
+            let decoded_events = decode_events(emitted_events);
+            if let Event::RewardClaimed(RewardClaimed { contribution_id, contributor, reward, bonus }) = decoded_events[10] {
+                assert_eq!(contribution_id, issue_id);
+                assert_eq!(contributor, accounts.bob);
+                assert_eq!(reward, single_reward);
+                assert_eq!(bonus, 0);
+            } else {
+                panic!("encountered unexpected event kind: expected a RewardClaimed event")
+            }
+        }
+
+        #[ink::test]
+        fn claim_pays_the_approved_reward() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.get_reserved(), 1);
+
+            // A reward change executed before the claim doesn't apply to the approved contribution
+            set_reward(&mut contract, 5);
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 1);
+            assert_eq!(contract.get_reserved(), 0);
+        }
+
+        #[ink::test]
+        fn cannot_claim_non_approved_contribution() {
+            let accounts = default_accounts();
+            let contract = create_contract(1u128);
+            set_next_caller(accounts.bob);
+
+            let contribution_id = 1u64;
+            assert_eq!(
+                contract.can_claim(contribution_id),
+                Err(WorkflowError::NoContributionApprovedYet)
+            );
+        }
+
+        #[ink::test]
+        fn cannot_claim_unknown_contribution() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.can_claim(2u64),
+                Err(WorkflowError::UnknownContribution)
+            );
+        }
+
+        #[ink::test]
+        fn cannot_claim_if_not_contributor() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.eve);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.can_claim(contribution_id),
+                Err(WorkflowError::CallerIsNotContributor)
+            );
+        }
+
+        #[ink::test]
+        fn cannot_claim_already_claimed_reward() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(contribution_id);
+            assert_eq!(
+                contract.can_claim(contribution_id),
+                Err(WorkflowError::AlreadyClaimed)
+            );
+        }
+
+        #[ink::test]
+        fn guardian_can_pause() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            assert_eq!(contract.set_guardian(Some(accounts.charlie)), Ok(()));
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.pause(), Ok(()));
+            assert!(Pausable::paused(&contract));
+
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.register_identity(identity),
+                Err(WorkflowError::ContractPaused)
+            );
+        }
+
+        #[ink::test]
+        fn guardian_cannot_unpause() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let _ = contract.set_guardian(Some(accounts.charlie));
+
+            set_next_caller(accounts.charlie);
+            let _ = contract.pause();
+            assert_eq!(
+                contract.unpause(),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+            assert_eq!(
+                contract.set_guardian(Some(accounts.django)),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.unpause(), Ok(()));
+            assert!(!Pausable::paused(&contract));
+        }
+
+        #[ink::test]
+        fn only_owner_or_guardian_can_pause() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let _ = contract.set_guardian(Some(accounts.charlie));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.pause(), Err(WorkflowError::CallerIsNotGuardian));
+        }
+
+        #[ink::test]
+        fn cannot_approve_nor_claim_when_paused() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+            let _ = contract.pause();
+            assert_eq!(contract.approve(2, identity), Err(WorkflowError::ContractPaused));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.claim(contribution_id),
+                Err(WorkflowError::ContractPaused)
+            );
+        }
+
+        #[ink::test]
+        fn claim_stores_credential() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+            assert_eq!(contract.get_credential(contribution_id), None);
+
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(contribution_id);
+            assert_eq!(
+                contract.get_credential(contribution_id),
+                Some(Credential {
+                    payload: CredentialPayload {
+                        contract: contract_id(),
+                        contribution_id,
+                        contributor: accounts.bob,
+                        reward: 1u128,
+                    },
+                    claimed_at: 0,
+                    signature: None,
+                })
+            );
+        }
+
+        #[ink::test]
+        fn claim_attaches_registered_credential_signature() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+            apply_change(&mut contract, ParameterChange::PlatformKey(Some(signer_account(PLATFORM_SECRET))));
+
+            let payload = CredentialPayload {
+                contract: contract_id(),
+                contribution_id,
+                contributor: accounts.bob,
+                reward: 1u128,
+            };
+            let signature = sign(PLATFORM_SECRET, &SingleToken::hash_payload(&payload));
+            assert_eq!(
+                contract.register_credential_signature(contribution_id, signature),
+                Ok(())
+            );
+            let decoded_events = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>());
+            if let Some(Event::CredentialSignatureRegistered(event)) = decoded_events.last() {
+                assert_eq!(event.account, accounts.alice);
+                assert_eq!(event.contribution_id, contribution_id);
+                assert_eq!(event.old_signature, None);
+                assert_eq!(event.new_signature, signature.to_vec());
+            } else {
+                panic!("encountered unexpected event kind: expected a CredentialSignatureRegistered event")
+            }
+
+            set_next_caller(accounts.bob);
+            let _ = contract.claim(contribution_id);
+            let credential = contract.get_credential(contribution_id).expect("credential is stored");
+            assert_eq!(credential.payload, payload);
+            assert_eq!(credential.signature, Some(signature.to_vec()));
+        }
+
+        #[ink::test]
+        fn register_credential_signature_from_unknown_key_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+            let signature = sign(OTHER_SECRET, &[0; 32]);
+            assert_eq!(
+                contract.register_credential_signature(contribution_id, signature),
+                Err(WorkflowError::PlatformKeyNotSet)
+            );
+
+            apply_change(&mut contract, ParameterChange::PlatformKey(Some(signer_account(PLATFORM_SECRET))));
+            assert_eq!(
+                contract.register_credential_signature(contribution_id, signature),
+                Err(WorkflowError::InvalidSignature)
+            );
+        }
+
+        #[ink::test]
+        fn fund_records_deposits() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 30);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 10);
+            assert_eq!(contract.get_deposit(accounts.charlie), 40);
+            assert_eq!(contract.get_deposit(accounts.alice), 1);
+            assert_eq!(contract.get_total_funded(), 41);
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            if let Some(Event::FundsDeposited(FundsDeposited { funder, amount, total_funded })) = decoded_events.last() {
+                assert_eq!(*funder, accounts.charlie);
+                assert_eq!(*amount, 10);
+                assert_eq!(*total_funded, 41);
+            } else {
+                panic!("encountered unexpected event kind: expected a FundsDeposited event")
+            }
+
+            // The available balance excludes the reserved rewards
+            set_balance(contract_id(), 41);
+            assert_eq!(contract.get_available_balance(), Ok(41));
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
+            assert_eq!(contract.get_available_balance(), Ok(40));
+        }
+
+        #[ink::test]
+        fn only_owner_can_terminate() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.terminate(),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+            assert_eq!(
+                contract.process_refunds(10),
+                Err(WorkflowError::ContractNotTerminated)
+            );
+        }
+
+        #[ink::test]
+        fn terminate_refunds_funders_pro_rata() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 30);
+            set_next_caller(accounts.bob);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 9);
+            // Half of the funds have been spent elsewhere.
+            set_balance(contract_id(), 20);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.terminate(), Ok(()));
+            assert_eq!(
+                ink::env::pay_with_call!(contract.fund(Vec::new()), 1),
+                Err(WorkflowError::ContractTerminated)
+            );
+
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.django);
+            assert_eq!(contract.process_refunds(2), Ok(1));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 15);
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance);
+            assert_eq!(contract.process_refunds(2), Ok(0));
+            // The rounding remainder goes to the last funder
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 5);
+        }
+
+        #[ink::test]
+        fn terminate_refunds_earmarks_to_their_funders() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(vec![1]), 30);
+            set_next_caller(accounts.bob);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 9);
+            // Some of the general funds have been spent elsewhere.
+            set_balance(contract_id(), 35);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.terminate(), Ok(()));
+
+            // The earmark goes back to its funder, the rest is refunded pro-rata
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.django);
+            assert_eq!(contract.process_refunds(1), Ok(3));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 30);
+            assert_eq!(contract.get_earmarked(), 0);
+            assert_eq!(contract.process_refunds(10), Ok(0));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 30);
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 5);
+        }
+
+        #[ink::test]
+        fn terminate_refunds_the_pool_as_dust_once_all_deposits_are_earmarked() {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            set_balance(contract_id(), 0);
+            let mut contract = SingleToken::new([0; 32]);
+            assert_eq!(contract.set_dust_policy(DustPolicy::Treasury(accounts.frank)), Ok(()));
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(vec![1]), 30);
+            set_next_caller(accounts.eve);
+            assert_eq!(ink::env::pay_with_call!(contract.receive(), 5), Ok(()));
+            set_balance(contract_id(), 35);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.terminate(), Ok(()));
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            let frank_initial_balance = get_balance(accounts.frank);
+            set_next_caller(accounts.django);
+            assert_eq!(contract.process_refunds(10), Ok(0));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 30);
+            assert_eq!(get_balance(accounts.frank), frank_initial_balance + 5);
+        }
+
+        #[ink::test]
+        fn withdrawn_funders_are_refunded_once() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(vec![1]), 30);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.close_issue(1), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.withdraw_earmark(0), Ok(()));
+            assert_eq!(contract.get_deposit(accounts.charlie), 0);
+
+            // Funding again after a full withdrawal does not index the funder twice
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 10);
+            set_next_caller(accounts.bob);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 10);
+            set_balance(contract_id(), 21);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.terminate(), Ok(()));
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.django);
+            assert_eq!(contract.process_refunds(10), Ok(0));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 10);
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 10);
+            assert_eq!(get_balance(contract_id()), 0);
+        }
+
+        #[ink::test]
+        fn dust_goes_to_treasury() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 101);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 101);
+            assert_eq!(contract.set_dust_policy(DustPolicy::Treasury(accounts.frank)), Ok(()));
+            assert_eq!(contract.get_dust_policy(), DustPolicy::Treasury(accounts.frank));
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.claim_split(1, vec![(accounts.django, 30), (accounts.eve, 70)]),
+                Ok(())
+            );
+            assert_eq!(get_balance(accounts.django), 30);
+            assert_eq!(get_balance(accounts.eve), 70);
+            assert_eq!(get_balance(accounts.frank), 1);
+
+            // The rounding remainder of the refunds goes to the treasury as well
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 101);
+            set_balance(contract_id(), 3);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.terminate(), Ok(()));
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            assert_eq!(contract.process_refunds(2), Ok(0));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 1);
+            assert_eq!(get_balance(accounts.frank), 2);
+        }
+
+        #[ink::test]
+        fn statement_of_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 100);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 100);
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 303);
+
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+
+            assert_eq!(
+                contract.statement_of(accounts.charlie, 0),
+                Statement { deposited: 303, allocated: 75, refunded: 0, fees: 0 }
+            );
+            assert_eq!(
+                contract.statement_of(accounts.alice, 0),
+                Statement { deposited: 101, allocated: 25, refunded: 0, fees: 0 }
+            );
+
+            // Refunds are recorded in the epoch they are processed
+            set_block_timestamp(EPOCH_DURATION);
+            assert_eq!(contract.get_current_epoch(), 1);
+            set_next_caller(accounts.alice);
+            let _ = contract.terminate();
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            let _ = contract.process_refunds(2);
+            let refunded = get_balance(accounts.charlie) - charlie_initial_balance;
+            assert!(refunded > 0);
+            assert_eq!(
+                contract.statement_of(accounts.charlie, 1),
+                Statement { deposited: 0, allocated: 0, refunded, fees: 0 }
+            );
+            assert_eq!(contract.statement_of(accounts.charlie, 0).refunded, 0);
+        }
+
+        #[ink::test]
+        fn upheld_challenge_revokes_approval() {
+            let accounts = default_accounts();
+            let mut contract = create_challengeable_contract();
+            assert_eq!(contract.get_disputable_until(1), Some(100));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Err(WorkflowError::DisputeWindowOpen));
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                ink::env::pay_with_call!(contract.challenge(1), 9),
+                Err(WorkflowError::InvalidBond)
+            );
+            assert_eq!(ink::env::pay_with_call!(contract.challenge(1), 10), Ok(()));
+            assert_eq!(
+                ink::env::pay_with_call!(contract.challenge(1), 10),
+                Err(WorkflowError::ContributionChallenged)
+            );
+            assert_eq!(
+                contract.resolve_challenge(1, true),
+                Err(WorkflowError::CallerIsNotArbitrator)
+            );
+
+            set_block_timestamp(100);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Err(WorkflowError::ContributionChallenged));
+
+            // The challenger gets back the bond plus half of the reward
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::Arbitrator(Some(accounts.django)));
+            set_next_caller(accounts.django);
+            assert_eq!(contract.resolve_challenge(1, true), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 60);
+            assert_eq!(contract.get_contribution(1), None);
+            assert_eq!(contract.get_challenge(1), None);
+            assert_eq!(contract.get_escrow(Asset::Native), Ok(0));
+        }
+
+        #[ink::test]
+        fn challenged_contribution_does_not_expire() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            apply_change(
+                &mut contract,
+                ParameterChange::ChallengeConfig {
+                    bond: 10,
+                    dispute_window: 100,
+                    challenger_share: 5_000,
+                },
+            );
+            apply_change(&mut contract, ParameterChange::ClaimWindow(1));
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(ink::env::pay_with_call!(contract.challenge(1), 10), Ok(()));
+
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.expire(1), Err(WorkflowError::ContributionChallenged));
+            set_next_caller(accounts.django);
+            assert_eq!(contract.keeper_sweep(), Ok(0));
+            assert!(contract.get_contribution(1).is_some());
+
+            // Once the challenge is rejected, the contribution expires
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.resolve_challenge(1, false), Ok(()));
+            assert_eq!(contract.expire(1), Ok(()));
+            assert_eq!(contract.get_contribution(1), None);
+        }
+
+        #[ink::test]
+        fn challenger_share_follows_the_contribution_reward() {
+            let accounts = default_accounts();
+            let mut contract = create_challengeable_contract();
+            let hard = SingleToken::hash("hard".as_bytes());
+            apply_change(&mut contract, ParameterChange::DifficultyReward(hard, Some(RewardAmount::from_plancks(40))));
+            let identity = SingleToken::hash("bobby".as_bytes());
+            assert_eq!(contract.approve_with_difficulty(2, identity, hard), Ok(()));
+            assert_eq!(contract.get_reservation(2), 40);
+
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.challenge(2), 10);
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.resolve_challenge(2, true), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 20 + 10);
+            assert_eq!(contract.get_escrow(Asset::Native), Ok(100));
+        }
+
+        #[ink::test]
+        fn rejected_challenge_forfeits_bond() {
+            let accounts = default_accounts();
+            let mut contract = create_challengeable_contract();
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.challenge(1), 10);
+            assert_eq!(contract.get_escrow(Asset::Native), Ok(100));
+
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.resolve_challenge(1, false), Ok(()));
+            assert_eq!(contract.resolve_challenge(1, false), Err(WorkflowError::NoChallenge));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance);
+
+            // The approval cannot be challenged after the dispute window
+            set_block_timestamp(100);
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                ink::env::pay_with_call!(contract.challenge(1), 10),
+                Err(WorkflowError::DisputeWindowClosed)
+            );
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+        }
+
+        #[ink::test]
+        fn terminate_keeps_approved_reward_claimable() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(contribution_id, identity);
+            // Only the approved reward is left in the contract.
+            set_balance(contract_id(), 1);
+            let _ = contract.terminate();
+            assert_eq!(contract.get_refund().map(|refund| refund.pool), Some(0));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(contribution_id), Ok(()));
+        }
+
+        #[ink::test]
+        fn first_claim_is_multiplied() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::FirstContributionMultiplier(20_000));
+            let _ = contract.approve(1u64, identity);
+            let _ = contract.approve(2u64, identity);
+
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1u64), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 2);
+            assert_eq!(contract.get_claims_count(identity), 1);
+
+            assert_eq!(contract.claim(2u64), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 3);
+            assert_eq!(contract.get_claims_count(identity), 2);
+        }
+
+        #[ink::test]
+        fn claim_streaks_are_tracked() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            assert_eq!(contract.get_streak(identity), None);
+
+            set_next_caller(accounts.alice);
+            for contribution_id in 1u64..=5 {
+                let _ = contract.approve(contribution_id, identity);
+            }
+
+            // Two claims in the same epoch, then one in the next epoch
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1u64), Ok(()));
+            assert_eq!(contract.claim(2u64), Ok(()));
+            assert_eq!(contract.get_streak(identity), Some(Streak { current: 1, longest: 1, last_epoch: 0 }));
+            set_block_timestamp(EPOCH_DURATION);
+            assert_eq!(contract.claim(3u64), Ok(()));
+            assert_eq!(contract.get_streak(identity), Some(Streak { current: 2, longest: 2, last_epoch: 1 }));
+
+            // The streak is still alive during the following epoch, then broken
+            set_block_timestamp(2 * EPOCH_DURATION);
+            assert_eq!(contract.get_streak(identity), Some(Streak { current: 2, longest: 2, last_epoch: 1 }));
+            set_block_timestamp(3 * EPOCH_DURATION);
+            assert_eq!(contract.get_streak(identity), Some(Streak { current: 0, longest: 2, last_epoch: 1 }));
+            assert_eq!(contract.claim(4u64), Ok(()));
+            assert_eq!(contract.get_streak(identity), Some(Streak { current: 1, longest: 2, last_epoch: 3 }));
+        }
+
+        #[ink::test]
+        fn trial_contributions_skip_finality_and_are_capped() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve_trial(1u64, identity), Err(WorkflowError::TrialsDisabled));
+            let trials = TrialConfig { reward: RewardAmount::from_plancks(2), max_per_identity: 1, duration: 100 };
+            assert_eq!(contract.set_trials(Some(trials)), Ok(()));
+            assert_eq!(contract.set_finality_depth(10), Ok(()));
+            set_block_timestamp(1_000);
+
+            assert_eq!(contract.approve_trial(1u64, identity), Ok(()));
+            assert_eq!(contract.get_trials_count(identity), 1);
+            assert_eq!(contract.get_finalizable_at(1u64), None);
+            assert_eq!(contract.get_contribution(1u64).map(|c| c.expires_at), Some(Some(1_100)));
+            assert_eq!(contract.approve_trial(2u64, identity), Err(WorkflowError::TrialCapReached));
+
+            // The fixed trial reward is paid right away
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1u64), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 2);
+        }
+
+        #[ink::test]
+        fn only_admin_can_upgrade_and_migrate() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            assert_eq!(contract.get_version(), STORAGE_VERSION);
+            assert_eq!(contract.migrate(), Err(WorkflowError::AlreadyMigrated));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.queue_change(ParameterChange::CodeHash(Hash::from([1; 32]))),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+            assert_eq!(
+                contract.migrate(),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            // The code is only upgraded once the change delay has elapsed
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.queue_change(ParameterChange::CodeHash(Hash::from([1; 32]))), Ok(()));
+            assert_eq!(contract.execute_change(Parameter::CodeHash), Err(WorkflowError::ChangeNotReady));
+
+            // The storage of a previous version is migrated once
+            contract.version = 0;
+            assert_eq!(contract.migrate(), Ok(()));
+            assert_eq!(contract.get_version(), STORAGE_VERSION);
+            assert_eq!(contract.migrate(), Err(WorkflowError::AlreadyMigrated));
+
+            // Validate `Migrated` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            if let Some(Event::Migrated(Migrated { from, to })) = decoded_events.last() {
+                assert_eq!(*from, 0);
+                assert_eq!(*to, STORAGE_VERSION);
+            } else {
+                panic!("encountered unexpected event kind: expected a Migrated event")
+            }
+        }
+
+        #[ink::test]
+        fn session_accounts_approve_within_their_scope() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            let session = Session {
+                selectors: vec![ink::selector_bytes!("approve")],
+                max_value: 2,
+                expires_at: 5,
+            };
+            assert_eq!(contract.authorize_session(accounts.charlie, Some(session.clone())), Ok(()));
+            assert_eq!(contract.get_session(accounts.charlie), Some(session));
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.approve(1u64, identity), Ok(()));
+            assert_eq!(contract.get_session_spent(accounts.charlie), 1);
+            assert_eq!(
+                contract.approve_in_run(1, 2u64, identity),
+                Err(WorkflowError::SessionNotAllowed)
+            );
+            assert_eq!(contract.approve(2u64, identity), Ok(()));
+            assert_eq!(contract.approve(3u64, identity), Err(WorkflowError::SessionLimitExceeded));
+
+            // The actual reward of the contribution is accounted to the session
+            set_next_caller(accounts.alice);
+            let hard = SingleToken::hash("hard".as_bytes());
+            apply_change(&mut contract, ParameterChange::DifficultyReward(hard, Some(RewardAmount::from_plancks(3))));
+            let session = Session {
+                selectors: vec![ink::selector_bytes!("approve"), ink::selector_bytes!("approve_with_difficulty")],
+                max_value: 4,
+                expires_at: 5,
+            };
+            let _ = contract.authorize_session(accounts.django, Some(session));
+            set_next_caller(accounts.django);
+            assert_eq!(contract.approve_with_difficulty(4, identity, hard), Ok(()));
+            assert_eq!(contract.get_session_spent(accounts.django), 3);
+            assert_eq!(
+                contract.approve_with_difficulty(5, identity, hard),
+                Err(WorkflowError::SessionLimitExceeded)
+            );
+            assert_eq!(contract.approve(6u64, identity), Ok(()));
+            assert_eq!(contract.get_session_spent(accounts.django), 4);
+
+            // The session expires, then is revoked
+            set_next_caller(accounts.alice);
+            let session = Session { selectors: vec![ink::selector_bytes!("approve")], max_value: 10, expires_at: 0 };
+            let _ = contract.authorize_session(accounts.charlie, Some(session));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.approve(3u64, identity), Err(WorkflowError::SessionNotAllowed));
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.authorize_session(accounts.charlie, None), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.approve(3u64, identity),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+        }
+
+        #[ink::test]
+        fn legacy_state_can_be_imported() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            let charly = SingleToken::hash("charly".as_bytes());
+            let identities = vec![(bobby, accounts.bob), (charly, accounts.charlie)];
+            let contributions = vec![(1, bobby, true), (2, charly, false)];
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.import_state(identities.clone(), contributions.clone()),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.import_state(identities.clone(), contributions), Ok(()));
+            assert_eq!(contract.get_account(bobby), Some(accounts.bob));
+            assert_eq!(contract.get_claims_count(bobby), 1);
+            assert_eq!(contract.get_approved_count(), 2);
+            assert_eq!(contract.get_reserved(), 1);
+            assert_eq!(contract.import_state(identities, Vec::new()), Err(WorkflowError::IdentityAlreadyRegistered));
+
+            // The claimed contribution is not paid again, the unclaimed one is claimable
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Err(WorkflowError::AlreadyClaimed));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.claim(2), Ok(()));
+        }
+
+        #[ink::test]
+        fn reputation_can_be_imported_from_other_deployments() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.create_template(Template {
+                kind: String::from("bounty"),
+                tier: 2,
+                expiry: None,
+                tags: Vec::new(),
+            });
+            let _ = contract.set_tier_min_reputation(2, Some(10));
+            assert_eq!(
+                contract.approve_from_template(0, 1, identity),
+                Err(WorkflowError::InsufficientReputation)
+            );
+
+            let attestation = ReputationAttestation {
+                source: accounts.django,
+                identity,
+                points: 12,
+                issued_at: 0,
+            };
+            let signature = sign(ATTESTOR_SECRET, &SingleToken::hash_payload(&attestation));
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.import_reputation(attestation, signature),
+                Err(WorkflowError::UnknownReputationSource)
+            );
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_reputation_source(accounts.django, Some(signer_account(OTHER_SECRET))), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.import_reputation(attestation, signature), Err(WorkflowError::InvalidSignature));
+
+            set_next_caller(accounts.alice);
+            let _ = contract.set_reputation_source(accounts.django, Some(signer_account(ATTESTOR_SECRET)));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.import_reputation(attestation, signature), Ok(()));
+            assert_eq!(contract.get_imported_reputation(identity), 12);
+
+            // The imported reputation is enough for the high tier template
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve_from_template(0, 1, identity), Ok(()));
+        }
+
+        #[ink::test]
+        fn claim_many_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            for contribution_id in 1u64..=3 {
+                let _ = contract.approve(contribution_id, identity);
+            }
+
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_many(vec![1, 2, 3]), Ok(3));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 3);
+            assert_eq!(contract.claim_many(vec![3]), Err(WorkflowError::AlreadyClaimed));
+            assert_eq!(
+                contract.claim_many(vec![4; MAX_CLAIM_BATCH + 1]),
+                Err(WorkflowError::ClaimBatchTooLarge)
+            );
+
+            // Validate one `RewardClaimed` event per contribution
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let claimed = decode_events(emitted_events)
+                .into_iter()
+                .filter(|event| matches!(event, Event::RewardClaimed(_)))
+                .count();
+            assert_eq!(claimed, 3);
+        }
+
+        #[ink::test]
+        fn credited_payout_can_be_withdrawn_to_another_account() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+
+            // The off-chain environment cannot fail a transfer, the credit is recorded directly
+            assert_eq!(contract.credit_payout(accounts.bob, 1), Ok(()));
+            assert_eq!(contract.get_pending_payout(accounts.bob, Asset::Native), 1);
+            assert_eq!(contract.get_escrow(Asset::Native), Ok(1));
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            if let Some(Event::CreditedInsteadOfPaid(CreditedInsteadOfPaid { contributor, amount })) = decoded_events.last() {
+                assert_eq!(*contributor, accounts.bob);
+                assert_eq!(*amount, 1);
+            } else {
+                panic!("encountered unexpected event kind: expected a CreditedInsteadOfPaid event")
+            }
+
+            // The contributor withdraws to another account
+            let django_initial_balance = get_balance(accounts.django);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.withdraw_pending_to(Asset::Native, accounts.django), Ok(()));
+            assert_eq!(get_balance(accounts.django), django_initial_balance + 1);
+            assert_eq!(contract.get_pending_payout(accounts.bob, Asset::Native), 0);
+            assert_eq!(
+                contract.withdraw_pending_to(Asset::Native, accounts.django),
+                Err(WorkflowError::NoPendingPayout)
+            );
+        }
+
+        #[ink::test]
+        fn unclaimed_contribution_can_be_expired() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::ClaimWindow(2));
+            let _ = contract.approve(1u64, identity);
+            assert_eq!(contract.get_claim_deadline(1u64), Some(2));
+            assert_eq!(contract.get_reserved(), 1);
+            assert_eq!(contract.expire(1u64), Err(WorkflowError::ContributionNotExpired));
+
+            for _ in 0..3 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            }
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1u64), Err(WorkflowError::ContributionExpired));
+            assert_eq!(
+                contract.expire(1u64),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            let available = contract.get_available_balance().unwrap();
+            assert_eq!(contract.expire(1u64), Ok(()));
+            assert_eq!(contract.get_reserved(), 0);
+            assert_eq!(contract.get_contribution(1u64), None);
+            assert_eq!(contract.get_available_balance(), Ok(available + 1));
+        }
+
+        #[ink::test]
+        fn identity_can_be_unregistered_and_registered_again() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.unregister_identity(identity),
+                Err(WorkflowError::CallerIsNotContributor)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.unregister_identity(identity), Ok(()));
+            assert_eq!(contract.get_account(identity), None);
+            assert_eq!(
+                contract.unregister_identity(identity),
+                Err(WorkflowError::UnknownContributor)
+            );
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+            assert_eq!(contract.get_account(identity), Some(accounts.charlie));
+        }
+
+        #[ink::test]
+        fn rotated_identity_claims_pending_rewards() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+
+            set_next_caller(accounts.django);
+            assert_eq!(
+                contract.rotate_identity(identity, accounts.django),
+                Err(WorkflowError::CallerIsNotContributor)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.rotate_identity(identity, accounts.django), Ok(()));
+            assert_eq!(contract.get_account(identity), Some(accounts.django));
+            assert_eq!(contract.claim(1), Err(WorkflowError::CallerIsNotContributor));
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let event = decode_events(emitted_events).pop().expect("an event");
+            if let Event::IdentityRotated(IdentityRotated { identity: rotated, previous_account, account }) = event {
+                assert_eq!(rotated, identity);
+                assert_eq!(previous_account, accounts.bob);
+                assert_eq!(account, accounts.django);
+            } else {
+                panic!("encountered unexpected event kind: expected a IdentityRotated event")
+            }
+
+            set_next_caller(accounts.django);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(get_balance(accounts.django), 1);
+        }
+
+        #[ink::test]
+        fn difficulty_tiers_pay_their_reward() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(5u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            let hard_bug = SingleToken::hash("hard bug".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+            assert_eq!(
+                contract.queue_change(ParameterChange::DifficultyReward(hard_bug, Some(RewardAmount::from_plancks(3)))),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve_with_difficulty(1, identity, hard_bug), Err(WorkflowError::UnknownTier));
+            apply_change(&mut contract, ParameterChange::DifficultyReward(hard_bug, Some(RewardAmount::from_plancks(3))));
+            assert_eq!(contract.get_difficulty_reward(hard_bug), Some(3));
+            assert_eq!(contract.approve_with_difficulty(1, identity, hard_bug), Ok(()));
+            assert_eq!(contract.get_reservation(1), 3);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(get_balance(accounts.bob), 1000 + 3);
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::DifficultyReward(hard_bug, None));
+            assert_eq!(contract.get_difficulty_reward(hard_bug), None);
+        }
+
+        #[ink::test]
+        fn verifier_keys_rotate_with_overlapping_windows() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            let verifier = |secret, valid_from, valid_to| VerifierKey {
+                key: signer_account(secret),
+                valid_from,
+                valid_to,
+            };
+            assert_eq!(
+                contract.queue_change(ParameterChange::Verifier(verifier(ATTESTOR_SECRET, 10, 10))),
+                Err(WorkflowError::InvalidVerifierKey)
+            );
+            apply_change(&mut contract, ParameterChange::Verifier(verifier(ATTESTOR_SECRET, 0, 100)));
+            apply_change(&mut contract, ParameterChange::Verifier(verifier(WORKFLOW_SECRET, 50, 200)));
+            assert_eq!(contract.get_verifier(0).map(|verifier| verifier.key), Some(signer_account(ATTESTOR_SECRET)));
+            assert_eq!(
+                contract.get_verifier(1),
+                Some(VerifierKey { key: signer_account(WORKFLOW_SECRET), valid_from: 50, valid_to: 200 })
+            );
+
+            let registration = |key_id| RegistrationPayload {
+                contract: contract_id(),
+                identity,
+                account: accounts.bob,
+                key_id,
+            };
+            let approval = |key_id| VerifiedApprovalPayload {
+                approval: ApprovalPayload {
+                    contract: contract_id(),
+                    contribution_id: 1,
+                    contributor_identity: identity,
+                    nonce: 7,
+                },
+                key_id,
+            };
+
+            // Both keys are valid during the overlap, the signature must match the key id
+            set_next_caller(accounts.django);
+            set_block_timestamp(60);
+            let signature = sign(ATTESTOR_SECRET, &SingleToken::hash_payload(&registration(1)));
+            assert_eq!(
+                contract.register_with_attestation(identity, accounts.bob, 1, signature),
+                Err(WorkflowError::InvalidSignature)
+            );
+            let signature = sign(ATTESTOR_SECRET, &SingleToken::hash_payload(&registration(0)));
+            assert_eq!(contract.register_with_attestation(identity, accounts.bob, 0, signature), Ok(()));
+            assert_eq!(contract.get_account(identity), Some(accounts.bob));
+
+            // The previous key expired
+            set_block_timestamp(150);
+            let signature = sign(ATTESTOR_SECRET, &SingleToken::hash_payload(&approval(0)));
+            assert_eq!(
+                contract.approve_with_attestation(1, identity, 7, 0, signature),
+                Err(WorkflowError::InvalidVerifierKey)
+            );
+            let signature = sign(WORKFLOW_SECRET, &SingleToken::hash_payload(&approval(1)));
+            assert_eq!(contract.approve_with_attestation(1, identity, 7, 1, signature), Ok(()));
+            assert_eq!(
+                contract.approve_with_attestation(1, identity, 7, 1, signature),
+                Err(WorkflowError::SignatureAlreadyUsed)
+            );
+            assert_eq!(
+                contract.approve_with_attestation(2, identity, 8, 5, signature),
+                Err(WorkflowError::InvalidVerifierKey)
+            );
+        }
+
+        #[ink::test]
+        fn revoked_verifier_key_is_rejected() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            let verifier = VerifierKey { key: signer_account(ATTESTOR_SECRET), valid_from: 0, valid_to: 100 };
+            apply_change(&mut contract, ParameterChange::Verifier(verifier));
+            assert_eq!(contract.revoke_verifier(1), Err(WorkflowError::InvalidVerifierKey));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.revoke_verifier(0),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            set_block_timestamp(40);
+            assert_eq!(contract.revoke_verifier(0), Ok(()));
+            assert_eq!(
+                contract.get_verifier(0),
+                Some(VerifierKey { key: signer_account(ATTESTOR_SECRET), valid_from: 0, valid_to: 40 })
+            );
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            if let Some(Event::VerifierRevoked(VerifierRevoked { account, key_id, valid_to })) = decoded_events.last() {
+                assert_eq!((*account, *key_id, *valid_to), (accounts.alice, 0, 40));
+            } else {
+                panic!("encountered unexpected event kind: expected a VerifierRevoked event")
+            }
+
+            let registration = RegistrationPayload {
+                contract: contract_id(),
+                identity,
+                account: accounts.bob,
+                key_id: 0,
+            };
+            let signature = sign(ATTESTOR_SECRET, &SingleToken::hash_payload(&registration));
+            assert_eq!(
+                contract.register_with_attestation(identity, accounts.bob, 0, signature),
+                Err(WorkflowError::InvalidVerifierKey)
+            );
+        }
+
+        #[ink::test]
+        fn claims_accumulate_stats() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(2u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+            assert_eq!(contract.get_stats(identity), ContributorStats::default());
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.approve(2, identity), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(contract.claim(2), Ok(()));
+
+            assert_eq!(
+                contract.get_stats(identity),
+                ContributorStats { claims_count: 2, total_rewarded: 2, first_claim_at: 0, last_claim_at: 2 }
+            );
+        }
+
+        #[ink::test]
+        fn separation_of_duties_forbids_self_approval() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(2u128);
+            let alice = SingleToken::hash("alice".as_bytes());
+            let alice_alt = SingleToken::hash("alice-alt".as_bytes());
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.register_identity(alice), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(alice_alt), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.register_identity(bobby), Ok(()));
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.link_identity(alice_alt, Some(alice)), Ok(()));
+            assert_eq!(contract.set_separation_of_duties(true), Ok(()));
+            assert!(contract.is_separation_of_duties());
+            assert_eq!(contract.approve(1, alice), Err(WorkflowError::SelfApprovalForbidden));
+            assert_eq!(contract.approve(1, alice_alt), Err(WorkflowError::SelfApprovalForbidden));
+            assert_eq!(contract.approve(1, bobby), Ok(()));
+
+            assert_eq!(contract.set_separation_of_duties(false), Ok(()));
+            assert_eq!(contract.approve(2, alice), Ok(()));
+        }
+
+        #[ink::test]
+        fn separation_of_duties_applies_to_the_pre_approver() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let alice = SingleToken::hash("alice".as_bytes());
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            assert_eq!(contract.set_separation_of_duties(true), Ok(()));
+            assert_eq!(contract.pre_approve(1, bobby), Ok(()));
+            assert_eq!(contract.pre_approve(2, alice), Ok(()));
+
+            // The registering contributor is not the approver
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(bobby), Ok(()));
+            assert!(contract.get_contribution(1).is_some());
+
+            // The pre-approver cannot approve itself by registering
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.register_identity(alice), Ok(()));
+            assert_eq!(contract.get_contribution(2), None);
+            assert!(contract.is_awaiting_registration(alice));
+        }
+
+        #[ink::test]
+        fn separation_of_duties_applies_to_the_signer() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let maintainer = SingleToken::hash("maintainer".as_bytes());
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(signer_account(WORKFLOW_SECRET));
+            assert_eq!(contract.register_identity(maintainer), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(bobby), Ok(()));
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.add_signer(signer_account(WORKFLOW_SECRET)), Ok(()));
+            assert_eq!(contract.set_separation_of_duties(true), Ok(()));
+            let signed = |contribution_id, contributor_identity| {
+                sign(
+                    WORKFLOW_SECRET,
+                    &SingleToken::hash_payload(&ApprovalPayload {
+                        contract: contract_id(),
+                        contribution_id,
+                        contributor_identity,
+                        nonce: 0,
+                    }),
+                )
+            };
+
+            // Relaying the approval through another account does not bypass the check
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.approve_with_signature(1, maintainer, 0, signed(1, maintainer)),
+                Err(WorkflowError::SelfApprovalForbidden)
+            );
+            assert_eq!(contract.approve_with_signature(2, bobby, 0, signed(2, bobby)), Ok(()));
+        }
+
+        #[ink::test]
+        fn low_funds_are_reported() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(2u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+            assert_eq!(
+                contract.set_low_funds_threshold(3),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_low_funds_threshold(3), Ok(()));
+            assert_eq!(contract.get_low_funds_threshold(), 3);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let event = decode_events(emitted_events).pop().expect("an event");
+            if let Event::LowFunds(LowFunds { outstanding, balance }) = event {
+                assert_eq!(outstanding, 1);
+                assert_eq!(balance, 2);
+            } else {
+                panic!("encountered unexpected event kind: expected a LowFunds event")
+            }
+
+            // No warning above the threshold
+            assert_eq!(contract.set_low_funds_threshold(1), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let event = decode_events(emitted_events).pop().expect("an event");
+            assert!(!matches!(event, Event::LowFunds(_)));
+        }
+
+        #[ink::test]
+        fn solvency_views_work() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+
+            assert_eq!(contract.get_outstanding_obligations(), Ok(1));
+            assert_eq!(contract.get_free_balance(), Ok(1));
+            assert_eq!(contract.get_free_balance(), contract.get_available_balance());
+            assert_eq!(contract.is_solvent(), Ok(true));
+
+            // The balance doesn't cover the reserved reward anymore
+            set_balance(contract_id(), 0);
+            assert_eq!(contract.get_free_balance(), Ok(0));
+            assert_eq!(contract.is_solvent(), Ok(false));
+        }
+
+        #[ink::test]
+        fn export_contributions_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(2u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(contract.approve(2, identity), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+
+            assert_eq!(
+                contract.export_contributions(0, 10),
+                vec![
+                    (1, STATUS_CLAIMED, accounts.bob, 1, ASSET_CODE_NATIVE, 0, 1),
+                    (2, STATUS_CLAIMABLE, accounts.bob, 1, ASSET_CODE_NATIVE, 1, 0),
+                ]
+            );
+            assert_eq!(contract.export_contributions(1, 1).len(), 1);
+        }
+
+        #[ink::test]
+        fn identities_are_enumerable() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            let charlie = SingleToken::hash("charlie".as_bytes());
+            let django = SingleToken::hash("django".as_bytes());
+            for (account, identity) in [(accounts.bob, bobby), (accounts.charlie, charlie), (accounts.django, django)] {
+                set_next_caller(account);
+                assert_eq!(contract.register_identity(identity), Ok(()));
+            }
+            assert_eq!(contract.get_identity_count(), 3);
+            assert_eq!(contract.get_identities(0, 2), vec![(bobby, accounts.bob), (charlie, accounts.charlie)]);
+            assert_eq!(contract.get_identities(2, 2), vec![(django, accounts.django)]);
+
+            // The last identity takes the place of the unregistered one
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.unregister_identity(bobby), Ok(()));
+            assert_eq!(contract.get_identity_count(), 2);
+            assert_eq!(contract.get_identities(0, 10), vec![(django, accounts.django), (charlie, accounts.charlie)]);
+
+            set_next_caller(accounts.django);
+            assert_eq!(contract.unregister_identity(django), Ok(()));
+            assert_eq!(contract.get_identities(0, 10), vec![(charlie, accounts.charlie)]);
+        }
+
+        #[ink::test]
+        fn keeper_sweep_executes_ready_queued_items() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::KeeperBounty(1));
+            assert_eq!(contract.queue_change(ParameterChange::ClaimCooldown(5)), Ok(()));
+            assert_eq!(contract.queue_change(ParameterChange::Reward(2)), Ok(()));
+            assert_eq!(contract.queue_identity_unbinding(identity), Ok(()));
+            assert_eq!(contract.cancel_change(Parameter::ClaimCooldown), Ok(()));
+
+            // Nothing to process before the change delay
+            set_next_caller(accounts.django);
+            assert_eq!(contract.keeper_sweep(), Ok(0));
+            assert_eq!(contract.get_reward(), 1);
+
+            set_block_timestamp(DEFAULT_CHANGE_DELAY);
+            assert_eq!(contract.keeper_sweep(), Ok(2));
+            assert_eq!(contract.get_reward(), 2);
+            let updated_by = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>())
+                .into_iter()
+                .find_map(|event| match event {
+                    Event::RewardUpdated(RewardUpdated { account, new_reward: 2, .. }) => Some(account),
+                    _ => None,
+                });
+            assert_eq!(updated_by, Some(accounts.alice));
+            assert_eq!(contract.get_claim_cooldown(), 0);
+            assert_eq!(contract.get_pending_change(Parameter::Reward), None);
+            assert_eq!(contract.get_account(identity), None);
+            assert_eq!(contract.get_unbinding_eta(identity), None);
+            assert_eq!(get_balance(accounts.django), 2);
+            assert_eq!(contract.keeper_sweep(), Ok(0));
+        }
+
+        #[ink::test]
+        fn keeper_sweep_prunes_removed_contributions() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::KeeperBounty(1));
+            apply_change(&mut contract, ParameterChange::ClaimWindow(1));
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.approve(2, identity), Ok(()));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(contract.expire(1), Ok(()));
+
+            // The entry of the expired contribution is pruned without bounty
+            set_next_caller(accounts.django);
+            assert_eq!(contract.keeper_sweep(), Ok(2));
+            assert_eq!(contract.sweep_index_len, 0);
+            assert_eq!(get_balance(accounts.django), 1);
+            assert_eq!(contract.keeper_sweep(), Ok(0));
+
+            // The claimed contributions are pruned as well, the listing being kept
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(3, identity), Ok(()));
+            assert_eq!(contract.approve(4, identity), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(3), Ok(()));
+            set_next_caller(accounts.django);
+            assert_eq!(contract.keeper_sweep(), Ok(1));
+            assert_eq!(contract.sweep_index_len, 1);
+            assert_eq!(contract.sweep_index.get(0), Some(4));
+            assert_eq!(contract.get_contributions(0, 10).len(), 2);
+        }
+
+        #[ink::test]
+        fn keeper_sweep_expires_and_pays_bounty() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+            assert_eq!(
+                contract.queue_change(ParameterChange::KeeperBounty(1)),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::KeeperBounty(1));
+            apply_change(&mut contract, ParameterChange::ClaimWindow(1));
+            assert_eq!(contract.approve(1, identity), Ok(()));
+
+            // Nothing to process before the claim deadline
+            set_next_caller(accounts.django);
+            assert_eq!(contract.keeper_sweep(), Ok(0));
+            assert_eq!(get_balance(accounts.django), 0);
+
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            assert_eq!(contract.keeper_sweep(), Ok(1));
+            assert_eq!(contract.get_contribution(1), None);
+            assert_eq!(get_balance(accounts.django), 1);
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let event = decode_events(emitted_events).pop().expect("an event");
+            if let Event::KeeperSwept(KeeperSwept { keeper, processed, bounty }) = event {
+                assert_eq!(keeper, accounts.django);
+                assert_eq!(processed, 1);
+                assert_eq!(bounty, 1);
+            } else {
+                panic!("encountered unexpected event kind: expected a KeeperSwept event")
+            }
+
+            assert_eq!(contract.keeper_sweep(), Ok(0));
+
+            // The bounty of an expiration is capped to the released reward
+            set_balance(contract_id(), 10);
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::KeeperBounty(5));
+            assert_eq!(contract.approve(2, identity), Ok(()));
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            set_next_caller(accounts.django);
+            assert_eq!(contract.keeper_sweep(), Ok(1));
+            assert_eq!(get_balance(accounts.django), 1 + 1);
+        }
+
+        #[ink::test]
+        fn contributions_are_paginated() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(3u128);
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            let charlie = SingleToken::hash("charlie".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(bobby), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.register_identity(charlie), Ok(()));
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(3, bobby), Ok(()));
+            assert_eq!(contract.approve(1, charlie), Ok(()));
+            assert_eq!(contract.approve(2, bobby), Ok(()));
+
+            let ids = |contributions: Vec<Contribution>| contributions.iter().map(|contribution| contribution.id).collect::<Vec<_>>();
+            assert_eq!(ids(contract.get_contributions(0, 2)), vec![3, 1]);
+            assert_eq!(ids(contract.get_contributions(2, 2)), vec![2]);
+            assert_eq!(ids(contract.get_contributions(3, 2)), Vec::<u64>::new());
+            assert_eq!(ids(contract.get_contributions_by_contributor(accounts.bob, 0, 10)), vec![3, 2]);
+            assert_eq!(ids(contract.get_contributions_by_contributor(accounts.bob, 1, 10)), vec![2]);
+            assert_eq!(ids(contract.get_contributions_by_contributor(accounts.charlie, 0, 10)), vec![1]);
+            assert_eq!(ids(contract.get_contributions_by_contributor(accounts.django, 0, 10)), Vec::<u64>::new());
+        }
+
+        #[ink::test]
+        fn approve_with_metadata_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            let metadata = SingleToken::hash("https://github.com/kudos-ink/contracts/pull/1".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(identity), Ok(()));
+            assert_eq!(
+                contract.approve_with_metadata(1, identity, metadata),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve_with_metadata(1, identity, metadata), Ok(()));
+            assert_eq!(contract.get_contribution(1).map(|contribution| contribution.metadata), Some(Some(metadata)));
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let event = decode_events(emitted_events).pop().expect("an event");
+            if let Event::ContributionApproval(ContributionApproval { id, metadata: approved, .. }) = event {
+                assert_eq!(id, 1);
+                assert_eq!(approved, Some(metadata));
+            } else {
+                panic!("encountered unexpected event kind: expected a ContributionApproval event")
+            }
+        }
+
+        #[ink::test]
+        fn account_binds_one_identity_unless_allowed() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            let bobby_org = SingleToken::hash("bobby-org".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(bobby), Ok(()));
+            assert_eq!(contract.get_identity_of(accounts.bob), Some(bobby));
+            assert_eq!(
+                contract.register_identity(bobby_org),
+                Err(WorkflowError::AccountAlreadyBound)
+            );
+            assert_eq!(
+                contract.set_multiple_identities(true),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.charlie);
+            let charlie = SingleToken::hash("charlie".as_bytes());
+            assert_eq!(contract.register_identity(charlie), Ok(()));
+            assert_eq!(
+                contract.rotate_identity(charlie, accounts.bob),
+                Err(WorkflowError::AccountAlreadyBound)
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_multiple_identities(true), Ok(()));
+            assert!(contract.is_multiple_identities());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(bobby_org), Ok(()));
+            assert_eq!(contract.get_account(bobby_org), Some(accounts.bob));
+
+            // Unregistering an older identity keeps the latest one bound
+            assert_eq!(contract.unregister_identity(bobby), Ok(()));
+            assert_eq!(contract.get_identity_of(accounts.bob), Some(bobby_org));
+        }
+
+        #[ink::test]
+        fn owner_unregisters_identity_after_delay() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_handle(String::from("Bobby"), Vec::new()), Ok(()));
+            let identity = SingleToken::hash("Bobby".as_bytes());
+            assert_eq!(
+                contract.queue_identity_unbinding(identity),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.force_unregister_identity(identity),
+                Err(WorkflowError::ChangeNotQueued)
+            );
+            assert_eq!(contract.queue_identity_unbinding(identity), Ok(()));
+            assert_eq!(contract.get_unbinding_eta(identity), Some(DEFAULT_CHANGE_DELAY));
+            assert_eq!(
+                contract.force_unregister_identity(identity),
+                Err(WorkflowError::ChangeNotReady)
+            );
+
+            set_block_timestamp(DEFAULT_CHANGE_DELAY);
+            assert_eq!(contract.force_unregister_identity(identity), Ok(()));
+            assert_eq!(contract.get_account(identity), None);
+            assert_eq!(contract.get_unbinding_eta(identity), None);
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let event = decode_events(emitted_events).pop().expect("an event");
+            if let Event::IdentityUnregistered(IdentityUnregistered { identity: unregistered, account }) = event {
+                assert_eq!(unregistered, identity);
+                assert_eq!(account, accounts.bob);
+            } else {
+                panic!("encountered unexpected event kind: expected a IdentityUnregistered event")
+            }
+
+            // The handle is released, even in another case
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.register_handle(String::from("bobby"), Vec::new()), Ok(()));
+
+            // The unbindings follow the configured delay
+            set_next_caller(accounts.alice);
+            let delay = 2 * DEFAULT_CHANGE_DELAY;
+            let identity = SingleToken::hash("bobby".as_bytes());
+            assert_eq!(contract.set_change_delay(Parameter::IdentityUnbinding, delay), Ok(()));
+            assert_eq!(contract.queue_identity_unbinding(identity), Ok(()));
+            assert_eq!(contract.get_unbinding_eta(identity), Some(DEFAULT_CHANGE_DELAY + delay));
+        }
+
+        #[ink::test]
+        fn platform_identities_do_not_collide() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let id_hash = SingleToken::hash("bobby".as_bytes());
+            let github = Identity { platform: Platform::GitHub, id_hash };
+            let gitlab = Identity { platform: Platform::GitLab, id_hash };
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_platform_identity(github, Vec::new()), Ok(()));
+            assert_eq!(contract.get_account(id_hash), Some(accounts.bob));
+
+            // The same ID on another platform is another identity
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.register_platform_identity(gitlab, Vec::new()), Ok(()));
+            assert_eq!(
+                contract.register_platform_identity(gitlab, Vec::new()),
+                Err(WorkflowError::IdentityAlreadyRegistered)
+            );
+            let gitlab_key = SingleToken::identity_key(&gitlab);
+            assert_ne!(gitlab_key, id_hash);
+            assert_eq!(contract.get_account(gitlab_key), Some(accounts.charlie));
+            assert_eq!(contract.get_platform(gitlab_key), Platform::GitLab);
+            assert_eq!(contract.get_platform(id_hash), Platform::GitHub);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve_platform(1, gitlab), Ok(()));
+            assert_eq!(contract.get_contribution(1).map(|c| c.contributor), Some(accounts.charlie));
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let event = decode_events(emitted_events).pop().expect("an event");
+            if let Event::ContributionApproval(ContributionApproval { id, platform, .. }) = event {
+                assert_eq!(id, 1);
+                assert_eq!(platform, Platform::GitLab);
+            } else {
+                panic!("encountered unexpected event kind: expected a ContributionApproval event")
+            }
+        }
+
+        #[ink::test]
+        fn insurance_is_set_through_the_queue() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let insurance = InsuranceConfig { pool: accounts.django, premium_rate: BASIS_POINTS };
+            assert_eq!(
+                contract.queue_change(ParameterChange::Insurance(Some(InsuranceConfig { premium_rate: BASIS_POINTS + 1, ..insurance }))),
+                Err(WorkflowError::InvalidShare)
+            );
+            apply_change(&mut contract, ParameterChange::Insurance(Some(insurance)));
+            assert_eq!(contract.get_insurance(), Some(insurance));
+
+            // The premium (i.e. the whole reward) is paid out of the free funds
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            set_balance(contract_id(), 1);
+            assert_eq!(contract.premium_of(1_000), Ok(1_000));
+            assert_eq!(contract.approve(1, identity), Err(WorkflowError::InsufficientFunds));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.queue_change(ParameterChange::Insurance(None)),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+        }
+
+        #[ink::test]
+        fn insurance_takes_over_failed_payout() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
+            let _ = contract.approve(2, identity);
+
+            // The payout of the first contribution failed, the second one is paid normally
+            assert_eq!(contract.credit_payout(accounts.bob, 1), Ok(()));
+            assert_eq!(contract.record_failed_payout(1, 1), Ok(()));
+            assert_eq!(contract.get_failed_payout(1), 1);
+            assert_eq!(contract.get_failed_payout(2), 0);
+
+            let insurance = InsuranceConfig { pool: accounts.django, premium_rate: 0 };
+            apply_change(&mut contract, ParameterChange::Insurance(Some(insurance)));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.take_over_failed_payout(1, 1), Err(WorkflowError::CallerIsNotInsurance));
+
+            // The paid contribution is not compensable, the failed one only once
+            set_next_caller(accounts.django);
+            assert_eq!(contract.take_over_failed_payout(2, 1), Ok(0));
+            assert_eq!(contract.take_over_failed_payout(1, 1), Ok(1));
+            assert_eq!(contract.take_over_failed_payout(1, 1), Ok(0));
+            assert_eq!(contract.get_failed_payout(1), 0);
+            assert_eq!(contract.get_escrow(Asset::Native), Ok(contract.get_reserved()));
+
+            // The contributor cannot withdraw the compensated payout anymore
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.get_pending_payout(accounts.bob, Asset::Native), 0);
+            assert_eq!(contract.withdraw_pending(Asset::Native), Err(WorkflowError::NoPendingPayout));
+        }
+
+        #[ink::test]
+        fn queue_invalid_first_contribution_multiplier_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            assert_eq!(contract.get_first_contribution_multiplier(), BASIS_POINTS);
+            assert_eq!(
+                contract.queue_change(ParameterChange::FirstContributionMultiplier(BASIS_POINTS - 1)),
+                Err(WorkflowError::InvalidMultiplier)
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.queue_change(ParameterChange::FirstContributionMultiplier(20_000)),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+        }
+
+        #[ink::test]
+        fn queued_change_is_executed_after_delay() {
+            let mut contract = create_contract(1u128);
+            let change = ParameterChange::FirstContributionMultiplier(20_000);
+            set_block_timestamp(1_000);
+            assert_eq!(contract.queue_change(change.clone()), Ok(()));
+            assert_eq!(
+                contract.queue_change(change.clone()),
+                Err(WorkflowError::ChangeAlreadyQueued)
+            );
+            assert_eq!(
+                contract.get_pending_change(Parameter::FirstContributionMultiplier),
+                Some(PendingChange {
+                    change: change.clone(),
+                    eta: 1_000 + DEFAULT_CHANGE_DELAY,
+                    account: default_accounts().alice,
+                })
+            );
+
+            set_block_timestamp(DEFAULT_CHANGE_DELAY);
+            assert_eq!(
+                contract.execute_change(Parameter::FirstContributionMultiplier),
+                Err(WorkflowError::ChangeNotReady)
+            );
+            assert_eq!(contract.get_first_contribution_multiplier(), BASIS_POINTS);
+
+            set_block_timestamp(1_000 + DEFAULT_CHANGE_DELAY);
+            assert_eq!(
+                contract.execute_change(Parameter::FirstContributionMultiplier),
+                Ok(())
+            );
+            assert_eq!(contract.get_first_contribution_multiplier(), 20_000);
+            assert_eq!(
+                contract.get_pending_change(Parameter::FirstContributionMultiplier),
+                None
+            );
+
+            // Validate `ChangeExecuted` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            if let Some(Event::ChangeExecuted(ChangeExecuted { change: executed })) = decoded_events.last() {
+                assert_eq!(*executed, change);
+            } else {
+                panic!("encountered unexpected event kind: expected a ChangeExecuted event")
+            }
+        }
+
+        #[ink::test]
+        fn cancelled_change_cannot_be_executed() {
+            let mut contract = create_contract(1u128);
+            let platform_key = Some(signer_account(PLATFORM_SECRET));
+            assert_eq!(
+                contract.queue_change(ParameterChange::PlatformKey(platform_key)),
+                Ok(())
+            );
+            assert_eq!(contract.cancel_change(Parameter::PlatformKey), Ok(()));
+            assert_eq!(
+                contract.cancel_change(Parameter::PlatformKey),
+                Err(WorkflowError::ChangeNotQueued)
+            );
+
+            set_block_timestamp(DEFAULT_CHANGE_DELAY);
+            assert_eq!(
+                contract.execute_change(Parameter::PlatformKey),
+                Err(WorkflowError::ChangeNotQueued)
+            );
+            assert_eq!(contract.get_platform_key(), None);
+        }
+
+        #[ink::test]
+        fn change_delay_cannot_be_lowered() {
+            let mut contract = create_contract(1u128);
+            let delay = 2 * DEFAULT_CHANGE_DELAY;
+            assert_eq!(contract.set_change_delay(Parameter::PlatformKey, delay), Ok(()));
+            assert_eq!(contract.get_change_delay(Parameter::PlatformKey), delay);
+            assert_eq!(
+                contract.get_change_delay(Parameter::FirstContributionMultiplier),
+                DEFAULT_CHANGE_DELAY
+            );
+            assert_eq!(
+                contract.set_change_delay(Parameter::PlatformKey, DEFAULT_CHANGE_DELAY),
+                Err(WorkflowError::InvalidDelay)
+            );
+        }
+
+        #[ink::test]
+        fn multiplied_reward_overflow_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            set_balance(accounts.charlie, Balance::MAX);
+            set_balance(contract_id(), 0);
+            set_reward(&mut contract, Balance::MAX - 1);
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                ink::env::pay_with_call!(contract.fund(Vec::new()), Balance::MAX - 1),
+                Ok(())
+            );
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::FirstContributionMultiplier(2 * BASIS_POINTS));
+            assert_eq!(contract.approve(1u64, identity), Err(WorkflowError::Overflow));
+        }
+
+        #[ink::test]
+        fn deposit_overflow_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            set_balance(accounts.charlie, Balance::MAX);
+            set_balance(contract_id(), 0);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                ink::env::pay_with_call!(contract.fund(Vec::new()), Balance::MAX),
+                Err(WorkflowError::Overflow)
+            );
+            assert_eq!(contract.get_deposit(accounts.charlie), 0);
+        }
+
+        #[ink::test]
+        fn refunds_do_not_overflow_with_large_balances() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let large_deposit = 10u128.pow(30);
+            set_balance(accounts.charlie, large_deposit);
+            set_balance(contract_id(), 0);
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), large_deposit);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.terminate();
+            assert_eq!(contract.process_refunds(2), Ok(0));
+            // The owner deposit of 1 is worth less than one unit of the pool, the rounding
+            // remainder going to the last funder.
+            assert_eq!(get_balance(accounts.charlie), large_deposit);
+        }
+
+        #[ink::test]
+        fn approve_with_closure_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::OracleKey(Some(signer_account(ORACLE_SECRET))));
+            assert_eq!(
+                contract.approve(1u64, identity),
+                Err(WorkflowError::ClosureProofRequired)
+            );
+
+            let statement = ClosureStatement {
+                contract: contract_id(),
+                contribution_id: 1u64,
+                state: IssueState::ClosedAsCompleted,
+            };
+            let statement_hash = SingleToken::hash_payload(&statement);
+            let signature = sign(ORACLE_SECRET, &statement_hash);
+            assert_eq!(
+                contract.approve_with_closure(1u64, identity, statement, signature),
+                Ok(())
+            );
+            assert_eq!(contract.get_closure_statement(1u64), Some(statement_hash));
+            assert!(contract.get_contribution(1u64).is_some());
+        }
+
+        #[ink::test]
+        fn approve_with_signature_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.add_signer(signer_account(WORKFLOW_SECRET)), Ok(()));
+
+            let signed = |secret, contribution_id, nonce| {
+                sign(
+                    secret,
+                    &SingleToken::hash_payload(&ApprovalPayload {
+                        contract: contract_id(),
+                        contribution_id,
+                        contributor_identity: identity,
+                        nonce,
+                    }),
+                )
+            };
+
+            // Anyone can relay a signed approval
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                contract.approve_with_signature(1u64, identity, 7, signed(OTHER_SECRET, 1u64, 7)),
+                Err(WorkflowError::InvalidSigner)
+            );
+            assert_eq!(
+                contract.approve_with_signature(1u64, identity, 8, signed(WORKFLOW_SECRET, 1u64, 7)),
+                Err(WorkflowError::InvalidSigner)
+            );
+            assert_eq!(
+                contract.approve_with_signature(1u64, identity, 7, signed(WORKFLOW_SECRET, 1u64, 7)),
+                Ok(())
+            );
+            assert!(contract.get_contribution(1u64).is_some());
+            assert_eq!(
+                contract.approve_with_signature(1u64, identity, 7, signed(WORKFLOW_SECRET, 1u64, 7)),
+                Err(WorkflowError::SignatureAlreadyUsed)
+            );
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::OracleKey(Some(signer_account(ORACLE_SECRET))));
+            assert_eq!(
+                contract.approve_with_signature(2u64, identity, 7, signed(WORKFLOW_SECRET, 2u64, 7)),
+                Err(WorkflowError::ClosureProofRequired)
+            );
+        }
+
+        #[ink::test]
+        fn signers_can_be_added_removed_and_rotated() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let workflow = signer_account(WORKFLOW_SECRET);
+            let other = signer_account(OTHER_SECRET);
+            assert!(!contract.is_signer(workflow));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.add_signer(workflow),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.add_signer(workflow), Ok(()));
+            assert_eq!(contract.add_signer(workflow), Err(WorkflowError::InvalidSigner));
+            assert!(contract.is_signer(workflow));
+
+            assert_eq!(contract.rotate_signer(other, workflow), Err(WorkflowError::InvalidSigner));
+            assert_eq!(contract.rotate_signer(workflow, other), Ok(()));
+            assert!(!contract.is_signer(workflow));
+            assert!(contract.is_signer(other));
+
+            assert_eq!(contract.remove_signer(workflow), Err(WorkflowError::InvalidSigner));
+            assert_eq!(contract.remove_signer(other), Ok(()));
+            assert!(!contract.is_signer(other));
+
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let events = decode_events(emitted_events);
+            let n = events.len();
+            assert!(matches!(events[n - 3], Event::SignerAdded(SignerAdded { signer }) if signer == workflow));
+            assert!(matches!(
+                events[n - 2],
+                Event::SignerRotated(SignerRotated { old_signer, new_signer }) if old_signer == workflow && new_signer == other
+            ));
+            assert!(matches!(events[n - 1], Event::SignerRemoved(SignerRemoved { signer }) if signer == other));
+        }
+
+        #[ink::test]
+        fn approve_with_metrics_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::OracleKey(Some(signer_account(ORACLE_SECRET))));
+            let statement = |contribution_id, metrics| MetricsStatement {
+                contract: contract_id(),
+                contribution_id,
+                metrics,
+            };
+            let signed = |statement: &MetricsStatement| sign(ORACLE_SECRET, &SingleToken::hash_payload(statement));
+            let reviewed = statement(1u64, vec![120, 3]);
+            assert_eq!(
+                contract.approve_with_metrics(1u64, identity, reviewed.clone(), signed(&reviewed)),
+                Err(WorkflowError::PayoutFormulaNotSet)
+            );
+
+            assert_eq!(
+                contract.set_payout_formula(Some(PayoutFormula { base: 10, coefficients: vec![1, 101], max_reward: 100 })),
+                Err(WorkflowError::InvalidPayoutFormula)
+            );
+            let formula = PayoutFormula { base: 10, coefficients: vec![1, 20], max_reward: 200 };
+            assert_eq!(contract.set_payout_formula(Some(formula.clone())), Ok(()));
+            assert_eq!(contract.get_payout_formula(), Some(formula));
+
+            let wrong = statement(1u64, vec![120]);
+            assert_eq!(
+                contract.approve_with_metrics(1u64, identity, wrong.clone(), signed(&wrong)),
+                Err(WorkflowError::InvalidMetricsStatement)
+            );
+            assert_eq!(
+                contract.approve_with_metrics(1u64, identity, reviewed.clone(), sign(PLATFORM_SECRET, &[0; 32])),
+                Err(WorkflowError::InvalidSignature)
+            );
+            assert_eq!(
+                contract.approve_with_metrics(1u64, identity, reviewed.clone(), signed(&reviewed)),
+                Ok(())
+            );
+            assert_eq!(contract.get_formula_reward(1u64), Some(190));
+
+            // Re-sending another statement does not change the reward of the approved contribution
+            let reservation = contract.get_reservation(1u64);
+            let resent = statement(1u64, vec![0, 0]);
+            assert_eq!(
+                contract.approve_with_metrics(1u64, identity, resent.clone(), signed(&resent)),
+                Ok(())
+            );
+            assert_eq!(contract.get_formula_reward(1u64), Some(190));
+            assert_eq!(contract.get_reservation(1u64), reservation);
+
+            // The reward is capped
+            let large = statement(2u64, vec![1000, 0]);
+            assert_eq!(
+                contract.approve_with_metrics(2u64, identity, large.clone(), signed(&large)),
+                Ok(())
+            );
+            assert_eq!(contract.get_formula_reward(2u64), Some(200));
+
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(2u64), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 200);
+        }
+
+        #[ink::test]
+        fn approve_with_invalid_closure_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            let open = ClosureStatement {
+                contract: contract_id(),
+                contribution_id: 1u64,
+                state: IssueState::Open,
+            };
+            let signature = sign(ORACLE_SECRET, &SingleToken::hash_payload(&open));
+            assert_eq!(
+                contract.approve_with_closure(1u64, identity, open, signature),
+                Err(WorkflowError::OracleKeyNotSet)
+            );
+
+            apply_change(&mut contract, ParameterChange::OracleKey(Some(signer_account(ORACLE_SECRET))));
+            assert_eq!(
+                contract.approve_with_closure(1u64, identity, open, signature),
+                Err(WorkflowError::IssueNotCompleted)
+            );
+            assert_eq!(
+                contract.approve_with_closure(2u64, identity, open, signature),
+                Err(WorkflowError::InvalidClosureStatement)
+            );
+
+            let completed = ClosureStatement {
+                state: IssueState::ClosedAsCompleted,
+                ..open
+            };
+            let signature = sign(OTHER_SECRET, &SingleToken::hash_payload(&completed));
+            assert_eq!(
+                contract.approve_with_closure(1u64, identity, completed, signature),
+                Err(WorkflowError::InvalidSignature)
+            );
+            assert_eq!(contract.get_contribution(1u64), None);
+        }
+
+        #[ink::test]
+        fn expired_identity_must_be_reattested() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::IdentityValidity(Some(DEFAULT_CHANGE_DELAY)));
+            set_block_timestamp(DEFAULT_CHANGE_DELAY);
+            assert!(contract.identity_is_expired(identity));
+            assert_eq!(
+                contract.approve(1u64, identity),
+                Err(WorkflowError::IdentityExpired)
+            );
+
+            assert_eq!(
+                contract.reattest(identity, None),
+                Err(WorkflowError::CallerIsNotContributor)
+            );
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.reattest(identity, None), Ok(()));
+            assert!(!contract.identity_is_expired(identity));
+            assert_eq!(
+                contract.get_attestation(identity),
+                Some(Attestation {
+                    attested_at: DEFAULT_CHANGE_DELAY,
+                    nonce: 1
+                })
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1u64, identity), Ok(()));
+        }
+
+        #[ink::test]
+        fn reattest_requires_platform_signature() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::PlatformKey(Some(signer_account(PLATFORM_SECRET))));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.reattest(identity, None),
+                Err(WorkflowError::SignatureRequired)
+            );
+            let payload = AttestationPayload {
+                contract: contract_id(),
+                identity,
+                account: accounts.bob,
+                nonce: 0,
+            };
+            let signature = sign(PLATFORM_SECRET, &SingleToken::hash_payload(&payload));
+            assert_eq!(contract.reattest(identity, Some(signature)), Ok(()));
+
+            // The signature cannot be replayed
+            assert_eq!(
+                contract.reattest(identity, Some(signature)),
+                Err(WorkflowError::InvalidSignature)
+            );
+        }
+
+        #[ink::test]
+        fn first_claim_bonus_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(100u128);
+            set_reward(&mut contract, 10);
+            assert_eq!(contract.set_first_claim_bonus(10_001), Err(WorkflowError::InvalidShare));
+            assert_eq!(contract.set_first_claim_bonus(5_000), Ok(()));
+            assert_eq!(contract.get_first_claim_bonus(), 5_000);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            assert_eq!(
+                contract.set_first_claim_bonus(0),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
+            let _ = contract.approve(2, identity);
+            let _ = contract.approve(3, identity);
+
+            // Only the first claim of the identity earns the bonus
+            set_balance(contract_id(), 100);
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(contract.claim(2), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 15 + 10);
+
+            // The bonus is capped to the free balance, not paid out of the other reservations
+            let identity = SingleToken::hash("charly".as_bytes());
+            set_next_caller(accounts.charlie);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(4, identity);
+            set_balance(contract_id(), 22);
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.claim(4), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 12);
+            assert_eq!(contract.get_reserved(), 10);
+
+            // The bonus only tops up the first contribution multiplier
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::FirstContributionMultiplier(12_000));
+            let identity = SingleToken::hash("django".as_bytes());
+            set_next_caller(accounts.django);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            set_balance(contract_id(), 100);
+            assert_eq!(contract.approve(5, identity), Ok(()));
+            assert_eq!(contract.get_reservation(5), 12);
+            set_next_caller(accounts.django);
+            assert_eq!(contract.claim(5), Ok(()));
+            assert_eq!(get_balance(accounts.django), 15);
+
+            let bonuses = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>())
+                .into_iter()
+                .filter_map(|event| match event {
+                    Event::RewardClaimed(RewardClaimed { reward, bonus, .. }) => Some((reward, bonus)),
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(bonuses, vec![(15, 5), (10, 0), (12, 2), (15, 3)]);
+        }
+
+        #[ink::test]
+        fn first_claim_bonus_is_earned_once_per_person() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(100u128);
+            set_reward(&mut contract, 10);
+            assert_eq!(contract.set_first_claim_bonus(5_000), Ok(()));
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            let bobby_alt = SingleToken::hash("bobby-alt".as_bytes());
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(bobby), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.register_identity(bobby_alt), Ok(()));
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.link_identity(bobby_alt, Some(bobby)), Ok(()));
+            assert_eq!(contract.approve(1, bobby), Ok(()));
+            assert_eq!(contract.approve(2, bobby_alt), Ok(()));
+
+            // The linked identity has never claimed, but its person already did
+            set_balance(contract_id(), 100);
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.claim(2), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 10);
+        }
+
+        #[ink::test]
+        fn escrow_follows_the_approved_rewards() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(100u128);
+            set_reward(&mut contract, 10);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.set_allowed_asset(Asset::Psp22(accounts.django), true);
+            let basket = vec![
+                BasketShare {
+                    asset: Asset::Native,
+                    share: 4_000,
+                },
+                BasketShare {
+                    asset: Asset::Psp22(accounts.django),
+                    share: 6_000,
+                },
+            ];
+            apply_change(&mut contract, ParameterChange::Basket(basket));
+            let hard = SingleToken::hash("hard".as_bytes());
+            apply_change(&mut contract, ParameterChange::DifficultyReward(hard, Some(RewardAmount::from_plancks(40))));
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.approve_with_difficulty(2, identity, hard), Ok(()));
+            assert_eq!(contract.get_escrow(Asset::Psp22(accounts.django)), Ok(6 + 24));
+
+            // The escrow is not affected by a later reward change, and released upon claim
+            set_reward(&mut contract, 20);
+            assert_eq!(contract.get_escrow(Asset::Psp22(accounts.django)), Ok(30));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_with_ratio(2, 10_000), Ok(()));
+            assert_eq!(contract.get_escrow(Asset::Psp22(accounts.django)), Ok(6));
+        }
+
+        #[ink::test]
+        fn claim_with_ratio_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(100u128);
+            set_reward(&mut contract, 10);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.set_allowed_asset(Asset::Psp22(accounts.django), true);
+            let basket = vec![
+                BasketShare {
+                    asset: Asset::Native,
+                    share: 4_000,
+                },
+                BasketShare {
+                    asset: Asset::Psp22(accounts.django),
+                    share: 6_000,
+                },
+            ];
+            apply_change(&mut contract, ParameterChange::Basket(basket));
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.approve(2, identity), Ok(()));
+            assert_eq!(contract.get_reservation(1), 4);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_with_ratio(1, 10_001), Err(WorkflowError::InvalidPayoutRatio));
+            // More tokens than escrowed for the contribution
+            assert_eq!(contract.claim_with_ratio(1, 3_000), Err(WorkflowError::InsufficientFunds));
+            // More native than reserved and free
+            set_balance(contract_id(), 9);
+            assert_eq!(contract.claim_with_ratio(1, 10_000), Err(WorkflowError::InsufficientFunds));
+
+            // The whole reward is paid in native currency, the token part staying in the pool
+            set_balance(contract_id(), 20);
+            let bob_initial_balance = get_balance(accounts.bob);
+            assert_eq!(contract.claim_with_ratio(1, 10_000), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 10);
+            assert_eq!(contract.get_reserved(), 4);
+            assert_eq!(contract.get_escrow(Asset::Psp22(accounts.django)), Ok(6));
+            assert_eq!(contract.claim_with_ratio(1, 10_000), Err(WorkflowError::AlreadyClaimed));
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::Basket(Vec::new()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_with_ratio(2, 10_000), Err(WorkflowError::InvalidBasket));
+        }
+
+        #[ink::test]
+        fn basket_is_set_through_the_queue() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let basket = vec![
+                BasketShare {
+                    asset: Asset::Psp22(accounts.django),
+                    share: 7_000,
+                },
+                BasketShare {
+                    asset: Asset::Native,
+                    share: 3_000,
+                },
+            ];
+            assert_eq!(contract.queue_change(ParameterChange::Basket(basket.clone())), Err(WorkflowError::AssetNotAllowed));
+            assert_eq!(contract.set_allowed_asset(Asset::Psp22(accounts.django), true), Ok(()));
+            apply_change(&mut contract, ParameterChange::Basket(basket.clone()));
+            assert_eq!(contract.get_basket(), basket);
+            assert_eq!(
+                contract.basket_payouts(11),
+                Ok(vec![(Asset::Psp22(accounts.django), 7), (Asset::Native, 4)])
+            );
+
+            assert_eq!(
+                contract.queue_change(ParameterChange::Basket(vec![BasketShare {
+                    asset: Asset::Native,
+                    share: 9_000,
+                }])),
+                Err(WorkflowError::InvalidBasket)
+            );
+            assert_eq!(
+                contract.queue_change(ParameterChange::Basket(vec![
+                    BasketShare {
+                        asset: Asset::Native,
+                        share: 5_000,
+                    },
+                    BasketShare {
+                        asset: Asset::Native,
+                        share: 5_000,
+                    },
+                ])),
+                Err(WorkflowError::InvalidBasket)
+            );
+
+            // The approvals are blocked once an asset of the basket is disallowed
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            assert_eq!(
+                contract.set_allowed_asset(Asset::Psp22(accounts.django), false),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_allowed_asset(Asset::Psp22(accounts.django), false), Ok(()));
+            assert!(!contract.is_allowed_asset(Asset::Psp22(accounts.django)));
+            assert!(contract.is_allowed_asset(Asset::Native));
+            assert_eq!(contract.approve(1, identity), Err(WorkflowError::AssetNotAllowed));
+
+            apply_change(&mut contract, ParameterChange::Basket(Vec::new()));
+            assert_eq!(contract.approve(1, identity), Ok(()));
+        }
+
+        #[ink::test]
+        fn pending_payout_can_be_withdrawn() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1u64, identity);
+            assert_eq!(contract.get_escrow(Asset::Native), Ok(1));
+
+            // A failed basket payout is recorded as pending
+            assert_eq!(contract.defer_payout(accounts.bob, Asset::Native, 1), Ok(()));
+            assert_eq!(contract.get_pending_payout(accounts.bob, Asset::Native), 1);
+            assert_eq!(contract.get_escrow(Asset::Native), Ok(2));
+
+            set_balance(contract_id(), 2);
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.withdraw_pending(Asset::Native), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 1);
+            assert_eq!(contract.get_pending_payout(accounts.bob, Asset::Native), 0);
+            assert_eq!(contract.get_escrow(Asset::Native), Ok(1));
+            assert_eq!(
+                contract.withdraw_pending(Asset::Native),
+                Err(WorkflowError::NoPendingPayout)
+            );
+        }
+
+        #[ink::test]
+        fn claim_cooldown_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            apply_change(&mut contract, ParameterChange::ClaimCooldown(1_000));
+            let _ = contract.approve(1u64, identity);
+            let _ = contract.approve(2u64, identity);
+            let _ = contract.approve(3u64, identity);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1u64), Ok(()));
+            let last_claim = contract.get_last_claim(identity).expect("claim is recorded");
+            assert_eq!(contract.claim(2u64), Err(WorkflowError::ClaimCooldown));
+            assert_eq!(contract.can_claim(2u64), Err(WorkflowError::ClaimCooldown));
+
+            // The owner can bypass the cooldown for a given contribution
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_cooldown_exemption(2u64, true), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(2u64), Ok(()));
+            assert_eq!(contract.claim(3u64), Err(WorkflowError::ClaimCooldown));
+
+            set_block_timestamp(last_claim + 1_000);
+            assert_eq!(contract.claim(3u64), Ok(()));
+        }
+
+        #[ink::test]
+        fn approve_from_template_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            let template = Template {
+                kind: String::from("release-notes"),
+                tier: 1,
+                expiry: Some(1_000),
+                tags: vec![String::from("docs")],
+            };
+            assert_eq!(contract.create_template(template.clone()), Ok(0));
+            assert_eq!(contract.get_template(0), Some(template));
+            assert_eq!(
+                contract.approve_from_template(1, 1u64, identity),
+                Err(WorkflowError::UnknownTemplate)
+            );
+
+            set_block_timestamp(10);
+            assert_eq!(contract.approve_from_template(0, 1u64, identity), Ok(()));
+            let contribution = contract.get_contribution(1u64).expect("contribution is approved");
+            assert_eq!(contribution.template_id, Some(0));
+            assert_eq!(contribution.expires_at, Some(1_010));
+
+            // Removing the template does not affect approved contributions
+            assert_eq!(contract.remove_template(0), Ok(()));
+            assert_eq!(contract.get_template(0), None);
+
+            set_next_caller(accounts.bob);
+            set_block_timestamp(1_010);
+            assert_eq!(contract.claim(1u64), Err(WorkflowError::ContributionExpired));
+        }
+
+        #[ink::test]
+        fn high_tier_approval_requires_reputation() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            for tier in [0, 2] {
+                let _ = contract.create_template(Template {
+                    kind: String::from("bounty"),
+                    tier,
+                    expiry: None,
+                    tags: Vec::new(),
+                });
+            }
+            assert_eq!(contract.set_tier_min_reputation(2, Some(10)), Ok(()));
+            assert_eq!(contract.get_tier_min_reputation(2), Some(10));
+            assert_eq!(
+                contract.approve_from_template(1, 1, identity),
+                Err(WorkflowError::InsufficientReputation)
+            );
+            assert_eq!(contract.approve_from_template(0, 1, identity), Ok(()));
+
+            assert_eq!(contract.set_tier_min_reputation(2, None), Ok(()));
+            assert_eq!(contract.approve_from_template(1, 2, identity), Ok(()));
+        }
+
+        #[ink::test]
+        fn create_template_with_too_many_tags_fails() {
+            let mut contract = create_contract(1u128);
+            let template = Template {
+                kind: String::from("triage"),
+                tier: 0,
+                expiry: None,
+                tags: vec![String::from("tag"); MAX_TEMPLATE_TAGS + 1],
+            };
+            assert_eq!(
+                contract.create_template(template),
+                Err(WorkflowError::TooManyTags)
+            );
+        }
+
+        #[ink::test]
+        fn only_owner_can_set_reputation() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            assert_eq!(contract.set_reputation(Some(accounts.django)), Ok(()));
+            assert_eq!(contract.get_reputation(), Some(accounts.django));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_reputation(None),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+        }
+
+        #[ink::test]
+        fn only_owner_can_set_faucet() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            assert_eq!(contract.get_faucet(), None);
+            assert_eq!(contract.set_faucet(Some(accounts.django)), Ok(()));
+            assert_eq!(contract.get_faucet(), Some(accounts.django));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.set_faucet(None),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+        }
+
+        #[ink::test]
+        fn receive_records_unattributed_funds() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            set_next_caller(accounts.charlie);
+            // Unknown selectors without value are rejected
+            assert_eq!(
+                ink::env::pay_with_call!(contract.receive(), 0),
+                Err(WorkflowError::UnknownSelector)
+            );
+            assert_eq!(ink::env::pay_with_call!(contract.receive(), 5), Ok(()));
+            assert_eq!(contract.get_unattributed_funds(), 5);
+            assert_eq!(contract.get_deposit(accounts.charlie), 0);
+
+            // Validate `UnattributedFundsReceived` event emition
+            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+            let decoded_events = decode_events(emitted_events);
+            if let Some(Event::UnattributedFundsReceived(UnattributedFundsReceived { from, amount })) = decoded_events.last() {
+                assert_eq!(*from, accounts.charlie);
+                assert_eq!(*amount, 5);
+            } else {
+                panic!("encountered unexpected event kind: expected a UnattributedFundsReceived event")
+            }
+        }
+
+        #[ink::test]
+        fn receive_in_strict_mode_fails() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            assert_eq!(contract.set_strict_funding(true), Ok(()));
+            assert!(contract.is_strict_funding());
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                ink::env::pay_with_call!(contract.receive(), 5),
+                Err(WorkflowError::UntrackedTransfer)
+            );
+            assert_eq!(
+                contract.set_strict_funding(false),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+        }
+
+        const PLATFORM_SECRET: [u8; 32] = [1; 32];
+        const OTHER_SECRET: [u8; 32] = [2; 32];
+        const ORACLE_SECRET: [u8; 32] = [3; 32];
+        const ATTESTOR_SECRET: [u8; 32] = [4; 32];
+        const WORKFLOW_SECRET: [u8; 32] = [5; 32];
+
+        fn signer_account(secret: [u8; 32]) -> AccountId {
+            let secp = secp256k1::Secp256k1::new();
+            let secret_key = secp256k1::SecretKey::from_slice(&secret).expect("valid secret key");
+            let public_key = secp256k1::PublicKey::from_secret_key(&secp, &secret_key).serialize();
+            let mut account = <Blake2x256 as HashOutput>::Type::default();
+            ink::env::hash_bytes::<Blake2x256>(&public_key, &mut account);
+            AccountId::from(account)
+        }
+
+        fn sign(secret: [u8; 32], message_hash: &HashValue) -> SignatureValue {
+            let secp = secp256k1::Secp256k1::new();
+            let secret_key = secp256k1::SecretKey::from_slice(&secret).expect("valid secret key");
+            let message = secp256k1::Message::from_slice(message_hash).expect("valid message");
+            let (recovery_id, compact) = secp
+                .sign_ecdsa_recoverable(&message, &secret_key)
+                .serialize_compact();
+            let mut signature = [0; 65];
+            signature[..64].copy_from_slice(&compact);
+            signature[64] = recovery_id.to_i32() as u8;
+            signature
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let contract = create_contract(1u128);
+            assert!(contract.supports_interface(interface_ids::INTROSPECTION));
+            assert!(contract.supports_interface(interface_ids::WORKFLOW_V1));
+            assert!(!contract.supports_interface(interface_ids::REPUTATION));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn contract_id() -> AccountId {
+            ink::env::test::callee::<ink::env::DefaultEnvironment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        fn set_block_timestamp(timestamp: Timestamp) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        /// Queue the `change` and execute it once its delay has elapsed, then rewind the time.
+        fn apply_change(contract: &mut SingleToken, change: ParameterChange) {
+            let parameter = change.parameter();
+            let now = ink::env::block_timestamp::<ink::env::DefaultEnvironment>();
+            assert_eq!(contract.queue_change(change), Ok(()));
+            let eta = contract.get_pending_change(parameter).unwrap().eta;
+            set_block_timestamp(eta);
+            assert_eq!(contract.execute_change(parameter), Ok(()));
+            set_block_timestamp(now);
+        }
+
+        /// Set the `reward` of the approvals through the timelock queue.
+        fn set_reward(contract: &mut SingleToken, reward: Balance) {
+            apply_change(contract, ParameterChange::Reward(reward));
+        }
+
+        fn set_balance(account_id: AccountId, balance: Balance) {
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account_id, balance)
+        }
+
+        fn get_balance(account: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
+                .expect("Cannot get account balance")
+        }
+
+        /// Creates a new instance of `SingleToken` with `initial_balance`.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract(initial_balance: Balance) -> SingleToken {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            set_balance(contract_id(), initial_balance);
+            let mut single_token = SingleToken::new([0; 32]);
+            set_reward(&mut single_token, 1);
+            let _ = ink::env::pay_with_call!(single_token.fund(Vec::new()), 1);
+            single_token
+        }
+
+        #[ink::test]
+        fn pre_approved_contributions_are_bound_upon_registration() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            let charly = SingleToken::hash("charly".as_bytes());
+            assert_eq!(contract.pre_approve(1, bobby), Ok(()));
+            assert_eq!(contract.pre_approve(2, charly), Ok(()));
+            assert_eq!(contract.pre_approve(3, bobby), Ok(()));
+            assert_eq!(contract.pre_approve(1, charly), Err(WorkflowError::ContributionAlreadyApproved));
+            assert_eq!(contract.approve(1, bobby), Err(WorkflowError::ContributionAlreadyApproved));
+            assert!(contract.is_awaiting_registration(bobby));
+            assert_eq!(contract.list_unbound_approvals(1, 5), vec![(2, charly), (3, bobby)]);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(bobby), Ok(()));
+            assert!(!contract.is_awaiting_registration(bobby));
+            assert_eq!(contract.list_unbound_approvals(0, 5), vec![(2, charly)]);
+            assert_eq!(contract.get_approved_count(), 2);
+            assert_eq!(contract.claim(3), Ok(()));
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.pre_approve(4, bobby), Err(WorkflowError::IdentityAlreadyRegistered));
+        }
+
+        #[ink::test]
+        fn underfunded_pre_approval_does_not_block_registration() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            assert_eq!(contract.pre_approve(1, bobby), Ok(()));
+            set_balance(contract_id(), 0);
+
+            // The pre-approval stays pending until the contract is funded
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.register_identity(bobby), Ok(()));
+            assert_eq!(contract.get_account(bobby), Some(accounts.bob));
+            assert!(contract.is_awaiting_registration(bobby));
+            assert_eq!(contract.get_contribution(1), None);
+            let decoded_events = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>());
+            if let Some(Event::PreApprovalDeferred(PreApprovalDeferred { contribution_id, identity, error })) =
+                decoded_events.last()
+            {
+                assert_eq!(*contribution_id, 1);
+                assert_eq!(*identity, bobby);
+                assert_eq!(*error, WorkflowError::InsufficientFunds.code());
+            } else {
+                panic!("encountered unexpected event kind: expected a PreApprovalDeferred event")
+            }
+            assert_eq!(contract.retry_pre_approvals(bobby), Ok(0));
+
+            set_balance(contract_id(), 1);
+            assert_eq!(
+                contract.retry_pre_approvals(SingleToken::hash("charly".as_bytes())),
+                Err(WorkflowError::UnknownContributor)
+            );
+            assert_eq!(contract.retry_pre_approvals(bobby), Ok(1));
+            assert!(!contract.is_awaiting_registration(bobby));
+            assert_eq!(contract.claim(1), Ok(()));
+        }
+
+        #[ink::test]
+        fn offered_reward_escalates_until_assigned() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 10);
+            set_balance(contract_id(), 1000);
+            assert_eq!(
+                contract.set_escalation(Some(EscalationPolicy {
+                    increment: RewardAmount::from_plancks(0),
+                    max_reward: RewardAmount::from_plancks(25),
+                })),
+                Err(WorkflowError::InvalidEscalation)
+            );
+            let escalation = EscalationPolicy {
+                increment: RewardAmount::from_plancks(5),
+                max_reward: RewardAmount::from_plancks(25),
+            };
+            assert_eq!(contract.set_escalation(Some(escalation)), Ok(()));
+            assert_eq!(contract.get_escalation(), Some(escalation));
+
+            assert_eq!(contract.get_offered_reward(1), None);
+            assert_eq!(contract.open_issue(1), Ok(()));
+            assert_eq!(contract.open_issue(2), Ok(()));
+            assert_eq!(contract.get_offered_reward(1), Some(10));
+            set_block_timestamp(EPOCH_DURATION);
+            assert_eq!(contract.get_offered_reward(1), Some(15));
+            set_block_timestamp(2 * EPOCH_DURATION);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.get_escalated_reward(1), Some(20));
+            assert_eq!(contract.get_offered_reward(1), None);
+
+            // The offered reward is capped
+            set_block_timestamp(10 * EPOCH_DURATION);
+            assert_eq!(contract.get_offered_reward(2), Some(25));
+
+            set_next_caller(accounts.bob);
+            let bob_initial_balance = get_balance(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 20);
+        }
+
+        #[ink::test]
+        fn earmarked_funds_back_their_issues() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 100);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(ink::env::pay_with_call!(contract.fund(vec![1]), 100), Ok(()));
+            set_balance(contract_id(), 100);
+            assert_eq!(
+                contract.get_earmark(0),
+                Some(Earmark { funder: accounts.charlie, contribution_ids: vec![1], remaining: 100 })
+            );
+            assert_eq!(contract.get_earmarks_of(1), vec![0]);
+            assert_eq!(contract.get_earmarked(), 100);
+            assert_eq!(contract.get_available_balance(), Ok(0));
+
+            // The earmarked funds only back their issues
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(2, identity), Err(WorkflowError::InsufficientFunds));
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.get_earmarked(), 0);
+            assert_eq!(contract.get_reserved(), 100);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(
+                ink::env::pay_with_call!(contract.fund(vec![1]), 50),
+                Err(WorkflowError::InvalidEarmark)
+            );
+            assert_eq!(ink::env::pay_with_call!(contract.fund(vec![3]), 50), Ok(()));
+            set_balance(contract_id(), 150);
+            assert_eq!(contract.withdraw_earmark(1), Err(WorkflowError::EarmarkInUse));
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.close_issue(3), Ok(()));
+            assert!(contract.is_issue_closed(3));
+            assert_eq!(contract.withdraw_earmark(1), Err(WorkflowError::CallerIsNotFunder));
+
+            set_next_caller(accounts.charlie);
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            assert_eq!(contract.withdraw_earmark(1), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 50);
+            assert_eq!(contract.get_deposit(accounts.charlie), 100);
+            assert_eq!(contract.get_earmarked(), 0);
+            assert_eq!(contract.withdraw_earmark(2), Err(WorkflowError::UnknownEarmark));
+        }
+
+        #[ink::test]
+        fn expired_approvals_give_back_their_earmarks() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 100);
+            apply_change(&mut contract, ParameterChange::ClaimWindow(2));
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(ink::env::pay_with_call!(contract.fund(vec![1]), 100), Ok(()));
+            set_balance(contract_id(), 100);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.get_earmarked(), 0);
+
+            for _ in 0..3 {
+                ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
             }
+            assert_eq!(contract.expire(1), Ok(()));
+            assert_eq!(contract.get_earmarked(), 100);
+            assert_eq!(
+                contract.get_earmark(0),
+                Some(Earmark { funder: accounts.charlie, contribution_ids: vec![1], remaining: 100 })
+            );
+            assert!(contract.is_issue_closed(1));
+
+            // The unused earmark is refundable without closing the issue
+            set_next_caller(accounts.charlie);
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            assert_eq!(contract.withdraw_earmark(0), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 100);
+            assert_eq!(contract.get_earmarked(), 0);
         }
 
-        /// Set the reward
-        #[ink(message, payable)]
-        pub fn set_reward(&mut self) -> Result<(), WorkflowError> {
-            self.reward = self.env().transferred_value();
-            Ok(())
+        #[ink::test]
+        fn verify_no_double_payment_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            assert_eq!(
+                contract.verify_no_double_payment(1),
+                Err(WorkflowError::UnknownContribution)
+            );
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
+            assert_eq!(
+                contract.verify_no_double_payment(1),
+                Ok(PaymentAudit { claimed: false, paid: None, reserved: 1, holds: true })
+            );
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(contract.claim(1), Err(WorkflowError::AlreadyClaimed));
+            assert_eq!(
+                contract.verify_no_double_payment(1),
+                Ok(PaymentAudit { claimed: true, paid: Some(1), reserved: 0, holds: true })
+            );
         }
 
-        /// Register the caller as an aspiring contributor.
-        #[ink(message)]
-        pub fn register_identity(&mut self, identity: HashValue) -> Result<(), WorkflowError> {
-            if self.identity_is_known(identity) {
-                return Err(WorkflowError::IdentityAlreadyRegistered);
-            }
+        #[ink::test]
+        fn approve_reserves_reward() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 100);
+            set_balance(contract_id(), 150);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
 
-            let caller = Self::env().caller();
-            self.identities.insert(identity, &caller);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.get_reserved(), 100);
+            assert_eq!(contract.get_reservation(1), 100);
+            assert_eq!(contract.get_escrow(Asset::Native), Ok(100));
+            // Only 50 are left unreserved
+            assert_eq!(contract.approve(2, identity), Err(WorkflowError::InsufficientFunds));
 
-            self.env()
-                .emit_event(IdentityRegistered { identity, caller });
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 50);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.approve(2, identity), Ok(()));
+            assert_eq!(contract.get_reserved(), 200);
 
-            Ok(())
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(contract.get_reserved(), 100);
+            assert_eq!(contract.get_reservation(1), 0);
+            assert_eq!(get_balance(contract_id()), 100);
         }
 
-        /// Approve contribution. This is triggered by a workflow run.
-        #[ink(message)]
-        #[modifiers(only_owner)]
-        pub fn approve(
-            &mut self,
-            contribution_id: u64,
-            contributor_identity: HashValue,
-        ) -> Result<(), WorkflowError> {
-            if self.contribution.is_some() {
-                return Err(WorkflowError::ContributionAlreadyApproved);
-            }
+        #[ink::test]
+        fn late_payout_is_compensated() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 1000);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
+            // Half of the reserved funds have been lost elsewhere.
+            set_balance(contract_id(), 500);
+            assert_eq!(contract.set_late_compensation(100, BASIS_POINTS + 1), Err(WorkflowError::InvalidShare));
+            assert_eq!(contract.set_late_compensation(100, 250), Ok(()));
 
-            let contributor = match self.get_account(contributor_identity) {
-                Some(contributor) => contributor,
-                None => return Err(WorkflowError::UnknownContributor),
-            };
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.report_late_payout(1), Ok(()));
+            assert_eq!(
+                contract.report_late_payout(1),
+                Err(WorkflowError::LatePayoutAlreadyReported)
+            );
 
-            let contribution = Contribution {
-                id: contribution_id,
-                contributor,
-                is_reward_claimed: false,
-            };
-            self.contribution = Some(contribution);
+            // The compensation accrues per day late, up to the cap
+            set_block_timestamp(2 * DAY);
+            assert_eq!(contract.get_late_compensation_of(1), Ok(20));
+            set_block_timestamp(5 * DAY);
+            assert_eq!(contract.get_late_compensation_of(1), Ok(25));
 
-            self.env().emit_event(ContributionApproval {
-                id: contribution_id,
-                contributor,
-            });
+            set_balance(contract_id(), 1025);
+            let bob_initial_balance = get_balance(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 1025);
+            assert_eq!(contract.get_late_compensation_of(1), Ok(25));
+        }
 
-            Ok(())
+        #[ink::test]
+        fn late_compensation_is_capped_to_free_balance() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 1000);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
+            let _ = contract.set_late_compensation(100, 250);
+            set_balance(contract_id(), 500);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.report_late_payout(1), Ok(()));
+            set_block_timestamp(5 * DAY);
+
+            // The compensation is not paid out of the reservation of the other contributions
+            set_next_caller(accounts.charlie);
+            let charly = SingleToken::hash("charly".as_bytes());
+            let _ = contract.register_identity(charly);
+            set_next_caller(accounts.alice);
+            set_balance(contract_id(), 2010);
+            assert_eq!(contract.approve(2, charly), Ok(()));
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 1010);
+            assert_eq!(contract.get_late_compensation_of(1), Ok(10));
+            assert_eq!(get_balance(contract_id()), contract.get_reserved());
         }
 
-        /// Check the ability to claim for a given `contribution_id`.
-        #[ink(message)]
-        pub fn can_claim(&self, contribution_id: u64) -> Result<bool, WorkflowError> {
-            self.ensure_can_claim(contribution_id)?;
+        #[ink::test]
+        fn cannot_report_funded_claim_as_late() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
 
-            Ok(true)
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.report_late_payout(1), Err(WorkflowError::NotUnderfunded));
+            assert_eq!(contract.claim(1), Ok(()));
+            assert_eq!(contract.get_late_compensation_of(1), Ok(0));
         }
 
-        /// Claim reward for a given `contribution_id`.
-        #[ink(message)]
-        pub fn claim(&mut self, contribution_id: u64) -> Result<(), WorkflowError> {
-            let contribution = self.ensure_can_claim(contribution_id)?;
+        #[ink::test]
+        fn linked_identities_share_caps_and_cooldowns() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            let bobby = SingleToken::hash("bobby".as_bytes());
+            let bobby_alt = SingleToken::hash("bobby-alt".as_bytes());
+            let person = SingleToken::hash("bob".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(bobby);
+            set_next_caller(accounts.charlie);
+            let _ = contract.register_identity(bobby_alt);
 
-            // Perform the reward claim
-            if self.env().transfer(contribution.contributor, self.reward).is_err() {
-                return Err(WorkflowError::PaymentFailed);
-            }
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, bobby);
+            let _ = contract.approve(2, bobby_alt);
+            let _ = contract.approve(3, bobby_alt);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
 
-            self.contribution = Some(Contribution {
-                is_reward_claimed: true,
-                ..contribution
-            });
+            // Counters are merged upon link
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_claims_cap(2), Ok(()));
+            assert_eq!(contract.link_identity(bobby, Some(person)), Ok(()));
+            assert_eq!(contract.link_identity(bobby_alt, Some(person)), Ok(()));
+            assert_eq!(
+                contract.link_identity(bobby, Some(bobby_alt)),
+                Err(WorkflowError::IdentityAlreadyLinked)
+            );
+            assert_eq!(contract.person_of(bobby_alt), person);
+            assert_eq!(contract.get_claims_count(bobby_alt), 1);
 
-            self.env().emit_event(RewardClaimed {
-                contribution_id,
-                contributor: contribution.contributor,
-                reward: self.reward,
-            });
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.claim(2), Ok(()));
+            assert_eq!(contract.get_claims_count(bobby), 2);
+            assert_eq!(contract.claim(3), Err(WorkflowError::ClaimsCapReached));
 
-            Ok(())
+            // Unlinked identities get their own counters back
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.link_identity(bobby_alt, None), Ok(()));
+            assert_eq!(contract.get_claims_count(bobby_alt), 0);
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.claim(3), Ok(()));
         }
 
-        /// Simply returns the workflow hash.
-        #[ink(message)]
-        pub fn get_workflow(&self) -> HashValue {
-            self.workflow
-        }
+        #[ink::test]
+        fn claim_split_works() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 101);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
 
-        /// Simply returns the reward amount.
-        #[ink(message)]
-        pub fn get_reward(&self) -> Balance {
-            self.reward
-        }
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim_split(1, Vec::new()), Err(WorkflowError::InvalidSplits));
+            assert_eq!(
+                contract.claim_split(1, vec![(accounts.django, 30), (accounts.eve, 60)]),
+                Err(WorkflowError::InvalidSplits)
+            );
+            assert_eq!(
+                contract.claim_split(1, vec![(accounts.django, 20); MAX_SPLITS + 1]),
+                Err(WorkflowError::InvalidSplits)
+            );
 
-        /// Simply returns the aprroved `contribution` if some.
-        #[ink(message)]
-        pub fn get_contribution(&self) -> Option<Contribution> {
-            self.contribution
+            // The rounding remainder goes to the first recipient
+            assert_eq!(
+                contract.claim_split(1, vec![(accounts.django, 30), (accounts.eve, 70)]),
+                Ok(())
+            );
+            assert_eq!(get_balance(accounts.django), 31);
+            assert_eq!(get_balance(accounts.eve), 70);
+            assert_eq!(contract.claim_split(1, vec![(accounts.bob, 100)]), Err(WorkflowError::AlreadyClaimed));
         }
 
-        /// Simply returns the `AccountId` of a given identity.
-        #[ink(message)]
-        pub fn get_account(&self, identity: HashValue) -> Option<AccountId> {
-            self.identities.get(identity)
-        }
+        #[ink::test]
+        fn compliance_mode_requires_payment_reference() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            let tax_reference = SingleToken::hash("INV-2024-001".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
 
-        /// A helper function to ensure a contributor can claim the reward.
-        pub fn ensure_can_claim(
-            &self,
-            contribution_id: u64,
-        ) -> Result<Contribution, WorkflowError> {
-            // Check if a contribution is set
-            let contribution = match &self.contribution {
-                Some(contribution) => contribution,
-                None => return Err(WorkflowError::NoContributionApprovedYet),
-            };
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_compliance_mode(true), Ok(()));
+            let _ = contract.approve(1, identity);
+            assert_eq!(
+                contract.register_tax_reference(identity, Some(tax_reference)),
+                Err(WorkflowError::CallerIsNotContributor)
+            );
 
-            // Verify the contribution ID
-            if contribution_id != contribution.id {
-                return Err(WorkflowError::UnknownContribution);
-            }
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Err(WorkflowError::PaymentReferenceRequired));
+            assert_eq!(
+                contract.claim_with_memo(1, String::from("Invoice 001"), tax_reference),
+                Err(WorkflowError::PaymentReferenceRequired)
+            );
 
-            // Verify the caller is the contributor
-            if Self::env().caller() != contribution.contributor {
-                return Err(WorkflowError::CallerIsNotContributor);
-            }
+            assert_eq!(contract.register_tax_reference(identity, Some(tax_reference)), Ok(()));
+            assert_eq!(contract.get_tax_reference(identity), Some(tax_reference));
+            assert_eq!(
+                contract.claim_with_memo(1, String::new(), tax_reference),
+                Err(WorkflowError::PaymentReferenceRequired)
+            );
+            assert_eq!(
+                contract.claim_with_memo(1, String::from("Invoice 001"), tax_reference),
+                Ok(())
+            );
 
-            // Check if the reward has already been claimed
-            if contribution.is_reward_claimed {
-                return Err(WorkflowError::AlreadyClaimed);
+            let decoded_events = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>());
+            if let Some(Event::PaymentReferenced(event)) = decoded_events.last() {
+                assert_eq!(event.contribution_id, 1);
+                assert_eq!(event.memo, String::from("Invoice 001"));
+            } else {
+                panic!("encountered unexpected event kind: expected a PaymentReferenced event")
             }
-
-            Ok(*contribution)
-        }
-
-        /// A helper function to detect whether an aspiring contributor identity has been registered in the storage.
-        pub fn identity_is_known(&self, identity: HashValue) -> bool {
-            self.identities.get(identity).is_some()
         }
 
-        /// A helper function to hash bytes (e.g. identities or workflow file sha).
-        pub fn hash(input: &[u8]) -> HashValue {
-            let mut hash_value = <Sha2x256 as HashOutput>::Type::default();
-            ink::env::hash_bytes::<Sha2x256>(input, &mut hash_value);
-            hash_value
-        }
-    }
+        #[ink::test]
+        fn backup_assumes_ownership_after_missed_heartbeat() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            assert_eq!(
+                contract.set_backup(Some(accounts.charlie), 0),
+                Err(WorkflowError::InvalidHeartbeatPeriod)
+            );
+            assert_eq!(contract.set_backup(Some(accounts.charlie), 100), Ok(()));
+            assert_eq!(contract.get_backup(), Some(accounts.charlie));
 
-    #[cfg(test)]
-    mod tests {
-        /// Accounts
-        /// ALICE -> contract owner
-        /// BOB -> contributor
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.assume_ownership(), Err(WorkflowError::CallerIsNotBackup));
+            assert_eq!(
+                contract.heartbeat(),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
 
-        /// Imports all the definitions from the outer scope so we can use them here.
-        use super::*;
+            // The heartbeat postpones the takeover
+            set_block_timestamp(99);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.heartbeat(), Ok(()));
+            set_block_timestamp(150);
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.assume_ownership(), Err(WorkflowError::OwnerStillActive));
 
-        use ink::env::test::EmittedEvent;
-        type Event = <SingleToken as ::ink::reflect::ContractEventBase>::Type;
+            set_block_timestamp(199);
+            assert_eq!(contract.assume_ownership(), Ok(()));
+            assert_eq!(Ownable::owner(&contract), Some(accounts.charlie));
+            assert_eq!(contract.get_backup(), None);
 
-        /// We test if the constructor does its job.
-        #[ink::test]
-        fn new_works() {
-            let contract = create_contract(1u128);
-            assert_eq!(contract.get_workflow(), [0; 32]);
-            assert_eq!(contract.get_reward(), 1u128);
-            assert_eq!(contract.get_contribution(), None);
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.heartbeat(),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
         }
 
         #[ink::test]
-        fn register_identity_works() {
+        fn multisig_accepts_ownership() {
             let accounts = default_accounts();
             let mut contract = create_contract(1u128);
-            let bob_identity = SingleToken::hash("bobby".as_bytes());
+
+            // Plain accounts can never sign as a multisig
+            assert_eq!(
+                contract.transfer_ownership_to_contract(accounts.charlie),
+                Err(WorkflowError::NotAMultisig)
+            );
+            assert_eq!(contract.get_pending_owner(), None);
+
             set_next_caller(accounts.bob);
             assert_eq!(
-                contract.register_identity(bob_identity),
-                Ok(())
+                contract.transfer_ownership_to_contract(accounts.charlie),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
             );
 
-            // Validate `IdentityRegistered` event emition
+            // The probe being a cross-contract call, the pending owner is set directly
+            contract.pending_owner = Some(accounts.charlie);
+            assert_eq!(contract.accept_ownership(), Err(WorkflowError::CallerIsNotPendingOwner));
+
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.accept_ownership(), Ok(()));
+            assert_eq!(Ownable::owner(&contract), Some(accounts.charlie));
+            assert_eq!(contract.get_pending_owner(), None);
+            assert!(AccessControl::has_role(&contract, APPROVER, Some(accounts.charlie)));
+            assert!(!AccessControl::has_role(&contract, APPROVER, Some(accounts.alice)));
+            assert_eq!(contract.accept_ownership(), Err(WorkflowError::CallerIsNotPendingOwner));
+
             let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
-            assert_eq!(1, emitted_events.len());
-            let decoded_events = decode_events(emitted_events);
-            if let Event::IdentityRegistered(IdentityRegistered { identity, caller }) = decoded_events[0] {
-                assert_eq!(identity, bob_identity);
-                assert_eq!(caller, accounts.bob);
+            let event = decode_events(emitted_events).pop().expect("an event");
+            if let Event::OwnershipAccepted(OwnershipAccepted { previous_owner, owner }) = event {
+                assert_eq!(previous_owner, Some(accounts.alice));
+                assert_eq!(owner, accounts.charlie);
             } else {
-                panic!("encountered unexpected event kind: expected a IdentityRegistered event")
+                panic!("encountered unexpected event kind: expected a OwnershipAccepted event")
             }
+        }
 
-            let maybe_account = contract.get_account(bob_identity);
+        #[ink::test]
+        fn describe_error_works() {
+            let contract = create_contract(1u128);
+            assert_eq!(
+                contract.describe_error(WorkflowError::InsufficientFunds.code()),
+                Some(ErrorDescription {
+                    name: String::from("InsufficientFunds"),
+                    description: String::from("Returned if the free balance cannot cover the reward reserved on approval."),
+                    hint: String::from("Ask the maintainers to fund the contract."),
+                })
+            );
+            // Multi-line descriptions are joined
+            assert_eq!(
+                contract.describe_error(WorkflowError::InvalidSigner.code()).map(|error| error.description),
+                Some(String::from(
+                    "Returned if the approval signer is not trusted, or if the signer to add, remove or rotate is respectively already trusted, not trusted or replaced by a trusted one."
+                ))
+            );
+            assert_eq!(
+                contract.describe_error(WorkflowError::AccountAlreadyBound.code()).map(|error| error.name),
+                Some(String::from("AccountAlreadyBound"))
+            );
+            let len = u8::try_from(WorkflowError::CATALOG.len()).expect("less than 256 errors");
+            assert_eq!(contract.describe_error(len), None);
+        }
+
+        #[ink::test]
+        fn message_class_works() {
+            let contract = create_contract(1u128);
+            assert_eq!(
+                contract.message_class(ink::selector_bytes!("get_reward")),
+                Some(MessageClass::Query)
+            );
             assert_eq!(
-                maybe_account,
-                Some(accounts.bob)
+                contract.message_class(ink::selector_bytes!("claim")),
+                Some(MessageClass::Claim)
+            );
+            assert_eq!(
+                contract.message_class(ink::selector_bytes!("approve")),
+                Some(MessageClass::Admin)
             );
+            assert_eq!(contract.message_class(ink::selector_bytes!("fund")), None);
         }
 
         #[ink::test]
-        fn already_registered_identity_fails() {
+        fn proxy_claim_works() {
             let accounts = default_accounts();
             let mut contract = create_contract(1u128);
             let identity = SingleToken::hash("bobby".as_bytes());
             set_next_caller(accounts.bob);
             let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
+
+            set_next_caller(accounts.bob);
             assert_eq!(
-                contract.register_identity(identity),
-                Err(WorkflowError::IdentityAlreadyRegistered)
+                contract.set_proxy(accounts.django, Some(MessageClass::Admin)),
+                Err(WorkflowError::InvalidProxyClass)
             );
+            assert_eq!(contract.set_proxy(accounts.django, Some(MessageClass::Query)), Ok(()));
+            set_next_caller(accounts.django);
+            assert_eq!(contract.proxy_claim(accounts.bob, 1), Err(WorkflowError::CallerIsNotProxy));
+
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.set_proxy(accounts.django, Some(MessageClass::Claim)), Ok(()));
+            let bob_initial_balance = get_balance(accounts.bob);
+            set_next_caller(accounts.django);
+            assert_eq!(contract.proxy_claim(accounts.bob, 1), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 1);
+            assert_eq!(get_balance(accounts.django), 0);
         }
 
         #[ink::test]
-        fn approve_works() {
+        fn tier_vote_sets_reward() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.propose(1), Err(WorkflowError::BadgeNotSet));
+            assert_eq!(contract.set_voting(Some(accounts.django), 100), Ok(()));
+            apply_change(&mut contract, ParameterChange::TierReward(2, Some(RewardAmount::from_plancks(50))));
+            assert_eq!(contract.propose(1), Ok(()));
+            assert_eq!(contract.propose(1), Err(WorkflowError::ContributionAlreadyApproved));
+            assert_eq!(contract.vote(1, 3), Err(WorkflowError::UnknownTier));
+            assert_eq!(contract.approve(1, identity), Err(WorkflowError::VotingOpen));
+            assert_eq!(contract.close_vote(1), Err(WorkflowError::VotingOpen));
+
+            // Nobody voted, the default reward applies
+            set_block_timestamp(100);
+            assert_eq!(contract.vote(1, 2), Err(WorkflowError::VotingClosed));
+            assert_eq!(contract.close_vote(1), Ok(()));
+            assert_eq!(contract.get_voted_reward(1), Some(contract.get_reward()));
+            assert_eq!(contract.close_vote(1), Err(WorkflowError::NoProposal));
+            assert_eq!(contract.approve(1, identity), Ok(()));
+        }
+
+        #[ink::test]
+        fn spot_checks_require_auditor_confirmation() {
             let accounts = default_accounts();
             let mut contract = create_contract(1u128);
             let identity = SingleToken::hash("bobby".as_bytes());
             set_next_caller(accounts.bob);
             let _ = contract.register_identity(identity);
 
-            let contribution_id = 1u64;
             set_next_caller(accounts.alice);
+            assert_eq!(contract.set_spot_checks(BASIS_POINTS + 1, Vec::new()), Err(WorkflowError::InvalidShare));
             assert_eq!(
-                contract.approve(contribution_id, identity),
-                Ok(())
+                contract.set_spot_checks(1, vec![accounts.django; MAX_AUDITORS + 1]),
+                Err(WorkflowError::TooManyAuditors)
             );
 
-            // Validate `ContributionApproval` event emition
-            let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
-            assert_eq!(2, emitted_events.len());
-            let decoded_events = decode_events(emitted_events);
-            if let Event::ContributionApproval(ContributionApproval { id, contributor }) = decoded_events[1] {
-                assert_eq!(id, contribution_id);
-                assert_eq!(contributor, accounts.bob);
-            } else {
-                panic!("encountered unexpected event kind: expected a ContributionApproval event")
-            }
+            // Every approval is flagged at the maximum rate
+            assert_eq!(contract.set_spot_checks(BASIS_POINTS, vec![accounts.django]), Ok(()));
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.get_spot_check(1), Some(accounts.django));
 
-            let maybe_contribution = contract.get_contribution();
-            assert_eq!(
-                maybe_contribution,
-                Some(Contribution {id: contribution_id, contributor: accounts.bob, is_reward_claimed: false})
-            );
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Err(WorkflowError::SpotCheckPending));
+            assert_eq!(contract.confirm_spot_check(1), Err(WorkflowError::CallerIsNotAuditor));
+            set_next_caller(accounts.django);
+            assert_eq!(contract.confirm_spot_check(1), Ok(()));
+            assert_eq!(contract.confirm_spot_check(1), Err(WorkflowError::NoSpotCheck));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+
+            // No approval is flagged when disabled
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_spot_checks(0, Vec::new()), Ok(()));
+            assert_eq!(contract.approve(2, identity), Ok(()));
+            assert_eq!(contract.get_spot_check(2), None);
         }
 
         #[ink::test]
-        fn only_contract_owner_can_approve() {
+        fn finalize_works() {
             let accounts = default_accounts();
             let mut contract = create_contract(1u128);
             let identity = SingleToken::hash("bobby".as_bytes());
             set_next_caller(accounts.bob);
             let _ = contract.register_identity(identity);
 
-            let contribution_id = 1u64;
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.set_finality_depth(2), Ok(()));
+            assert_eq!(contract.approve(1, identity), Ok(()));
+            assert_eq!(contract.approve(2, identity), Ok(()));
+            assert_eq!(contract.get_finalizable_at(1), Some(2));
+
+            // Claims are rejected until the approval is finalized
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Err(WorkflowError::ApprovalNotFinalized));
+            assert_eq!(contract.finalize(1), Err(WorkflowError::FinalityDepthNotReached));
+
+            // The owner can finalize immediately
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.finalize(2), Ok(()));
+            assert_eq!(contract.finalize(2), Err(WorkflowError::NotPendingFinality));
+
+            // Anyone can finalize once the finality depth is reached
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.finalize(1), Ok(()));
+            assert_eq!(contract.get_finalizable_at(1), None);
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+
+            set_next_caller(accounts.bob);
             assert_eq!(
-                contract.approve(contribution_id, identity),
-                Err(WorkflowError::OwnableError(OwnableError::CallerIsNotOwner))
+                contract.set_finality_depth(0),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
             );
         }
 
         #[ink::test]
-        fn already_approved_contribution_fails() {
+        fn anyone_can_finalize_and_pay() {
             let accounts = default_accounts();
             let mut contract = create_contract(1u128);
             let identity = SingleToken::hash("bobby".as_bytes());
-            let identity2 = SingleToken::hash("bobby2".as_bytes());
             set_next_caller(accounts.bob);
             let _ = contract.register_identity(identity);
 
-            let contribution_id = 1u64;
             set_next_caller(accounts.alice);
-            let _ = contract.approve(contribution_id, identity);
+            assert_eq!(contract.set_finality_depth(2), Ok(()));
+            assert_eq!(contract.approve(1, identity), Ok(()));
 
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.finalize_and_pay(2), Err(WorkflowError::UnknownContribution));
             assert_eq!(
-                contract.approve(contribution_id, identity2),
-                Err(WorkflowError::ContributionAlreadyApproved)
+                contract.finalize_and_pay(1),
+                Err(WorkflowError::FinalityDepthNotReached)
             );
+
+            // The reward goes to the contributor, not the caller
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            ink::env::test::advance_block::<ink::env::DefaultEnvironment>();
+            let bob_initial_balance = get_balance(accounts.bob);
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            assert_eq!(contract.finalize_and_pay(1), Ok(()));
+            assert_eq!(get_balance(accounts.bob), bob_initial_balance + 1);
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance);
+            assert_eq!(contract.get_finalizable_at(1), None);
+            assert_eq!(contract.finalize_and_pay(1), Err(WorkflowError::AlreadyClaimed));
         }
 
         #[ink::test]
-        fn approve_unknown_contributor_identity_fails() {
+        fn funder_veto_goes_to_arbitration() {
             let accounts = default_accounts();
-            let mut contract = create_contract(1u128);
+            let mut contract = create_challengeable_contract();
+            set_next_caller(accounts.django);
+            assert_eq!(contract.veto(1), Err(WorkflowError::CallerIsNotFunder));
+
+            // Only the funders backing the contribution can veto it
+            set_next_caller(accounts.charlie);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 50);
+            assert_eq!(contract.veto(1), Err(WorkflowError::CallerIsNotFunder));
+            let _ = ink::env::pay_with_call!(contract.fund(vec![2, 3]), 50);
+            set_next_caller(accounts.alice);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            let _ = contract.approve(2, identity);
+            set_next_caller(accounts.django);
+            let _ = ink::env::pay_with_call!(contract.fund(Vec::new()), 50);
+            assert_eq!(contract.veto(2), Err(WorkflowError::CallerIsNotFunder));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.veto(2), Ok(()));
+            assert_eq!(
+                contract.get_challenge(2),
+                Some(Challenge { challenger: accounts.charlie, bond: 0, is_veto: true })
+            );
+
+            // A rejected veto cannot be renewed
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.resolve_challenge(2, false), Ok(()));
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.veto(2), Err(WorkflowError::AlreadyVetoed));
+
+            // An upheld veto revokes the approval without rewarding the funder
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(3, identity);
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.veto(3), Ok(()));
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.resolve_challenge(3, true), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance);
+            assert_eq!(contract.get_contribution(3), None);
+        }
+
+        #[ink::test]
+        fn approvals_are_capped_per_run() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(3u128);
             let identity = SingleToken::hash("bobby".as_bytes());
-            let identity2 = SingleToken::hash("bobby2".as_bytes());
             set_next_caller(accounts.bob);
             let _ = contract.register_identity(identity);
 
-            let contribution_id = 1u64;
             set_next_caller(accounts.alice);
             assert_eq!(
-                contract.approve(contribution_id, identity2),
-                Err(WorkflowError::UnknownContributor)
+                contract.set_run_limits(Some(RunLimits { max_approvals: 2, max_value: 10 })),
+                Ok(())
+            );
+            assert_eq!(contract.approve_in_run(7, 1, identity), Ok(()));
+            assert_eq!(contract.approve_in_run(7, 2, identity), Ok(()));
+            // Identical re-approvals are not accounted for
+            assert_eq!(contract.approve_in_run(7, 2, identity), Ok(()));
+            assert_eq!(contract.get_run(7), RunUsage { approvals: 2, value: 2 });
+            assert_eq!(
+                contract.approve_in_run(7, 3, identity),
+                Err(WorkflowError::RunLimitExceeded)
+            );
+            assert_eq!(contract.approve_in_run(8, 3, identity), Ok(()));
+
+            // The total value is capped as well
+            let _ = contract.set_run_limits(Some(RunLimits { max_approvals: 2, max_value: 1 }));
+            assert_eq!(contract.approve_in_run(9, 4, identity), Ok(()));
+            assert_eq!(
+                contract.approve_in_run(9, 5, identity),
+                Err(WorkflowError::RunLimitExceeded)
             );
+            assert_eq!(contract.get_contribution(5), None);
         }
 
         #[ink::test]
-        fn can_claim_works() {
+        fn run_limits_account_the_actual_reward() {
             let accounts = default_accounts();
-            let mut contract = create_contract(1u128);
+            let mut contract = create_contract(100u128);
             let identity = SingleToken::hash("bobby".as_bytes());
             set_next_caller(accounts.bob);
             let _ = contract.register_identity(identity);
 
-            let contribution_id = 1u64;
+            // The first contribution reward is doubled
             set_next_caller(accounts.alice);
-            let _ = contract.approve(contribution_id, identity);
-            
-            set_next_caller(accounts.bob);
+            apply_change(&mut contract, ParameterChange::FirstContributionMultiplier(20_000));
+            let _ = contract.set_run_limits(Some(RunLimits { max_approvals: 10, max_value: 3 }));
+            assert_eq!(contract.approve_in_run(7, 1, identity), Ok(()));
+            assert_eq!(contract.get_run(7), RunUsage { approvals: 1, value: 2 });
             assert_eq!(
-                contract.can_claim(contribution_id),
-                Ok(true)
+                contract.approve_in_run(7, 2, identity),
+                Err(WorkflowError::RunLimitExceeded)
             );
+            assert_eq!(contract.get_contribution(2), None);
         }
 
         #[ink::test]
-        fn claim_works() {
+        fn admin_setters_emit_events() {
             let accounts = default_accounts();
-            let single_reward = 1u128;
-            let mut contract = create_contract(1u128);
+            let mut contract = create_contract(100u128);
+            let breaker = CircuitBreaker { window: 100, max_outflow: 1 };
+            set_reward(&mut contract, 5);
+            apply_change(&mut contract, ParameterChange::KeeperBounty(2));
+            apply_change(&mut contract, ParameterChange::ClaimWindow(10));
+            apply_change(&mut contract, ParameterChange::IdentityVerifier(Some(accounts.django)));
+            assert_eq!(contract.set_circuit_breaker(Some(breaker)), Ok(()));
+            apply_change(&mut contract, ParameterChange::TierReward(2, Some(RewardAmount::from_plancks(50))));
+            apply_change(&mut contract, ParameterChange::TierReward(2, None));
+
+            // The queued changes emit their update event when executed
+            let decoded_events = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>())
+                .into_iter()
+                .filter(|event| !matches!(event, Event::ChangeQueued(_) | Event::ChangeExecuted(_)))
+                .collect::<Vec<_>>();
+            let updates = &decoded_events[decoded_events.len() - 7..];
+            if let Event::RewardUpdated(RewardUpdated { account, old_reward, new_reward }) = updates[0] {
+                assert_eq!(account, accounts.alice);
+                assert_eq!((old_reward, new_reward), (1, 5));
+            } else {
+                panic!("encountered unexpected event kind: expected a RewardUpdated event")
+            }
+            if let Event::FeeUpdated(FeeUpdated { old_fee, new_fee, .. }) = updates[1] {
+                assert_eq!((old_fee, new_fee), (0, 2));
+            } else {
+                panic!("encountered unexpected event kind: expected a FeeUpdated event")
+            }
+            if let Event::ExpiryPolicyUpdated(ExpiryPolicyUpdated { old_claim_window, new_claim_window, .. }) = updates[2] {
+                assert_eq!((old_claim_window, new_claim_window), (0, 10));
+            } else {
+                panic!("encountered unexpected event kind: expected a ExpiryPolicyUpdated event")
+            }
+            if let Event::VerifierRotated(VerifierRotated { old_verifier, new_verifier, .. }) = updates[3] {
+                assert_eq!((old_verifier, new_verifier), (None, Some(accounts.django)));
+            } else {
+                panic!("encountered unexpected event kind: expected a VerifierRotated event")
+            }
+            if let Event::BudgetCapUpdated(BudgetCapUpdated { old_circuit_breaker, new_circuit_breaker, .. }) = updates[4] {
+                assert_eq!((old_circuit_breaker, new_circuit_breaker), (None, Some(breaker)));
+            } else {
+                panic!("encountered unexpected event kind: expected a BudgetCapUpdated event")
+            }
+            if let Event::TierUpdated(TierUpdated { tier, old_reward, new_reward, .. }) = updates[6] {
+                assert_eq!(tier, 2);
+                assert_eq!((old_reward, new_reward), (Some(50), None));
+            } else {
+                panic!("encountered unexpected event kind: expected a TierUpdated event")
+            }
+
+            let hard = SingleToken::hash("hard".as_bytes());
+            let boost = Boost { collection: accounts.django, multiplier: 15_000 };
+            let basket = vec![BasketShare { asset: Asset::Native, share: BASIS_POINTS }];
+            apply_change(&mut contract, ParameterChange::DifficultyReward(hard, Some(RewardAmount::from_plancks(3))));
+            apply_change(&mut contract, ParameterChange::Basket(basket.clone()));
+            apply_change(&mut contract, ParameterChange::Boost(Some(boost)));
+            assert_eq!(contract.set_compliance_mode(true), Ok(()));
+            assert_eq!(contract.set_late_compensation(10, 500), Ok(()));
+            assert_eq!(contract.set_first_claim_bonus(1_000), Ok(()));
+            assert_eq!(contract.set_dust_policy(DustPolicy::Treasury(accounts.eve)), Ok(()));
+            assert_eq!(contract.set_claims_cap(3), Ok(()));
+            assert_eq!(contract.set_low_funds_threshold(20), Ok(()));
+            assert_eq!(contract.set_separation_of_duties(true), Ok(()));
+            assert_eq!(contract.set_cooldown_exemption(1, true), Ok(()));
+
+            let decoded_events = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>())
+                .into_iter()
+                .filter(|event| !matches!(event, Event::ChangeQueued(_) | Event::ChangeExecuted(_)))
+                .collect::<Vec<_>>();
+            let updates = &decoded_events[decoded_events.len() - 11..];
+            match updates {
+                [
+                    Event::DifficultyRewardUpdated(DifficultyRewardUpdated { account, difficulty, old_reward: None, new_reward: Some(3) }),
+                    Event::BasketUpdated(BasketUpdated { old_basket, new_basket, .. }),
+                    Event::BoostUpdated(BoostUpdated { old_boost: None, new_boost: Some(new_boost), .. }),
+                    Event::ComplianceModeUpdated(ComplianceModeUpdated { old_compliance_mode: false, new_compliance_mode: true, .. }),
+                    Event::LateCompensationUpdated(LateCompensationUpdated { old_rate: 0, old_cap: 0, new_rate: 10, new_cap: 500, .. }),
+                    Event::FirstClaimBonusUpdated(FirstClaimBonusUpdated { old_bonus: 0, new_bonus: 1_000, .. }),
+                    Event::DustPolicyUpdated(DustPolicyUpdated { old_dust_policy: DustPolicy::Recipient, new_dust_policy, .. }),
+                    Event::ClaimsCapUpdated(ClaimsCapUpdated { old_claims_cap: 0, new_claims_cap: 3, .. }),
+                    Event::LowFundsThresholdUpdated(LowFundsThresholdUpdated { old_threshold: 0, new_threshold: 20, .. }),
+                    Event::SeparationOfDutiesUpdated(SeparationOfDutiesUpdated { old_enforced: false, new_enforced: true, .. }),
+                    Event::CooldownExemptionUpdated(CooldownExemptionUpdated { contribution_id: 1, old_exempted: false, new_exempted: true, .. }),
+                ] => {
+                    assert_eq!((*account, *difficulty), (accounts.alice, hard));
+                    assert_eq!((old_basket, new_basket), (&vec![], &basket));
+                    assert_eq!(*new_boost, boost);
+                    assert_eq!(*new_dust_policy, DustPolicy::Treasury(accounts.eve));
+                }
+                _ => panic!("encountered unexpected events: expected the setter update events"),
+            }
+        }
+
+        #[ink::test]
+        fn config_setters_emit_events() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(100u128);
+            let escalation = EscalationPolicy {
+                increment: RewardAmount::from_plancks(1),
+                max_reward: RewardAmount::from_plancks(10),
+            };
+            let insurance = InsuranceConfig { pool: accounts.frank, premium_rate: 100 };
+            assert_eq!(contract.set_escalation(Some(escalation)), Ok(()));
+            assert_eq!(contract.set_strict_funding(true), Ok(()));
+            assert_eq!(contract.set_backup(Some(accounts.eve), 100), Ok(()));
+            assert_eq!(contract.set_faucet(Some(accounts.django)), Ok(()));
+            assert_eq!(contract.set_reputation(Some(accounts.django)), Ok(()));
+            assert_eq!(contract.set_reputation_source(accounts.eve, Some(accounts.frank)), Ok(()));
+            assert_eq!(contract.set_tier_min_reputation(1, Some(5)), Ok(()));
+            assert_eq!(contract.set_voting(Some(accounts.django), 100), Ok(()));
+            apply_change(&mut contract, ParameterChange::Vesting(Some(accounts.django)));
+            assert_eq!(contract.set_lifecycle_hook(Some(accounts.django)), Ok(()));
+            apply_change(&mut contract, ParameterChange::Insurance(Some(insurance)));
+            apply_change(&mut contract, ParameterChange::YieldAdapter(Some(accounts.django)));
+            assert_eq!(contract.set_yield_policy(YieldPolicy::Treasury(accounts.eve)), Ok(()));
+
+            let decoded_events = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>());
+            let updates = &decoded_events[decoded_events.len() - 13..];
+            match updates {
+                [
+                    Event::EscalationUpdated(EscalationUpdated { account, old_escalation: None, new_escalation: Some(new_escalation) }),
+                    Event::StrictFundingUpdated(StrictFundingUpdated { old_strict_funding: false, new_strict_funding: true, .. }),
+                    Event::BackupUpdated(BackupUpdated { old_backup: None, old_heartbeat_period: 0, new_backup, new_heartbeat_period: 100, .. }),
+                    Event::FaucetUpdated(FaucetUpdated { old_faucet: None, new_faucet, .. }),
+                    Event::ReputationUpdated(ReputationUpdated { old_reputation: None, new_reputation, .. }),
+                    Event::ReputationSourceUpdated(ReputationSourceUpdated { source, old_key: None, new_key, .. }),
+                    Event::TierMinReputationUpdated(TierMinReputationUpdated { tier: 1, old_min_reputation: None, new_min_reputation: Some(5), .. }),
+                    Event::VotingUpdated(VotingUpdated { old_badge: None, old_voting_period: 0, new_badge, new_voting_period: 100, .. }),
+                    Event::VestingUpdated(VestingUpdated { old_vesting: None, new_vesting, .. }),
+                    Event::LifecycleHookUpdated(LifecycleHookUpdated { old_lifecycle_hook: None, new_lifecycle_hook, .. }),
+                    Event::InsuranceUpdated(InsuranceUpdated { old_insurance: None, new_insurance: Some(new_insurance), .. }),
+                    Event::YieldAdapterUpdated(YieldAdapterUpdated { old_yield_adapter: None, new_yield_adapter, .. }),
+                    Event::YieldPolicyUpdated(YieldPolicyUpdated { old_yield_policy: YieldPolicy::Funders, new_yield_policy, .. }),
+                ] => {
+                    assert_eq!(*account, accounts.alice);
+                    assert_eq!(*new_escalation, escalation);
+                    assert_eq!(*new_backup, Some(accounts.eve));
+                    assert_eq!((*source, *new_key), (accounts.eve, Some(accounts.frank)));
+                    assert_eq!(*new_insurance, insurance);
+                    assert_eq!(*new_yield_policy, YieldPolicy::Treasury(accounts.eve));
+                    for new_account in [new_faucet, new_reputation, new_badge, new_vesting, new_lifecycle_hook, new_yield_adapter] {
+                        assert_eq!(*new_account, Some(accounts.django));
+                    }
+                }
+                _ => panic!("encountered unexpected events: expected the setter update events"),
+            }
+
+            let template = Template { kind: String::from("bug"), tier: 1, expiry: None, tags: Vec::new() };
+            let run_limits = RunLimits { max_approvals: 2, max_value: 20 };
+            let trials = TrialConfig { reward: RewardAmount::from_plancks(2), max_per_identity: 1, duration: 100 };
+            let formula = PayoutFormula { base: 1, coefficients: vec![1], max_reward: 10 };
+            assert_eq!(contract.create_template(template.clone()), Ok(0));
+            assert_eq!(contract.set_canonical_identities(true), Ok(()));
+            assert_eq!(contract.set_multiple_identities(true), Ok(()));
+            apply_change(&mut contract, ParameterChange::IdentityRegistry(Some(accounts.django)));
+            assert_eq!(contract.set_run_limits(Some(run_limits)), Ok(()));
+            assert_eq!(contract.set_trials(Some(trials)), Ok(()));
+            assert_eq!(contract.remove_template(0), Ok(()));
+            assert_eq!(contract.set_payout_formula(Some(formula.clone())), Ok(()));
+            apply_change(
+                &mut contract,
+                ParameterChange::ChallengeConfig {
+                    bond: 10,
+                    dispute_window: 100,
+                    challenger_share: 5_000,
+                },
+            );
+            apply_change(&mut contract, ParameterChange::Arbitrator(Some(accounts.django)));
+            assert_eq!(contract.set_finality_depth(2), Ok(()));
+            assert_eq!(contract.set_spot_checks(1_000, vec![accounts.eve]), Ok(()));
+            assert_eq!(contract.set_change_delay(Parameter::Reward, DEFAULT_CHANGE_DELAY + 1), Ok(()));
+
+            // The queued changes emit their update event when executed
+            let decoded_events = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>())
+                .into_iter()
+                .filter(|event| !matches!(event, Event::ChangeQueued(_) | Event::ChangeExecuted(_)))
+                .collect::<Vec<_>>();
+            let updates = &decoded_events[decoded_events.len() - 12..];
+            match updates {
+                [
+                    Event::CanonicalIdentitiesUpdated(CanonicalIdentitiesUpdated { old_canonical_identities: false, new_canonical_identities: true, .. }),
+                    Event::MultipleIdentitiesUpdated(MultipleIdentitiesUpdated { old_multiple_identities: false, new_multiple_identities: true, .. }),
+                    Event::IdentityRegistryUpdated(IdentityRegistryUpdated { old_identity_registry: None, new_identity_registry, .. }),
+                    Event::RunLimitsUpdated(RunLimitsUpdated { old_run_limits: None, new_run_limits: Some(new_run_limits), .. }),
+                    Event::TrialsUpdated(TrialsUpdated { old_trials: None, new_trials: Some(new_trials), .. }),
+                    Event::TemplateRemoved(TemplateRemoved { id: 0, old_template, .. }),
+                    Event::PayoutFormulaUpdated(PayoutFormulaUpdated { old_payout_formula: None, new_payout_formula: Some(new_formula), .. }),
+                    Event::ChallengeConfigUpdated(ChallengeConfigUpdated {
+                        account,
+                        old_bond: 0,
+                        old_dispute_window: 0,
+                        old_challenger_share: 0,
+                        new_bond: 10,
+                        new_dispute_window: 100,
+                        new_challenger_share: 5_000,
+                    }),
+                    Event::ArbitratorUpdated(ArbitratorUpdated { old_arbitrator: None, new_arbitrator, .. }),
+                    Event::FinalityDepthUpdated(FinalityDepthUpdated { old_finality_depth: 0, new_finality_depth: 2, .. }),
+                    Event::SpotChecksUpdated(SpotChecksUpdated { old_rate: 0, old_auditors, new_rate: 1_000, new_auditors, .. }),
+                    Event::ChangeDelayUpdated(ChangeDelayUpdated { parameter: Parameter::Reward, old_delay, new_delay, .. }),
+                ] => {
+                    assert_eq!(*account, accounts.alice);
+                    assert_eq!(*new_identity_registry, Some(accounts.django));
+                    assert_eq!(*new_run_limits, run_limits);
+                    assert_eq!(*new_trials, trials);
+                    assert_eq!(*old_template, template);
+                    assert_eq!(*new_formula, formula);
+                    assert_eq!(*new_arbitrator, Some(accounts.django));
+                    assert_eq!((old_auditors, new_auditors), (&vec![], &vec![accounts.eve]));
+                    assert_eq!((*old_delay, *new_delay), (DEFAULT_CHANGE_DELAY, DEFAULT_CHANGE_DELAY + 1));
+                }
+                _ => panic!("encountered unexpected events: expected the setter update events"),
+            }
+
+            let identity = SingleToken::hash("bobby".as_bytes());
+            let person = SingleToken::hash("person".as_bytes());
+            let token = Asset::Psp22(accounts.django);
+            assert_eq!(contract.set_guardian(Some(accounts.charlie)), Ok(()));
+            assert_eq!(contract.set_allowed_asset(token, true), Ok(()));
+            assert_eq!(contract.link_identity(identity, Some(person)), Ok(()));
+            assert_eq!(contract.link_identity(identity, None), Ok(()));
+
+            let decoded_events = decode_events(ink::env::test::recorded_events().collect::<Vec<_>>());
+            let updates = &decoded_events[decoded_events.len() - 4..];
+            match updates {
+                [
+                    Event::GuardianUpdated(GuardianUpdated { account, old_guardian: None, new_guardian }),
+                    Event::AllowedAssetUpdated(AllowedAssetUpdated { asset, old_allowed: false, new_allowed: true, .. }),
+                    Event::IdentityLinked(IdentityLinked { identity: linked, old_person: None, new_person: Some(new_person), .. }),
+                    Event::IdentityLinked(IdentityLinked { identity: unlinked, old_person: Some(old_person), new_person: None, .. }),
+                ] => {
+                    assert_eq!(*account, accounts.alice);
+                    assert_eq!(*new_guardian, Some(accounts.charlie));
+                    assert_eq!(*asset, token);
+                    assert_eq!((*linked, *unlinked), (identity, identity));
+                    assert_eq!((*new_person, *old_person), (person, person));
+                }
+                _ => panic!("encountered unexpected events: expected the setter update events"),
+            }
+        }
+
+        #[ink::test]
+        fn circuit_breaker_pauses_on_abnormal_outflow() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(10u128);
             let identity = SingleToken::hash("bobby".as_bytes());
             set_next_caller(accounts.bob);
             let _ = contract.register_identity(identity);
-
-            let issue_id = 1u64;
             set_next_caller(accounts.alice);
-            let _ = contract.approve(issue_id, identity);
-            
-            let bob_initial_balance = get_balance(accounts.bob);
-            set_next_caller(accounts.bob);
-            assert_eq!(contract.claim(issue_id), Ok(()));
+            for contribution_id in 1..=4 {
+                let _ = contract.approve(contribution_id, identity);
+            }
             assert_eq!(
-                get_balance(accounts.bob),
-                bob_initial_balance + contract.reward
+                contract.set_circuit_breaker(Some(CircuitBreaker { window: 0, max_outflow: 1 })),
+                Err(WorkflowError::InvalidCircuitBreaker)
             );
+            let breaker = CircuitBreaker { window: 100, max_outflow: 1 };
+            assert_eq!(contract.set_circuit_breaker(Some(breaker)), Ok(()));
 
-            let maybe_contribution = contract.get_contribution();
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(1), Ok(()));
+            assert!(!Pausable::paused(&contract));
+            assert_eq!(contract.claim(2), Ok(()));
+            assert!(Pausable::paused(&contract));
             assert_eq!(
-                maybe_contribution,
-                Some(Contribution {id: issue_id, contributor: accounts.bob, is_reward_claimed: true})
+                contract.claim(3),
+                Err(WorkflowError::ContractPaused)
             );
 
-            // Validate `RewardClaimed` event emition
+            // Validate `CircuitBreakerTripped` event emition
             let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
-            assert_eq!(3, emitted_events.len());
             let decoded_events = decode_events(emitted_events);
-            if let Event::RewardClaimed(RewardClaimed { contribution_id, contributor, reward }) = decoded_events[2] {
-                assert_eq!(contribution_id, issue_id);
-                assert_eq!(contributor, accounts.bob);
-                assert_eq!(reward, single_reward);
+            if let Some(Event::CircuitBreakerTripped(CircuitBreakerTripped { outflow, max_outflow })) = decoded_events.last() {
+                assert_eq!(*outflow, 2);
+                assert_eq!(*max_outflow, 1);
             } else {
-                panic!("encountered unexpected event kind: expected a RewardClaimed event")
+                panic!("encountered unexpected event kind: expected a CircuitBreakerTripped event")
             }
+
+            // The previous window outflow is weighted by its overlap with the sliding window
+            set_block_timestamp(150);
+            assert_eq!(contract.get_outflow(), Ok(1));
+            set_next_caller(accounts.alice);
+            assert_eq!(contract.unpause(), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(3), Ok(()));
+            assert!(Pausable::paused(&contract));
+
+            set_block_timestamp(300);
+            assert_eq!(contract.get_outflow(), Ok(0));
+            set_next_caller(accounts.alice);
+            let _ = contract.unpause();
+            set_next_caller(accounts.bob);
+            assert_eq!(contract.claim(4), Ok(()));
+            assert!(!Pausable::paused(&contract));
         }
 
         #[ink::test]
-        fn cannot_claim_non_approved_contribution() {
+        fn yield_configuration_works() {
             let accounts = default_accounts();
-            let contract = create_contract(1u128);
-            set_next_caller(accounts.bob);
+            let mut contract = create_contract(1u128);
+            assert_eq!(contract.park(1), Err(WorkflowError::YieldAdapterNotSet));
+            assert_eq!(contract.harvest(), Err(WorkflowError::YieldAdapterNotSet));
+            assert_eq!(contract.unpark(1), Err(WorkflowError::InsufficientParkedFunds));
 
-            let contribution_id = 1u64;
+            apply_change(&mut contract, ParameterChange::YieldAdapter(Some(accounts.django)));
+            assert_eq!(contract.get_yield_adapter(), Some(accounts.django));
+            assert_eq!(contract.get_yield_policy(), YieldPolicy::Funders);
             assert_eq!(
-                contract.can_claim(contribution_id),
-                Err(WorkflowError::NoContributionApprovedYet)
+                contract.set_yield_policy(YieldPolicy::Treasury(accounts.eve)),
+                Ok(())
+            );
+            assert_eq!(contract.get_yield_policy(), YieldPolicy::Treasury(accounts.eve));
+            assert_eq!(contract.get_parked(), 0);
+
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.park(1),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
             );
         }
 
         #[ink::test]
-        fn cannot_claim_unknown_contribution() {
+        fn registration_requires_ownership_proof() {
             let accounts = default_accounts();
             let mut contract = create_contract(1u128);
+            apply_change(&mut contract, ParameterChange::AttestorKey(Some(signer_account(ATTESTOR_SECRET))));
+            assert_eq!(contract.get_attestor_key(), Some(signer_account(ATTESTOR_SECRET)));
             let identity = SingleToken::hash("bobby".as_bytes());
-            set_next_caller(accounts.bob);
-            let _ = contract.register_identity(identity);
+            let proof = sign(ATTESTOR_SECRET, &SingleToken::hash_payload(&(identity, accounts.bob)));
 
-            let contribution_id = 1u64;
-            set_next_caller(accounts.alice);
-            let _ = contract.approve(contribution_id, identity);
+            // The proof is bound to the account
+            set_next_caller(accounts.charlie);
+            assert_eq!(contract.register_identity(identity), Err(WorkflowError::InvalidSignature));
+            assert_eq!(
+                contract.register_identity_with_proof(identity, proof.to_vec()),
+                Err(WorkflowError::InvalidSignature)
+            );
 
             set_next_caller(accounts.bob);
+            let forged = sign(OTHER_SECRET, &SingleToken::hash_payload(&(identity, accounts.bob)));
             assert_eq!(
-                contract.can_claim(2u64),
-                Err(WorkflowError::UnknownContribution)
+                contract.register_identity_with_proof(identity, forged.to_vec()),
+                Err(WorkflowError::InvalidSignature)
             );
+            assert_eq!(contract.register_identity_with_proof(identity, proof.to_vec()), Ok(()));
+            assert_eq!(contract.get_account(identity), Some(accounts.bob));
         }
 
         #[ink::test]
-        fn cannot_claim_if_not_contributor() {
+        fn identity_registry_replaces_local_registrations() {
             let accounts = default_accounts();
             let mut contract = create_contract(1u128);
-            let identity = SingleToken::hash("bobby".as_bytes());
-            set_next_caller(accounts.eve);
-            let _ = contract.register_identity(identity);
-
-            let contribution_id = 1u64;
-            set_next_caller(accounts.alice);
-            let _ = contract.approve(contribution_id, identity);
+            apply_change(&mut contract, ParameterChange::IdentityRegistry(Some(accounts.django)));
+            assert_eq!(contract.get_identity_registry(), Some(accounts.django));
 
             set_next_caller(accounts.bob);
             assert_eq!(
-                contract.can_claim(contribution_id),
-                Err(WorkflowError::CallerIsNotContributor)
+                contract.queue_change(ParameterChange::IdentityRegistry(None)),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
+            assert_eq!(
+                contract.register(SingleToken::hash("bobby".as_bytes()), accounts.bob),
+                Err(WorkflowError::SharedIdentityRegistrySet)
             );
         }
 
         #[ink::test]
-        fn cannot_claim_already_claimed_reward() {
+        fn only_owner_can_set_lifecycle_hook() {
             let accounts = default_accounts();
             let mut contract = create_contract(1u128);
-            let identity = SingleToken::hash("bobby".as_bytes());
-            set_next_caller(accounts.bob);
-            let _ = contract.register_identity(identity);
-
-            let contribution_id = 1u64;
-            set_next_caller(accounts.alice);
-            let _ = contract.approve(contribution_id, identity);
+            assert_eq!(contract.set_lifecycle_hook(Some(accounts.django)), Ok(()));
+            assert_eq!(contract.get_lifecycle_hook(), Some(accounts.django));
 
             set_next_caller(accounts.bob);
-            let _ = contract.claim(contribution_id);
             assert_eq!(
-                contract.can_claim(contribution_id),
-                Err(WorkflowError::AlreadyClaimed)
+                contract.set_lifecycle_hook(None),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
             );
         }
 
+        #[ink::test]
+        fn vesting_is_set_through_the_queue() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            apply_change(&mut contract, ParameterChange::Vesting(Some(accounts.django)));
+            assert_eq!(contract.get_vesting(), Some(accounts.django));
 
-        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
-            ink::env::test::default_accounts::<Environment>()
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.queue_change(ParameterChange::Vesting(None)),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
         }
 
-        fn contract_id() -> AccountId {
-            ink::env::test::callee::<ink::env::DefaultEnvironment>()
-        }
+        #[ink::test]
+        fn boost_is_set_through_the_queue() {
+            let accounts = default_accounts();
+            let mut contract = create_contract(1u128);
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
+            set_next_caller(accounts.alice);
+            let _ = contract.approve(1, identity);
 
-        fn set_next_caller(caller: AccountId) {
-            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
-        }
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.claim_with_boost(1, Id::U8(1)),
+                Err(WorkflowError::BoostNotSet)
+            );
 
-        fn set_balance(account_id: AccountId, balance: Balance) {
-            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account_id, balance)
-        }
+            set_next_caller(accounts.alice);
+            assert_eq!(
+                contract.queue_change(ParameterChange::Boost(Some(Boost { collection: accounts.django, multiplier: BASIS_POINTS - 1 }))),
+                Err(WorkflowError::InvalidMultiplier)
+            );
+            let boost = Boost { collection: accounts.django, multiplier: 2 * BASIS_POINTS };
+            apply_change(&mut contract, ParameterChange::Boost(Some(boost)));
+            assert_eq!(contract.get_boost(), Some(boost));
 
-        fn get_balance(account: AccountId) -> Balance {
-            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
-                .expect("Cannot get account balance")
+            // The boost is not paid out of the reserved funds
+            set_balance(contract_id(), 1);
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                contract.claim_with_boost(1, Id::U8(1)),
+                Err(WorkflowError::InsufficientFunds)
+            );
+            assert_eq!(
+                contract.queue_change(ParameterChange::Boost(None)),
+                Err(WorkflowError::AccessControlError(AccessControlError::MissingRole))
+            );
         }
 
-        /// Creates a new instance of `SingleToken` with `initial_balance`.
+        /// Creates a new instance of `SingleToken` rewarding 100, with challenges enabled
+        /// (bond of 10, dispute window of 100ms, challenger share of 50%) and an approved contribution #1.
         ///
         /// Returns the `contract_instance`.
-        fn create_contract(initial_balance: Balance) -> SingleToken {
+        fn create_challengeable_contract() -> SingleToken {
             let accounts = default_accounts();
+            let mut contract = create_contract(1000u128);
+            set_reward(&mut contract, 100);
+            let challenge_config = |challenger_share| ParameterChange::ChallengeConfig {
+                bond: 10,
+                dispute_window: 100,
+                challenger_share,
+            };
+            assert_eq!(
+                contract.queue_change(challenge_config(BASIS_POINTS + 1)),
+                Err(WorkflowError::InvalidShare)
+            );
+            apply_change(&mut contract, challenge_config(5_000));
+
+            let identity = SingleToken::hash("bobby".as_bytes());
+            set_next_caller(accounts.bob);
+            let _ = contract.register_identity(identity);
             set_next_caller(accounts.alice);
-            set_balance(contract_id(), initial_balance);
-            let mut single_token = SingleToken::new([0; 32]);
-            ink::env::pay_with_call!(single_token.set_reward(), 1);
-            single_token
+            let _ = contract.approve(1, identity);
+            contract
         }
 
         fn decode_events(emittend_events: Vec<EmittedEvent>) -> Vec<Event> {
@@ -610,3 +12743,6 @@ pub mod single_token {
         }
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod simulation;
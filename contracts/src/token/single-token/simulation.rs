@@ -0,0 +1,238 @@
+//! An economic simulation of the reward contract, compiled natively against the off-chain
+//! environment.
+//!
+//! Randomized actors race against each other for thousands of steps:
+//! - funders (BOB, CHARLIE) depositing random amounts.
+//! - the approver (ALICE, i.e. the owner) approving contributions of random contributors.
+//! - contributors (DJANGO, EVE) claiming their rewards.
+//! - an attacker (FRANK, a registered contributor too) claiming the rewards of others or
+//!   claiming twice.
+//!
+//! The escrow is checked after every step, and the run reports the solvency violations,
+//! the successful attacks and the fairness of the payouts across contributors.
+
+use crate::single_token::{ParameterChange, SingleToken};
+use ink::env::DefaultEnvironment;
+use ink::primitives::AccountId;
+use kudos_ink_contracts::traits::types::HashValue;
+use kudos_ink_contracts::traits::workflow::WorkflowError;
+
+type Balance = u128;
+
+/// The number of simulated steps per run.
+const STEPS: usize = 500;
+
+/// The reward of every contribution.
+const REWARD: Balance = 10;
+
+/// The maximum amount of a single deposit.
+const MAX_DEPOSIT: u64 = 20;
+
+/// A SplitMix64 generator, deterministic for a given seed so that failing runs can be replayed.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+}
+
+/// A contribution as modeled by the simulation.
+struct Approval {
+    id: u64,
+    contributor: usize,
+    claimed: bool,
+}
+
+/// The outcome of a simulation run.
+#[derive(Debug, Default)]
+struct Report {
+    deposited: Balance,
+    approvals: u32,
+    underfunded_approvals: u32,
+    claims: u32,
+    failed_claims: u32,
+    attacks: u32,
+    successful_attacks: u32,
+    solvency_violations: u32,
+    audit_violations: u32,
+    // The approved and claimed contributions, and the amount received, per contributor.
+    approved: Vec<u32>,
+    claimed: Vec<u32>,
+    received: Vec<Balance>,
+}
+
+impl Report {
+    /// Jain's fairness index of the claimed fraction of the approved rewards across
+    /// contributors, `1.0` being perfectly fair.
+    fn fairness(&self) -> f64 {
+        let ratios: Vec<f64> = self
+            .approved
+            .iter()
+            .zip(self.claimed.iter())
+            .filter(|(approved, _)| **approved > 0)
+            .map(|(approved, claimed)| f64::from(*claimed) / f64::from(*approved))
+            .collect();
+        let sum: f64 = ratios.iter().sum();
+        let sum_of_squares: f64 = ratios.iter().map(|ratio| ratio * ratio).sum();
+        if sum_of_squares == 0.0 {
+            return 1.0;
+        }
+        sum * sum / (ratios.len() as f64 * sum_of_squares)
+    }
+}
+
+/// Run the simulation for a given `seed`, in a fresh off-chain environment.
+fn run(seed: u64) -> Report {
+    let mut report = Report::default();
+    ink::env::test::run_test::<DefaultEnvironment, _>(|accounts| {
+        let funders = [accounts.bob, accounts.charlie];
+        let contributors = [accounts.django, accounts.eve, accounts.frank];
+        let attacker = 2;
+        let identities: Vec<HashValue> = ["django", "eve", "frank"]
+            .iter()
+            .map(|name| SingleToken::hash(name.as_bytes()))
+            .collect();
+        let contract_id = ink::env::test::callee::<DefaultEnvironment>();
+
+        // The owner sets the reward through the timelock queue, the contract being funded by
+        // the funders only
+        set_caller(accounts.alice);
+        set_balance(contract_id, 0);
+        let mut contract = SingleToken::new([0; 32]);
+        let change = ParameterChange::Reward(REWARD);
+        let parameter = change.parameter();
+        contract.queue_change(change).expect("reward change is queued");
+        let delay = contract.get_change_delay(parameter);
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(delay);
+        contract.execute_change(parameter).expect("reward is set");
+
+        for (contributor, identity) in contributors.iter().zip(identities.iter()) {
+            set_caller(*contributor);
+            contract.register_identity(*identity).expect("identity is registered");
+        }
+
+        let mut rng = Rng(seed);
+        let mut approvals: Vec<Approval> = Vec::new();
+        report.approved = vec![0; contributors.len()];
+        report.claimed = vec![0; contributors.len()];
+        for _ in 0..STEPS {
+            match rng.below(5) {
+                0 => {
+                    let funder = funders[rng.below(2) as usize];
+                    let amount = Balance::from(1 + rng.below(MAX_DEPOSIT));
+                    set_caller(funder);
+                    transfer_in(amount);
+                    if contract.fund(Vec::new()).is_ok() {
+                        report.deposited += amount;
+                    }
+                }
+                1 => {
+                    let contributor = rng.below(contributors.len() as u64) as usize;
+                    let id = approvals.len() as u64;
+                    set_caller(accounts.alice);
+                    match contract.approve(id, identities[contributor]) {
+                        Ok(()) => {
+                            report.approvals += 1;
+                            report.approved[contributor] += 1;
+                            approvals.push(Approval { id, contributor, claimed: false });
+                        }
+                        Err(WorkflowError::InsufficientFunds) => report.underfunded_approvals += 1,
+                        Err(error) => panic!("unexpected approval error: {:?}", error),
+                    }
+                }
+                2 | 3 => {
+                    let contributor = rng.below(contributors.len() as u64) as usize;
+                    if let Some(approval) = approvals
+                        .iter_mut()
+                        .find(|approval| approval.contributor == contributor && !approval.claimed)
+                    {
+                        set_caller(contributors[contributor]);
+                        if contract.claim(approval.id).is_ok() {
+                            approval.claimed = true;
+                            report.claims += 1;
+                            report.claimed[contributor] += 1;
+                        } else {
+                            report.failed_claims += 1;
+                        }
+                    }
+                }
+                _ => {
+                    // The attacker races for a reward it is not entitled to
+                    if let Some(approval) = approvals
+                        .iter()
+                        .filter(|approval| approval.contributor != attacker || approval.claimed)
+                        .nth(rng.below(approvals.len().max(1) as u64) as usize)
+                    {
+                        set_caller(contributors[attacker]);
+                        report.attacks += 1;
+                        if contract.claim(approval.id).is_ok() {
+                            report.successful_attacks += 1;
+                        }
+                    }
+                }
+            }
+
+            if get_balance(contract_id) < contract.get_reserved() {
+                report.solvency_violations += 1;
+            }
+        }
+
+        for approval in &approvals {
+            let audit = contract
+                .verify_no_double_payment(approval.id)
+                .expect("contribution is approved");
+            if !audit.holds || audit.claimed != approval.claimed {
+                report.audit_violations += 1;
+            }
+        }
+        report.received = contributors.iter().map(|contributor| get_balance(*contributor)).collect();
+
+        Ok(())
+    })
+    .expect("simulation runs");
+    report
+}
+
+fn set_caller(caller: AccountId) {
+    ink::env::test::set_caller::<DefaultEnvironment>(caller);
+}
+
+fn transfer_in(amount: Balance) {
+    ink::env::test::transfer_in::<DefaultEnvironment>(amount);
+}
+
+fn set_balance(account_id: AccountId, balance: Balance) {
+    ink::env::test::set_account_balance::<DefaultEnvironment>(account_id, balance)
+}
+
+fn get_balance(account_id: AccountId) -> Balance {
+    ink::env::test::get_account_balance::<DefaultEnvironment>(account_id).unwrap_or_default()
+}
+
+#[test]
+fn escrow_stays_solvent_and_fair() {
+    for seed in 0..8 {
+        let report = run(seed);
+        // The report is only shown along with a failed assertion
+        let context = format!("seed {}: {:?}, fairness {:.3}", seed, report, report.fairness());
+
+        assert!(report.approvals > 0 && report.claims > 0, "nothing simulated, {}", context);
+        assert_eq!(report.solvency_violations, 0, "escrow is insolvent, {}", context);
+        assert_eq!(report.audit_violations, 0, "payment audit failed, {}", context);
+        assert_eq!(report.successful_attacks, 0, "attack succeeded, {}", context);
+        assert_eq!(report.failed_claims, 0, "approved reward not claimable, {}", context);
+        // Every contributor received exactly the reward of each claimed contribution
+        for (claimed, received) in report.claimed.iter().zip(report.received.iter()) {
+            assert_eq!(*received, Balance::from(*claimed) * REWARD, "unfair payout, {}", context);
+        }
+    }
+}
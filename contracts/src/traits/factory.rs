@@ -0,0 +1,35 @@
+use ink::primitives::AccountId;
+use openbrush::contracts::traits::ownable::*;
+use super::types::HashValue;
+
+#[openbrush::wrapper]
+pub type FactoryRef = dyn Factory;
+
+/// The workflow factory standard, instantiating a workflow contract per repository.
+#[openbrush::trait_definition]
+pub trait Factory {
+    /// Returns the workflow contract created for a given `repository`, if some.
+    #[ink(message)]
+    fn workflow_of(&self, repository: HashValue) -> Option<AccountId>;
+}
+
+/// Errors that can occur upon calling a factory contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum FactoryError {
+    OwnableError(OwnableError),
+    /// Returned if no workflow code hash is set.
+    CodeHashNotSet,
+    /// Returned if a workflow contract is already created for the repository.
+    WorkflowAlreadyCreated,
+    /// Returned if the workflow contract instantiation failed.
+    InstantiationFailed,
+    /// Returned if the ownership or the roles of the created workflow contract cannot be transferred.
+    OwnershipTransferFailed,
+}
+
+impl From<OwnableError> for FactoryError {
+    fn from(error: OwnableError) -> Self {
+        FactoryError::OwnableError(error)
+    }
+}
@@ -0,0 +1,36 @@
+use ink::primitives::AccountId;
+use openbrush::contracts::traits::ownable::*;
+
+#[openbrush::wrapper]
+pub type FaucetRef = dyn Faucet;
+
+#[openbrush::trait_definition]
+pub trait Faucet {
+    /// Drip the configured amount to a newly registered `account`.
+    /// This is triggered by the reward contracts upon identity registration.
+    #[ink(message)]
+    fn drip(&mut self, account: AccountId) -> Result<(), FaucetError>;
+}
+
+/// Errors that can occur upon calling the faucet contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum FaucetError {
+    OwnableError(OwnableError),
+    /// Returned if caller is neither the `owner` nor an allowed reward contract.
+    CallerNotAllowed,
+    /// Returned if the `account` already received a drip during the cooldown.
+    RateLimited,
+    /// Returned if the maximum number of drips of the current period is reached.
+    DripLimitReached,
+    /// Returned if the faucet balance is lower than the drip amount.
+    InsufficientFunds,
+    /// Attempted transfer failed.
+    TransferFailed,
+}
+
+impl From<OwnableError> for FaucetError {
+    fn from(error: OwnableError) -> Self {
+        FaucetError::OwnableError(error)
+    }
+}
@@ -0,0 +1,32 @@
+use ink::primitives::AccountId;
+use openbrush::contracts::traits::ownable::*;
+use super::types::HashValue;
+
+#[openbrush::wrapper]
+pub type IdentityRef = dyn Identity;
+
+/// The shared identity standard, resolving the contributor identities (e.g. the hashed
+/// Github IDs) registered once for every reward contract.
+#[openbrush::trait_definition]
+pub trait Identity {
+    /// Returns the account a given `identity` is registered for, if some.
+    #[ink(message)]
+    fn account_of(&self, identity: HashValue) -> Option<AccountId>;
+}
+
+/// Errors that can occur upon calling an identity registry contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum IdentityError {
+    OwnableError(OwnableError),
+    /// Returned if the identity is already registered.
+    IdentityAlreadyRegistered,
+    /// Returned if the identity verifier rejects the registration.
+    IdentityNotVerified,
+}
+
+impl From<OwnableError> for IdentityError {
+    fn from(error: OwnableError) -> Self {
+        IdentityError::OwnableError(error)
+    }
+}
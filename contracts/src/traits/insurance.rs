@@ -0,0 +1,50 @@
+use ink::primitives::AccountId;
+use openbrush::contracts::traits::ownable::*;
+use openbrush::traits::Balance;
+
+#[openbrush::wrapper]
+pub type InsuranceRef = dyn Insurance;
+
+/// The insurance standard, covering the rewards approved by the workflow contracts against
+/// their insolvency in exchange for a premium per approval.
+#[openbrush::trait_definition]
+pub trait Insurance {
+    /// Cover the reward `amount` of a given `contribution_id` owed to a `contributor` by the
+    /// calling workflow contract, the transferred value being the premium.
+    /// This is triggered by the reward contracts upon approval.
+    #[ink(message, payable)]
+    fn cover(&mut self, contribution_id: u64, contributor: AccountId, amount: Balance) -> Result<(), InsuranceError>;
+}
+
+/// Errors that can occur upon calling the insurance contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum InsuranceError {
+    OwnableError(OwnableError),
+    /// Returned if caller is not an allowed reward contract.
+    CallerNotAllowed,
+    /// Returned if the transferred premium is lower than the premium rate of the amount.
+    InsufficientPremium,
+    /// Returned if the contribution is already covered.
+    AlreadyCovered,
+    /// Returned if the contribution is not covered.
+    UnknownCoverage,
+    /// Returned if caller is not the covered contributor.
+    CallerIsNotContributor,
+    /// Returned if the contributor is already compensated for the contribution.
+    AlreadyCompensated,
+    /// Returned if the reward contract does not owe any failed payout of the contribution.
+    NoFailureEvidence,
+    /// Returned if the premium rate is greater than 100 percent.
+    InvalidPremiumRate,
+    /// Returned if the pool balance is lower than the requested amount.
+    InsufficientFunds,
+    /// Attempted transfer failed.
+    TransferFailed,
+}
+
+impl From<OwnableError> for InsuranceError {
+    fn from(error: OwnableError) -> Self {
+        InsuranceError::OwnableError(error)
+    }
+}
@@ -0,0 +1,92 @@
+#[openbrush::wrapper]
+pub type IntrospectionRef = dyn Introspection;
+
+/// The interface detection standard, implemented by every contract so that callers can
+/// feature-detect deployed contracts instead of failing on missing selectors.
+#[openbrush::trait_definition]
+pub trait Introspection {
+    /// Returns `true` if the contract implements the interface of a given `interface_id`.
+    #[ink(message)]
+    fn supports_interface(&self, interface_id: u32) -> bool;
+}
+
+/// The interface ids, computed as the XOR of the interface message selectors.
+///
+/// Any change to an interface messages results in a new id, so a new version of an
+/// interface gets its own constant while the previous one keeps identifying older deployments.
+pub mod interface_ids {
+    use ink::selector_id;
+
+    /// The `Introspection` interface.
+    pub const INTROSPECTION: u32 = selector_id!("Introspection::supports_interface");
+
+    /// The `Workflow` interface, first version.
+    pub const WORKFLOW_V1: u32 = selector_id!("Workflow::register_identity")
+        ^ selector_id!("Workflow::approve")
+        ^ selector_id!("Workflow::can_claim")
+        ^ selector_id!("Workflow::claim");
+
+    /// The `Faucet` hook, triggered upon identity registration.
+    pub const FAUCET: u32 = selector_id!("Faucet::drip");
+
+    /// The `Vesting` hook, triggered upon claim.
+    pub const VESTING: u32 = selector_id!("Vesting::vest");
+
+    /// The `Reputation` interface.
+    pub const REPUTATION: u32 =
+        selector_id!("Reputation::reputation_of") ^ selector_id!("Reputation::rank_of");
+
+    /// The `IdentityVerifier` hook, triggered upon identity registration.
+    pub const IDENTITY_VERIFIER: u32 = selector_id!("IdentityVerifier::verify");
+
+    /// The `YieldAdapter` interface.
+    pub const YIELD_ADAPTER: u32 = selector_id!("YieldAdapter::deposit")
+        ^ selector_id!("YieldAdapter::withdraw")
+        ^ selector_id!("YieldAdapter::balance_of");
+
+    /// The `Names` interface.
+    pub const NAMES: u32 = selector_id!("Names::resolve") ^ selector_id!("Names::name_of");
+
+    /// The `LifecycleHook` hook, triggered upon every contribution lifecycle transition.
+    pub const LIFECYCLE_HOOK: u32 = selector_id!("LifecycleHook::notify");
+
+    /// The `Factory` interface.
+    pub const FACTORY: u32 = selector_id!("Factory::workflow_of");
+
+    /// The `Identity` interface.
+    pub const IDENTITY: u32 = selector_id!("Identity::account_of");
+
+    /// The `Insurance` hook, triggered upon approval.
+    pub const INSURANCE: u32 = selector_id!("Insurance::cover");
+
+    /// The `Multisig` probe, checked before handing the ownership to a contract.
+    pub const MULTISIG: u32 = selector_id!("Multisig::is_multisig") ^ selector_id!("Multisig::owners");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::interface_ids::*;
+
+    #[test]
+    fn interface_ids_are_unique() {
+        let ids = [
+            INTROSPECTION,
+            WORKFLOW_V1,
+            FAUCET,
+            VESTING,
+            REPUTATION,
+            IDENTITY_VERIFIER,
+            YIELD_ADAPTER,
+            NAMES,
+            LIFECYCLE_HOOK,
+            FACTORY,
+            IDENTITY,
+            MULTISIG,
+            INSURANCE,
+        ];
+        for (index, id) in ids.iter().enumerate() {
+            assert!(!ids[index + 1..].contains(id));
+        }
+        assert_eq!(FAUCET, u32::from_be_bytes(ink::selector_bytes!("Faucet::drip")));
+    }
+}
@@ -0,0 +1,43 @@
+use openbrush::contracts::traits::ownable::*;
+
+#[openbrush::wrapper]
+pub type LifecycleHookRef = dyn LifecycleHook;
+
+/// A lifecycle transition of a contribution.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum Transition {
+    /// The contribution is approved.
+    Approved,
+    /// The approval is final, after the finality depth.
+    Finalized,
+    /// The reward is claimed.
+    Claimed,
+    /// The approval is revoked (e.g. an upheld challenge).
+    Revoked,
+    /// The reward was not claimed before the claim deadline, and is released.
+    Expired,
+}
+
+#[openbrush::trait_definition]
+pub trait LifecycleHook {
+    /// Notify a lifecycle `transition` of a given `contribution_id`.
+    /// This is triggered by the reward contracts upon every lifecycle transition.
+    #[ink(message)]
+    fn notify(&mut self, contribution_id: u64, transition: Transition) -> Result<(), LifecycleHookError>;
+}
+
+/// Errors that can occur upon calling the lifecycle hook.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum LifecycleHookError {
+    OwnableError(OwnableError),
+    /// Returned if caller is not an allowed reward contract.
+    CallerNotAllowed,
+}
+
+impl From<OwnableError> for LifecycleHookError {
+    fn from(error: OwnableError) -> Self {
+        LifecycleHookError::OwnableError(error)
+    }
+}
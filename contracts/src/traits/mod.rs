@@ -1,2 +1,14 @@
+pub mod factory;
+pub mod faucet;
+pub mod identity;
+pub mod insurance;
+pub mod introspection;
+pub mod lifecycle;
+pub mod multisig;
+pub mod names;
+pub mod reputation;
+pub mod verifier;
+pub mod vesting;
 pub mod workflow;
-pub mod types;
\ No newline at end of file
+pub mod types;
+pub mod yield_adapter;
\ No newline at end of file
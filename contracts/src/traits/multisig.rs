@@ -0,0 +1,18 @@
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+
+#[openbrush::wrapper]
+pub type MultisigRef = dyn Multisig;
+
+/// The multisig probe standard, implemented by multisig wallets so that contracts can check
+/// an address is able to sign before handing it their ownership.
+#[openbrush::trait_definition]
+pub trait Multisig {
+    /// Returns `true` if the contract is a multisig wallet.
+    #[ink(message)]
+    fn is_multisig(&self) -> bool;
+
+    /// Returns the owners (i.e. the signers) of the multisig wallet.
+    #[ink(message)]
+    fn owners(&self) -> Vec<AccountId>;
+}
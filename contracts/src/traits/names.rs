@@ -0,0 +1,40 @@
+use ink::primitives::AccountId;
+use openbrush::contracts::traits::ownable::*;
+use super::types::HashValue;
+
+#[openbrush::wrapper]
+pub type NamesRef = dyn Names;
+
+/// The name resolution standard, mapping the hash of human-readable repository slugs
+/// (e.g. `"org/repo"`) to the workflow contract addresses, and back.
+#[openbrush::trait_definition]
+pub trait Names {
+    /// Returns the contract address a given `name` resolves to, if some.
+    #[ink(message)]
+    fn resolve(&self, name: HashValue) -> Option<AccountId>;
+
+    /// Returns the name a given contract `address` is registered under, if some.
+    #[ink(message)]
+    fn name_of(&self, address: AccountId) -> Option<HashValue>;
+}
+
+/// Errors that can occur upon calling a names contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum NamesError {
+    OwnableError(OwnableError),
+    /// Returned if the name is already registered.
+    NameAlreadyRegistered,
+    /// Returned if the name is not registered.
+    UnknownName,
+    /// Returned if caller is not the owner of the name.
+    CallerIsNotNameOwner,
+    /// Returned if the address is already registered under another name.
+    AddressAlreadyNamed,
+}
+
+impl From<OwnableError> for NamesError {
+    fn from(error: OwnableError) -> Self {
+        NamesError::OwnableError(error)
+    }
+}
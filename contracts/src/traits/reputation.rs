@@ -0,0 +1,35 @@
+use openbrush::contracts::traits::ownable::*;
+use super::types::HashValue;
+
+#[openbrush::wrapper]
+pub type ReputationRef = dyn Reputation;
+
+/// The cross-contract reputation read standard, implemented by reputation sources
+/// (e.g. a leaderboard) and consumed by the reward contracts (e.g. for multipliers).
+#[openbrush::trait_definition]
+pub trait Reputation {
+    /// Returns the reputation points of a given contributor `identity`.
+    #[ink(message)]
+    fn reputation_of(&self, identity: HashValue) -> u64;
+
+    /// Returns the rank (starting at 1) of a given contributor `identity`, if ranked.
+    #[ink(message)]
+    fn rank_of(&self, identity: HashValue) -> Option<u32>;
+}
+
+/// Errors that can occur upon calling a reputation contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum ReputationError {
+    OwnableError(OwnableError),
+    /// Returned if caller is neither the `owner` nor an allowed reporter.
+    CallerNotAllowed,
+    /// Returned if the reputation points overflow.
+    Overflow,
+}
+
+impl From<OwnableError> for ReputationError {
+    fn from(error: OwnableError) -> Self {
+        ReputationError::OwnableError(error)
+    }
+}
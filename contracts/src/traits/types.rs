@@ -1 +1,44 @@
-pub type HashValue = [u8; 32];
\ No newline at end of file
+use ink::primitives::AccountId;
+
+pub type HashValue = [u8; 32];
+
+/// A recoverable ECDSA signature.
+pub type SignatureValue = [u8; 65];
+
+/// A code hosting platform contributors are identified on.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Platform {
+    #[default]
+    GitHub,
+    GitLab,
+    Codeberg,
+}
+
+/// A contributor identity on a given platform, `id_hash` being the hash of the platform ID
+/// (e.g. the hashed Github ID).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Identity {
+    pub platform: Platform,
+    pub id_hash: HashValue,
+}
+
+/// An asset rewards can be paid with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(::scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Asset {
+    /// The chain native currency.
+    Native,
+    /// A PSP22 token, referred by its contract address.
+    Psp22(AccountId),
+}
@@ -0,0 +1,40 @@
+use ink::prelude::vec::Vec;
+use ink::primitives::AccountId;
+use openbrush::contracts::traits::ownable::*;
+use super::types::HashValue;
+
+#[openbrush::wrapper]
+pub type IdentityVerifierRef = dyn IdentityVerifier;
+
+/// The identity verification standard, implemented by verifier contracts and consumed by
+/// the reward contracts upon identity registration.
+///
+/// Each verifier implements its own trust model, e.g. a platform signature, an oracle
+/// attestation, a commit-reveal secret or an owner confirmation, the `proof` being
+/// strategy specific (and possibly empty).
+#[openbrush::trait_definition]
+pub trait IdentityVerifier {
+    /// Verify that a given `account` controls a given `identity`.
+    #[ink(message)]
+    fn verify(
+        &mut self,
+        identity: HashValue,
+        account: AccountId,
+        proof: Vec<u8>,
+    ) -> Result<(), VerifierError>;
+}
+
+/// Errors that can occur upon calling an identity verifier contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum VerifierError {
+    OwnableError(OwnableError),
+    /// Returned if the `account` is not verified to control the `identity`.
+    NotVerified,
+}
+
+impl From<OwnableError> for VerifierError {
+    fn from(error: OwnableError) -> Self {
+        VerifierError::OwnableError(error)
+    }
+}
@@ -0,0 +1,36 @@
+use ink::primitives::AccountId;
+use openbrush::contracts::traits::ownable::*;
+
+#[openbrush::wrapper]
+pub type VestingRef = dyn Vesting;
+
+#[openbrush::trait_definition]
+pub trait Vesting {
+    /// Register the transferred value into a new vesting schedule for a given `beneficiary`.
+    /// This is triggered by the reward contracts upon claim.
+    #[ink(message, payable)]
+    fn vest(&mut self, beneficiary: AccountId) -> Result<(), VestingError>;
+}
+
+/// Errors that can occur upon calling the vesting contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum VestingError {
+    OwnableError(OwnableError),
+    /// Returned if caller is neither the `owner` nor an allowed reward contract.
+    CallerNotAllowed,
+    /// Returned if no value is transferred to vest.
+    NothingToVest,
+    /// Returned if the caller has no vested amount to withdraw.
+    NothingToWithdraw,
+    /// Attempted transfer failed.
+    TransferFailed,
+    /// Returned if an arithmetic operation on balances overflows.
+    Overflow,
+}
+
+impl From<OwnableError> for VestingError {
+    fn from(error: OwnableError) -> Self {
+        VestingError::OwnableError(error)
+    }
+}
@@ -1,18 +1,38 @@
-use openbrush::{contracts::traits::ownable::*, modifiers};
+use openbrush::{
+    contracts::traits::{access_control::*, ownable::*, pausable::*},
+    modifiers,
+};
 use super::types::HashValue;
+use ink::primitives::AccountId;
+use kudos_core::ClaimError;
+
+/// The role administering the other roles and the configuration (i.e. the openbrush default admin role).
+pub const ADMIN: RoleType = 0;
+
+/// The role allowed to approve contributions (e.g. the maintainers team).
+pub const APPROVER: RoleType = ink::selector_id!("APPROVER");
+
+/// The role allowed to manage the funds (e.g. park them in a yield adapter).
+pub const FUNDER: RoleType = ink::selector_id!("FUNDER");
+
+/// The role allowed to pause and unpause the contract.
+pub const PAUSER: RoleType = ink::selector_id!("PAUSER");
+
+/// All the roles, the admin role last so that it can be renounced after the others.
+pub const ROLES: [RoleType; 4] = [APPROVER, FUNDER, PAUSER, ADMIN];
 
 #[openbrush::wrapper]
-pub type WorkflowdRef = dyn Workflow + Ownable;
+pub type WorkflowdRef = dyn Workflow + AccessControl + Ownable;
 
 #[openbrush::trait_definition]
-pub trait Workflow: Ownable {
+pub trait Workflow: AccessControl + Ownable {
     /// Register the caller as an aspiring contributor.
     #[ink(message)]
     fn register_identity(&mut self, identity: HashValue) -> Result<(), WorkflowError>;
 
     /// Approve contribution. This is triggered by a workflow run.
     #[ink(message)]
-    #[modifiers(only_owner)]
+    #[modifiers(only_role(APPROVER))]
     fn approve(
         &mut self,
         contribution_id: u64,
@@ -28,27 +48,292 @@ pub trait Workflow: Ownable {
     fn claim(&mut self, contribution_id: u64) -> Result<(), WorkflowError>;
 }
 
+/// Declares the errors along with their catalog, so that every error comes with a description
+/// (i.e. its doc comment) and a remediation hint.
+macro_rules! workflow_errors {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident {
+            $(
+                $(#[doc = $doc:literal])*
+                $variant:ident $(($field:ty))? => $hint:literal,
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        pub enum $name {
+            $(
+                $(#[doc = $doc])*
+                $variant $(($field))?,
+            )*
+        }
+
+        impl $name {
+            /// The name, description and remediation hint of every error, indexed by its code.
+            pub const CATALOG: &'static [(&'static str, &'static str, &'static str)] =
+                &[$((stringify!($variant), concat!($($doc),*), $hint)),*];
+        }
+    };
+}
+
+workflow_errors! {
 /// Errors that can occur upon calling this contract.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
 pub enum WorkflowError {
-    OwnableError(OwnableError),
+    /// The caller is not the owner.
+    OwnableError(OwnableError) => "Call the message from the owner account.",
+    /// The contract is not in the expected paused state.
+    PausableError(PausableError) => "Check whether the contract is paused before pausing or unpausing it.",
     /// An aspiring contributor identity is already registered in the DB.
-    IdentityAlreadyRegistered,
+    IdentityAlreadyRegistered => "Use the registered identity, or unregister it first.",
     /// A `contribution` is already approved in the DB.
-    ContributionAlreadyApproved,
+    ContributionAlreadyApproved => "Nothing to do, the contribution is already approved.",
     /// No `contribution` is approved yet in the DB.
-    NoContributionApprovedYet,
+    NoContributionApprovedYet => "Wait for a contribution to be approved.",
     /// Contributor identity is not registered in the DB.
-    UnknownContributor,
+    UnknownContributor => "Register the contributor identity first.",
     /// Contribution is not in the DB.
-    UnknownContribution,
+    UnknownContribution => "Check the contribution id, or wait for its approval.",
     /// Attempted reward payment to a contributor failed.
-    PaymentFailed,
+    PaymentFailed => "Retry later, or ask the maintainers to fund the contract.",
     /// Returned if caller is not the `contributor` while required to.
-    CallerIsNotContributor,
+    CallerIsNotContributor => "Claim from the account registered for the contributor identity.",
     /// Returned when attempting to claim an already claimed reward.
-    AlreadyClaimed,
+    AlreadyClaimed => "Nothing to do, the reward is already paid.",
+    /// Returned if caller is neither the `owner` nor the `guardian` while required to.
+    CallerIsNotGuardian => "Call the message from the owner or the guardian account.",
+    /// Returned when a signature is required but no platform key is set.
+    PlatformKeyNotSet => "Ask the owner to set the platform key.",
+    /// Returned if a signature does not match the expected signer.
+    InvalidSignature => "Sign the expected payload with the expected key.",
+    /// Returned when the contract has been terminated by the owner.
+    ContractTerminated => "Request a refund, the contract does not accept new operations.",
+    /// Returned when refunds are processed before the contract is terminated.
+    ContractNotTerminated => "Wait for the owner to terminate the contract.",
+    /// Returned if a reward multiplier is lower than 1x.
+    InvalidMultiplier => "Use a multiplier of at least 1x.",
+    /// Returned if an arithmetic operation on balances overflows.
+    Overflow => "Use smaller amounts.",
+    /// Returned when funds are sent without context while in strict funding mode.
+    UntrackedTransfer => "Fund the contract with the `fund` message.",
+    /// Returned if a change is already queued for the parameter.
+    ChangeAlreadyQueued => "Execute or cancel the queued change first.",
+    /// Returned if no change is queued for the parameter.
+    ChangeNotQueued => "Queue the change first.",
+    /// Returned if the queued change delay has not elapsed yet.
+    ChangeNotReady => "Retry once the change delay has elapsed.",
+    /// Returned if a change delay is lowered.
+    InvalidDelay => "Use a delay at least as long as the current one.",
+    /// Returned if an approval lacks the issue closure statement while required to.
+    ClosureProofRequired => "Approve with `approve_with_closure` and the oracle statement.",
+    /// Returned when a closure statement is provided but no oracle key is set.
+    OracleKeyNotSet => "Ask the owner to set the oracle key.",
+    /// Returned if the closure statement does not refer to the approved contribution.
+    InvalidClosureStatement => "Provide the closure statement of the approved contribution.",
+    /// Returned if the linked issue is not closed as completed.
+    IssueNotCompleted => "Close the linked issue as completed first.",
+    /// Returned if the contributor identity registration has expired.
+    IdentityExpired => "Re-attest the identity registration.",
+    /// Returned when a re-attestation lacks the platform signature while required to.
+    SignatureRequired => "Provide the platform signature.",
+    /// Returned if the payout basket shares do not add up to 100% or repeat an asset.
+    InvalidBasket => "Make the shares add up to 100% with distinct assets.",
+    /// Returned if the caller has no pending payout for the asset.
+    NoPendingPayout => "Nothing to withdraw for this asset.",
+    /// Returned if the contributor identity claimed a reward during the cooldown.
+    ClaimCooldown => "Retry once the claim cooldown has elapsed.",
+    /// Contribution template is not in the DB.
+    UnknownTemplate => "Check the template id.",
+    /// Returned if a contribution template has too many tags.
+    TooManyTags => "Use fewer tags.",
+    /// Returned when attempting to claim the reward of an expired contribution.
+    ContributionExpired => "Ask the maintainers to approve the contribution again.",
+    /// Returned if a share is greater than 100%.
+    InvalidShare => "Use a share of at most 100%.",
+    /// Returned if the transferred value does not match the challenge bond.
+    InvalidBond => "Transfer exactly the challenge bond.",
+    /// Returned if the contribution approval cannot be challenged anymore.
+    DisputeWindowClosed => "Nothing to do, the approval cannot be challenged anymore.",
+    /// Returned when attempting to claim the reward of a contribution in its dispute window.
+    DisputeWindowOpen => "Retry once the dispute window has closed.",
+    /// Returned if the contribution approval is challenged.
+    ContributionChallenged => "Wait for the arbitrator to resolve the challenge.",
+    /// Returned if the contribution approval is not challenged.
+    NoChallenge => "Check the contribution id.",
+    /// Returned if caller is not the `arbitrator` while required to.
+    CallerIsNotArbitrator => "Call the message from the arbitrator account.",
+    /// Returned if an approval exceeds the workflow run limits.
+    RunLimitExceeded => "Split the approvals across workflow runs.",
+    /// Returned when claiming with a boost but no boost collection is set.
+    BoostNotSet => "Claim without a boost.",
+    /// Returned if the boost NFT cannot be burned (e.g. not held by the contributor).
+    BoostBurnFailed => "Claim with a boost NFT held by the contributor account.",
+    /// Returned if caller has not funded the contract while required to.
+    CallerIsNotFunder => "Call the message from a funder account.",
+    /// Returned if the contribution approval has already been vetoed.
+    AlreadyVetoed => "Wait for the arbitrator to resolve the veto.",
+    /// Returned if the contributor reputation is below the template tier minimum.
+    InsufficientReputation => "Contribute to lower tiers to earn reputation first.",
+    /// Returned if the circuit breaker window is zero.
+    InvalidCircuitBreaker => "Use a non-zero window.",
+    /// Returned if no remark proof challenge is pending for the identity.
+    NoRemarkChallenge => "Request a remark proof challenge first.",
+    /// Returned if the identity verifier rejects the identity registration.
+    IdentityNotVerified => "Provide a proof accepted by the identity verifier.",
+    /// Returned when parking funds but no yield adapter is set.
+    YieldAdapterNotSet => "Ask the owner to set the yield adapter.",
+    /// Returned if a call to the yield adapter fails.
+    YieldAdapterFailed => "Retry later, or check the yield adapter.",
+    /// Returned when replacing the yield adapter while funds are still parked.
+    FundsParked => "Unpark the funds first.",
+    /// Returned if the parked funds are lower than the amount to unpark.
+    InsufficientParkedFunds => "Unpark at most the parked funds.",
+    /// Returned if the approval is not finalized yet.
+    ApprovalNotFinalized => "Finalize the approval, or wait for its finality depth.",
+    /// Returned if the approval is not pending finality.
+    NotPendingFinality => "Nothing to do, the approval is already final.",
+    /// Returned when finalizing an approval before the finality depth is reached.
+    FinalityDepthNotReached => "Retry once the finality depth is reached.",
+    /// Returned if a backup is set without a heartbeat period.
+    InvalidHeartbeatPeriod => "Set a heartbeat period along with the backup.",
+    /// Returned if the caller is not the backup account.
+    CallerIsNotBackup => "Call the message from the backup account.",
+    /// Returned when assuming ownership before the heartbeat period has elapsed.
+    OwnerStillActive => "Retry once the heartbeat period has elapsed.",
+    /// Returned if the claim lacks the memo or the tax reference required in compliance mode.
+    PaymentReferenceRequired => "Claim with a memo and a tax reference.",
+    /// Returned if the claim splits are empty, too many or don't add up to 100 percent.
+    InvalidSplits => "Use at most the maximum number of splits adding up to 100%.",
+    /// Returned if the identity or the person is already linked.
+    IdentityAlreadyLinked => "Unlink the identity or the person first.",
+    /// Returned if the person of the contributor reached the claims cap.
+    ClaimsCapReached => "Nothing to do, the person reached the claims cap.",
+    /// Returned if the contract balance covers the reward.
+    NotUnderfunded => "Claim the reward instead.",
+    /// Returned if the late payout of the contribution is already reported.
+    LatePayoutAlreadyReported => "Wait for the maintainers to fund the contract.",
+    /// Returned if the maximum number of pre-approved contributions is reached.
+    TooManyUnboundApprovals => "Wait for the pre-approved contributors to register.",
+    /// Returned if there are more auditors than `MAX_AUDITORS`.
+    TooManyAuditors => "Use fewer auditors.",
+    /// Returned if the approval is pending a spot check.
+    SpotCheckPending => "Wait for the auditor to confirm the approval.",
+    /// Returned if the approval is not pending a spot check.
+    NoSpotCheck => "Check the contribution id.",
+    /// Returned if caller is neither the assigned auditor nor the `owner`.
+    CallerIsNotAuditor => "Call the message from the assigned auditor or the owner account.",
+    /// Returned when proposing a contribution but no badge collection is set.
+    BadgeNotSet => "Ask the owner to set the badge collection.",
+    /// Returned if the contribution is not proposed or its vote is closed.
+    NoProposal => "Propose the contribution first.",
+    /// Returned if the voting period is still running.
+    VotingOpen => "Retry once the voting period is over.",
+    /// Returned if the voting period is over.
+    VotingClosed => "Nothing to do, the vote is closed.",
+    /// Returned if the tier has no reward.
+    UnknownTier => "Vote for a tier with a reward.",
+    /// Returned if the caller already voted.
+    AlreadyVoted => "Nothing to do, the vote is counted.",
+    /// Returned if the caller holds no badge.
+    CallerIsNotBadgeHolder => "Vote from an account holding a badge.",
+    /// Returned if the class cannot be delegated to a proxy.
+    InvalidProxyClass => "Delegate a claim or query class only.",
+    /// Returned if the caller is not a proxy of the account for the class of the call.
+    CallerIsNotProxy => "Ask the account to add the caller as a proxy for this class.",
+    /// Returned when approving with metrics but no payout formula is set.
+    PayoutFormulaNotSet => "Ask the owner to set the payout formula.",
+    /// Returned if the payout formula has too many coefficients or exceeds its maximum reward.
+    InvalidPayoutFormula => "Use fewer coefficients or a lower maximum reward.",
+    /// Returned if the metrics statement is not issued for this contract and contribution,
+    /// or doesn't match the payout formula.
+    InvalidMetricsStatement => "Provide the metrics statement issued for this contribution.",
+    /// Returned if the free balance cannot cover the reward reserved on approval.
+    InsufficientFunds => "Ask the maintainers to fund the contract.",
+    /// Returned if the earmark backs no or too many contributions, an approved contribution,
+    /// or a contribution already backed by too many earmarks.
+    InvalidEarmark => "Earmark a few open issues only.",
+    /// Returned if the earmark doesn't exist.
+    UnknownEarmark => "Check the earmark id.",
+    /// Returned if an issue backed by the earmark is still open.
+    EarmarkInUse => "Wait for the backed issues to be closed.",
+    /// Returned if the escalation increment is zero.
+    InvalidEscalation => "Use a non-zero increment.",
+    /// Returned when registering an identity locally while the shared identity registry is set.
+    SharedIdentityRegistrySet => "Register the identity in the shared identity registry.",
+    /// Returned if the approval signer is not trusted, or if the signer to add, remove or
+    /// rotate is respectively already trusted, not trusted or replaced by a trusted one.
+    InvalidSigner => "Check the signer accounts.",
+    /// Returned if the signed approval has already been accepted.
+    SignatureAlreadyUsed => "Nothing to do, the approval is already accepted.",
+    /// Returned when registering an identity without its handle while the canonical identities are enabled.
+    HandleRequired => "Register the identity with `register_handle`.",
+    /// Returned if the canonical form of the handle collides with the handle registered for the given account.
+    IdentityCollision(AccountId) => "Use the account already registered for the handle.",
+    /// The caller misses the role required.
+    AccessControlError(AccessControlError) => "Call the message from an account with the required role.",
+    /// The contract is paused (e.g. during an incident).
+    ContractPaused => "Retry once the contract is unpaused.",
+    /// Trial contributions are disabled.
+    TrialsDisabled => "Approve the contribution as a regular one.",
+    /// The identity reached the maximum number of trial contributions.
+    TrialCapReached => "Approve the contribution as a regular one.",
+    /// The contract code cannot be upgraded (e.g. unknown code hash).
+    UpgradeFailed => "Check the code hash.",
+    /// The storage is already migrated to the version of the current code.
+    AlreadyMigrated => "Nothing to do, the storage is up to date.",
+    /// The session scope is invalid (e.g. too many messages allowed).
+    InvalidSession => "Allow fewer messages.",
+    /// The session account is not allowed to call the message, or expired.
+    SessionNotAllowed => "Authorize the session for this message, or renew it.",
+    /// The session account reached the maximum reward value it can approve.
+    SessionLimitExceeded => "Authorize a new session.",
+    /// Too many identities and contributions imported at once.
+    ImportBatchTooLarge => "Import fewer records at once.",
+    /// The reputation attestation is issued by an untrusted deployment.
+    UnknownReputationSource => "Import attestations of a trusted deployment.",
+    /// Too many contributions claimed at once.
+    ClaimBatchTooLarge => "Claim fewer contributions at once.",
+    /// The contribution can still be claimed.
+    ContributionNotExpired => "Nothing to do, the reward can still be claimed.",
+    /// The new owner is not a multisig contract with owners.
+    NotAMultisig => "Transfer the ownership to a multisig contract.",
+    /// The caller is not the pending owner.
+    CallerIsNotPendingOwner => "Accept the ownership from the pending owner account.",
+    /// Returned if the identity is registered through the shared identity registry.
+    IdentityNotUnregistrable => "Unregister the identity from the shared identity registry.",
+    /// Returned if the insurance contract fails to cover an approval.
+    InsuranceFailed => "Check the insurance pool, or disable the insurance.",
+    /// Returned if a payout asset is not in the asset allowlist.
+    AssetNotAllowed => "Ask the owner to allow the asset.",
+    /// Returned if the account is already bound to another identity.
+    AccountAlreadyBound => "Register the identity from another account.",
+    /// Returned if the approver is the contributor while separation of duties is enforced.
+    SelfApprovalForbidden => "Ask another approver to approve the contribution.",
+    /// Returned if the verifier key is unknown, not valid at the current time, or if its
+    /// validity window is empty.
+    InvalidVerifierKey => "Sign with a verifier key valid at the current time.",
+    /// Returned if the native share of a payout exceeds 100%.
+    InvalidPayoutRatio => "Use a native share of at most `BASIS_POINTS`.",
+    /// Returned if caller is not the insurance pool.
+    CallerIsNotInsurance => "Call the message from the insurance pool.",
+    /// Returned if a message is called with an unknown selector and no value.
+    UnknownSelector => "Check the selector against the contract metadata.",
+}
+}
+
+impl WorkflowError {
+    /// Returns the code of the error, i.e. the index of its variant in the SCALE encoding.
+    pub fn code(&self) -> u8 {
+        scale::Encode::encode(self)[0]
+    }
+
+    /// Returns the name, description and remediation hint of the error of a given `code`, if some.
+    pub fn describe(code: u8) -> Option<(&'static str, &'static str, &'static str)> {
+        Self::CATALOG
+            .get(usize::from(code))
+            .map(|(name, description, hint)| (*name, description.trim(), *hint))
+    }
 }
 
 impl From<OwnableError> for WorkflowError {
@@ -56,3 +341,28 @@ impl From<OwnableError> for WorkflowError {
         WorkflowError::OwnableError(error)
     }
 }
+
+impl From<AccessControlError> for WorkflowError {
+    fn from(error: AccessControlError) -> Self {
+        WorkflowError::AccessControlError(error)
+    }
+}
+
+impl From<PausableError> for WorkflowError {
+    fn from(error: PausableError) -> Self {
+        match error {
+            PausableError::Paused => WorkflowError::ContractPaused,
+            error => WorkflowError::PausableError(error),
+        }
+    }
+}
+
+impl From<ClaimError> for WorkflowError {
+    fn from(error: ClaimError) -> Self {
+        match error {
+            ClaimError::CallerIsNotContributor => WorkflowError::CallerIsNotContributor,
+            ClaimError::AlreadyClaimed => WorkflowError::AlreadyClaimed,
+            ClaimError::ContributionExpired => WorkflowError::ContributionExpired,
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use ink::primitives::AccountId;
+
+#[openbrush::wrapper]
+pub type YieldAdapterRef = dyn YieldAdapter;
+
+/// The yield adapter standard, implemented by adapters earning on idle native balances
+/// (e.g. through a liquid staking token) on behalf of the reward contracts.
+#[openbrush::trait_definition]
+pub trait YieldAdapter {
+    /// Deposit the transferred value for the caller.
+    #[ink(message, payable)]
+    fn deposit(&mut self) -> Result<(), YieldAdapterError>;
+
+    /// Withdraw `amount` of the caller deposit, including the earned yield.
+    #[ink(message)]
+    fn withdraw(&mut self, amount: u128) -> Result<(), YieldAdapterError>;
+
+    /// Returns the deposit of a given `owner`, including the earned yield.
+    #[ink(message)]
+    fn balance_of(&self, owner: AccountId) -> u128;
+}
+
+/// Errors that can occur upon calling a yield adapter contract.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(::scale_info::TypeInfo))]
+pub enum YieldAdapterError {
+    /// Returned if the caller deposit is lower than the requested amount.
+    InsufficientBalance,
+    /// Returned if the underlying position cannot be unwound yet (e.g. unbonding period).
+    Unavailable,
+    /// Attempted transfer failed.
+    TransferFailed,
+}
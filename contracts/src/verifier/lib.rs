@@ -0,0 +1,193 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod verifier {
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use kudos_ink_contracts::traits::types::HashValue;
+    use kudos_ink_contracts::traits::verifier::{VerifierError, *};
+    use openbrush::{modifiers, traits::Storage};
+
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// An identity verifier relying on owner confirmations.
+    ///
+    /// The owner (e.g. the Kudos backend once the contributor proved control of the
+    /// identity off-chain) confirms the account of every identity, and the verification
+    /// succeeds for the confirmed account only. No proof is expected.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct OwnerConfirmationVerifier {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The confirmed account per identity.
+        confirmations: Mapping<HashValue, AccountId>,
+    }
+
+    /// Emitted when the account of an `identity` is confirmed or revoked.
+    #[ink(event)]
+    pub struct ConfirmationUpdated {
+        identity: HashValue,
+        account: Option<AccountId>,
+    }
+
+    impl IdentityVerifier for OwnerConfirmationVerifier {
+        /// Verify that a given `account` is confirmed for a given `identity`.
+        #[ink(message)]
+        fn verify(
+            &mut self,
+            identity: HashValue,
+            account: AccountId,
+            proof: Vec<u8>,
+        ) -> Result<(), VerifierError> {
+            self.verify(identity, account, proof)
+        }
+    }
+
+    impl Introspection for OwnerConfirmationVerifier {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl OwnerConfirmationVerifier {
+        /// Constructor that initializes the verifier.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            instance
+        }
+
+        /// Verify that a given `account` is confirmed for a given `identity`, the `proof` is ignored.
+        #[ink(message)]
+        pub fn verify(
+            &mut self,
+            identity: HashValue,
+            account: AccountId,
+            _proof: Vec<u8>,
+        ) -> Result<(), VerifierError> {
+            if self.confirmations.get(identity) != Some(account) {
+                return Err(VerifierError::NotVerified);
+            }
+            Ok(())
+        }
+
+        /// Confirm the `account` of a given `identity`, or revoke the confirmation if none.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn confirm(&mut self, identity: HashValue, account: Option<AccountId>) -> Result<(), VerifierError> {
+            if let Some(account) = account {
+                self.confirmations.insert(identity, &account);
+            } else {
+                self.confirmations.remove(identity);
+            }
+
+            self.env().emit_event(ConfirmationUpdated { identity, account });
+
+            Ok(())
+        }
+
+        /// Simply returns the confirmed account of a given `identity` if some.
+        #[ink(message)]
+        pub fn get_confirmation(&self, identity: HashValue) -> Option<AccountId> {
+            self.confirmations.get(identity)
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(
+                interface_id,
+                interface_ids::INTROSPECTION | interface_ids::IDENTITY_VERIFIER
+            )
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB -> contributor
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let verifier = create_contract();
+            assert_eq!(Ownable::owner(&verifier), Some(accounts.alice));
+            assert_eq!(verifier.get_confirmation([0; 32]), None);
+        }
+
+        #[ink::test]
+        fn verify_works() {
+            let accounts = default_accounts();
+            let mut verifier = create_contract();
+            let identity = [1; 32];
+            assert_eq!(
+                verifier.verify(identity, accounts.bob, Vec::new()),
+                Err(VerifierError::NotVerified)
+            );
+
+            assert_eq!(verifier.confirm(identity, Some(accounts.bob)), Ok(()));
+            assert_eq!(verifier.verify(identity, accounts.bob, Vec::new()), Ok(()));
+            assert_eq!(
+                verifier.verify(identity, accounts.charlie, Vec::new()),
+                Err(VerifierError::NotVerified)
+            );
+
+            assert_eq!(verifier.confirm(identity, None), Ok(()));
+            assert_eq!(
+                verifier.verify(identity, accounts.bob, Vec::new()),
+                Err(VerifierError::NotVerified)
+            );
+        }
+
+        #[ink::test]
+        fn only_owner_can_confirm() {
+            let accounts = default_accounts();
+            let mut verifier = create_contract();
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                verifier.confirm([1; 32], Some(accounts.bob)),
+                Err(VerifierError::OwnableError(OwnableError::CallerIsNotOwner))
+            );
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let verifier = create_contract();
+            assert!(verifier.supports_interface(interface_ids::INTROSPECTION));
+            assert!(verifier.supports_interface(interface_ids::IDENTITY_VERIFIER));
+            assert!(!verifier.supports_interface(interface_ids::WORKFLOW_V1));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        /// Creates a new instance of `OwnerConfirmationVerifier`.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract() -> OwnerConfirmationVerifier {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            OwnerConfirmationVerifier::new()
+        }
+    }
+}
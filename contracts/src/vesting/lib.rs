@@ -0,0 +1,467 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+pub mod vesting {
+    use kudos_ink_contracts::math::checked_mul_div;
+    use kudos_ink_contracts::traits::vesting::{VestingError, *};
+    use kudos_ink_contracts::traits::introspection::{interface_ids, *};
+    use openbrush::{modifiers, traits::Storage};
+
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// The maximum number of vesting schedules processed per withdrawal.
+    pub const MAX_SCHEDULES_PER_WITHDRAWAL: u32 = 32;
+
+    /// A vesting schedule is represented by:
+    /// - the total amount vested.
+    /// - the amount already withdrawn.
+    /// - the timestamp the schedule starts from.
+    /// - the duration (in milliseconds) before anything is unlocked.
+    /// - the duration (in milliseconds) over which the total amount is linearly unlocked.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(ink::storage::traits::StorageLayout, scale_info::TypeInfo)
+    )]
+    pub struct Schedule {
+        total: Balance,
+        withdrawn: Balance,
+        start: Timestamp,
+        cliff: Timestamp,
+        duration: Timestamp,
+    }
+
+    /// A companion vesting contract for the reward contracts.
+    ///
+    /// Rewards routed here upon claim are locked until the cliff, then linearly unlocked
+    /// over the vesting duration, and withdrawn by the contributors with `vested_withdraw`.
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct LinearVesting {
+        #[storage_field]
+        ownable: ownable::Data,
+
+        // The duration (in milliseconds) before anything is unlocked.
+        cliff: Timestamp,
+
+        // The duration (in milliseconds) over which the vested amount is linearly unlocked.
+        duration: Timestamp,
+
+        // The vesting schedules per beneficiary and index.
+        schedules: Mapping<(AccountId, u32), Schedule>,
+
+        // The number of vesting schedules per beneficiary.
+        schedules_count: Mapping<AccountId, u32>,
+
+        // The index of the first vesting schedule not fully withdrawn per beneficiary.
+        first_schedules: Mapping<AccountId, u32>,
+
+        // The reward contracts allowed to vest.
+        callers: Mapping<AccountId, ()>,
+    }
+
+    /// Emitted when an `amount` is vested for a `beneficiary`.
+    #[ink(event)]
+    pub struct Vested {
+        #[ink(topic)]
+        beneficiary: AccountId,
+        amount: Balance,
+        start: Timestamp,
+    }
+
+    /// Emitted when a `beneficiary` withdraws their unlocked amount.
+    #[ink(event)]
+    pub struct Withdrawn {
+        #[ink(topic)]
+        beneficiary: AccountId,
+        amount: Balance,
+    }
+
+    /// Emitted when a reward contract is allowed or disallowed to vest.
+    #[ink(event)]
+    pub struct CallerUpdated {
+        #[ink(topic)]
+        caller: AccountId,
+        allowed: bool,
+    }
+
+    impl Vesting for LinearVesting {
+        /// Register the transferred value into a new vesting schedule for a given `beneficiary`.
+        ///
+        /// Constraint(s):
+        /// 1. The caller must be the owner or an allowed reward contract.
+        /// 2. The transferred value must not be zero.
+        ///
+        /// A `Vested` event is emitted.
+        #[ink(message, payable)]
+        fn vest(&mut self, beneficiary: AccountId) -> Result<(), VestingError> {
+            self.vest(beneficiary)
+        }
+    }
+
+    impl Introspection for LinearVesting {
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        fn supports_interface(&self, interface_id: u32) -> bool {
+            self.supports_interface(interface_id)
+        }
+    }
+
+    impl LinearVesting {
+        /// Constructor that initializes the vesting `cliff` and `duration` (in milliseconds).
+        #[ink(constructor)]
+        pub fn new(cliff: Timestamp, duration: Timestamp) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            ownable::Internal::_init_with_owner(&mut instance, caller);
+            Self {
+                cliff,
+                duration,
+                ..instance
+            }
+        }
+
+        /// Register the transferred value into a new vesting schedule for a given `beneficiary`.
+        ///
+        /// Only callable by the owner or an allowed reward contract.
+        #[ink(message, payable)]
+        pub fn vest(&mut self, beneficiary: AccountId) -> Result<(), VestingError> {
+            let caller = Self::env().caller();
+            if Some(caller) != self.ownable.owner && !self.callers.contains(caller) {
+                return Err(VestingError::CallerNotAllowed);
+            }
+
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(VestingError::NothingToVest);
+            }
+
+            let start = self.env().block_timestamp();
+            let count = self.get_schedules_count(beneficiary);
+            self.schedules.insert(
+                (beneficiary, count),
+                &Schedule {
+                    total: amount,
+                    withdrawn: 0,
+                    start,
+                    cliff: self.cliff,
+                    duration: self.duration,
+                },
+            );
+            self.schedules_count.insert(beneficiary, &(count + 1));
+
+            self.env().emit_event(Vested {
+                beneficiary,
+                amount,
+                start,
+            });
+
+            Ok(())
+        }
+
+        /// Withdraw the unlocked amount of the caller vesting schedules, up to
+        /// `MAX_SCHEDULES_PER_WITHDRAWAL` schedules from the first one not fully withdrawn.
+        ///
+        /// The fully withdrawn schedules are removed. A `Withdrawn` event is emitted.
+        #[ink(message)]
+        pub fn vested_withdraw(&mut self) -> Result<(), VestingError> {
+            let beneficiary = Self::env().caller();
+
+            let mut amount: Balance = 0;
+            let mut schedules = Vec::new();
+            let pending = self.pending_schedules(beneficiary);
+            for index in pending.clone() {
+                let Some(schedule) = self.schedules.get((beneficiary, index)) else {
+                    continue;
+                };
+                let withdrawable = self.unlocked(&schedule)?.saturating_sub(schedule.withdrawn);
+                if withdrawable > 0 {
+                    amount = amount.checked_add(withdrawable).ok_or(VestingError::Overflow)?;
+                    schedules.push((index, schedule.withdrawn + withdrawable, schedule));
+                }
+            }
+            if amount == 0 {
+                return Err(VestingError::NothingToWithdraw);
+            }
+
+            if self.env().transfer(beneficiary, amount).is_err() {
+                return Err(VestingError::TransferFailed);
+            }
+
+            for (index, withdrawn, schedule) in schedules {
+                if withdrawn >= schedule.total {
+                    self.schedules.remove((beneficiary, index));
+                } else {
+                    self.schedules
+                        .insert((beneficiary, index), &Schedule { withdrawn, ..schedule });
+                }
+            }
+            // Skip the removed schedules for the next withdrawals
+            let first = pending
+                .clone()
+                .find(|index| self.schedules.contains((beneficiary, *index)))
+                .unwrap_or(pending.end);
+            self.first_schedules.insert(beneficiary, &first);
+
+            self.env().emit_event(Withdrawn { beneficiary, amount });
+
+            Ok(())
+        }
+
+        /// Allow or disallow a reward contract to vest.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_caller(&mut self, caller: AccountId, allowed: bool) -> Result<(), VestingError> {
+            if allowed {
+                self.callers.insert(caller, &());
+            } else {
+                self.callers.remove(caller);
+            }
+
+            self.env().emit_event(CallerUpdated { caller, allowed });
+
+            Ok(())
+        }
+
+        /// Update the vesting `cliff` and `duration` (in milliseconds), applied to the new schedules only.
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        pub fn set_config(&mut self, cliff: Timestamp, duration: Timestamp) -> Result<(), VestingError> {
+            self.cliff = cliff;
+            self.duration = duration;
+            Ok(())
+        }
+
+        /// Returns the amount a given `beneficiary` can withdraw now, i.e. with a single
+        /// `vested_withdraw`.
+        #[ink(message)]
+        pub fn get_withdrawable(&self, beneficiary: AccountId) -> Result<Balance, VestingError> {
+            let mut amount: Balance = 0;
+            for index in self.pending_schedules(beneficiary) {
+                if let Some(schedule) = self.schedules.get((beneficiary, index)) {
+                    let withdrawable = self.unlocked(&schedule)?.saturating_sub(schedule.withdrawn);
+                    amount = amount.checked_add(withdrawable).ok_or(VestingError::Overflow)?;
+                }
+            }
+            Ok(amount)
+        }
+
+        /// Simply returns the vesting schedule of a given `beneficiary` at a given `index` if some.
+        #[ink(message)]
+        pub fn get_schedule(&self, beneficiary: AccountId, index: u32) -> Option<Schedule> {
+            self.schedules.get((beneficiary, index))
+        }
+
+        /// Simply returns the number of vesting schedules of a given `beneficiary`.
+        #[ink(message)]
+        pub fn get_schedules_count(&self, beneficiary: AccountId) -> u32 {
+            self.schedules_count.get(beneficiary).unwrap_or(0)
+        }
+
+        /// Simply returns the index of the first vesting schedule of a given `beneficiary` not
+        /// fully withdrawn.
+        #[ink(message)]
+        pub fn get_first_schedule(&self, beneficiary: AccountId) -> u32 {
+            self.first_schedules.get(beneficiary).unwrap_or(0)
+        }
+
+        /// Returns `true` if the contract implements the interface of a given `interface_id`.
+        #[ink(message)]
+        pub fn supports_interface(&self, interface_id: u32) -> bool {
+            matches!(
+                interface_id,
+                interface_ids::INTROSPECTION | interface_ids::VESTING
+            )
+        }
+
+        /// Simply checks if a given `caller` is allowed to vest.
+        #[ink(message)]
+        pub fn is_allowed_caller(&self, caller: AccountId) -> bool {
+            self.callers.contains(caller)
+        }
+
+        /// A helper function to compute the indices of the vesting schedules of a given
+        /// `beneficiary` processed by the next withdrawal.
+        fn pending_schedules(&self, beneficiary: AccountId) -> core::ops::Range<u32> {
+            let first = self.get_first_schedule(beneficiary);
+            let end = first
+                .saturating_add(MAX_SCHEDULES_PER_WITHDRAWAL)
+                .min(self.get_schedules_count(beneficiary));
+            first..end
+        }
+
+        /// A helper function to compute the unlocked amount of a vesting `schedule`.
+        ///
+        /// Nothing is unlocked before the cliff, the vested amount is then linearly
+        /// unlocked over the vesting duration.
+        fn unlocked(&self, schedule: &Schedule) -> Result<Balance, VestingError> {
+            let elapsed = self.env().block_timestamp().saturating_sub(schedule.start);
+            if elapsed < schedule.cliff {
+                return Ok(0);
+            }
+            if elapsed >= schedule.duration {
+                return Ok(schedule.total);
+            }
+
+            checked_mul_div(
+                schedule.total,
+                Balance::from(elapsed),
+                Balance::from(schedule.duration),
+            )
+            .ok_or(VestingError::Overflow)
+        }
+    }
+
+    /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
+    /// module and test functions are marked with a `#[test]` attribute.
+    /// The below code is technically just normal Rust code.
+    #[cfg(test)]
+    mod tests {
+        // Accounts
+        // ALICE -> contract owner
+        // BOB -> reward contract
+        // CHARLIE -> contributor
+
+        /// Imports all the definitions from the outer scope so we can use them here.
+        use super::*;
+
+        const CLIFF: Timestamp = 100;
+        const DURATION: Timestamp = 1_000;
+
+        /// We test if the constructor does its job.
+        #[ink::test]
+        fn new_works() {
+            let accounts = default_accounts();
+            let vesting = create_contract();
+            assert_eq!(Ownable::owner(&vesting), Some(accounts.alice));
+            assert!(!vesting.is_allowed_caller(accounts.bob));
+            assert_eq!(vesting.get_schedules_count(accounts.charlie), 0);
+        }
+
+        #[ink::test]
+        fn only_allowed_caller_can_vest() {
+            let accounts = default_accounts();
+            let mut vesting = create_contract();
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                ink::env::pay_with_call!(vesting.vest(accounts.charlie), 100),
+                Err(VestingError::CallerNotAllowed)
+            );
+
+            set_next_caller(accounts.alice);
+            assert_eq!(vesting.set_caller(accounts.bob, true), Ok(()));
+            set_next_caller(accounts.bob);
+            assert_eq!(
+                ink::env::pay_with_call!(vesting.vest(accounts.charlie), 0),
+                Err(VestingError::NothingToVest)
+            );
+            assert_eq!(
+                ink::env::pay_with_call!(vesting.vest(accounts.charlie), 100),
+                Ok(())
+            );
+            assert_eq!(
+                vesting.get_schedule(accounts.charlie, 0),
+                Some(Schedule { total: 100, withdrawn: 0, start: 0, cliff: CLIFF, duration: DURATION })
+            );
+        }
+
+        #[ink::test]
+        fn vested_withdraw_works() {
+            let accounts = default_accounts();
+            let mut vesting = create_contract();
+            let _ = ink::env::pay_with_call!(vesting.vest(accounts.charlie), 100);
+
+            // Nothing is unlocked before the cliff
+            set_block_timestamp(CLIFF - 1);
+            set_next_caller(accounts.charlie);
+            assert_eq!(vesting.vested_withdraw(), Err(VestingError::NothingToWithdraw));
+
+            // Then linearly unlocked
+            set_block_timestamp(CLIFF);
+            assert_eq!(vesting.get_withdrawable(accounts.charlie), Ok(10));
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            assert_eq!(vesting.vested_withdraw(), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 10);
+            assert_eq!(vesting.get_withdrawable(accounts.charlie), Ok(0));
+
+            // A second schedule starts from its own vesting time
+            set_next_caller(accounts.alice);
+            let _ = ink::env::pay_with_call!(vesting.vest(accounts.charlie), 50);
+            set_block_timestamp(DURATION);
+            assert_eq!(vesting.get_withdrawable(accounts.charlie), Ok(90 + 45));
+            set_block_timestamp(DURATION + CLIFF);
+            set_next_caller(accounts.charlie);
+            assert_eq!(vesting.vested_withdraw(), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + 150);
+            assert_eq!(vesting.vested_withdraw(), Err(VestingError::NothingToWithdraw));
+
+            // The fully withdrawn schedules are removed
+            assert_eq!(vesting.get_schedule(accounts.charlie, 0), None);
+            assert_eq!(vesting.get_schedule(accounts.charlie, 1), None);
+            assert_eq!(vesting.get_first_schedule(accounts.charlie), 2);
+        }
+
+        #[ink::test]
+        fn vested_withdraw_is_bounded() {
+            let accounts = default_accounts();
+            let mut vesting = create_contract();
+            for _ in 0..=MAX_SCHEDULES_PER_WITHDRAWAL {
+                let _ = ink::env::pay_with_call!(vesting.vest(accounts.charlie), 10);
+            }
+            // A schedule with a longer duration is not fully withdrawn
+            let _ = vesting.set_config(CLIFF, 2 * DURATION);
+            let _ = ink::env::pay_with_call!(vesting.vest(accounts.charlie), 10);
+
+            set_block_timestamp(DURATION);
+            let charlie_initial_balance = get_balance(accounts.charlie);
+            set_next_caller(accounts.charlie);
+            let withdrawable = Balance::from(MAX_SCHEDULES_PER_WITHDRAWAL) * 10;
+            assert_eq!(vesting.get_withdrawable(accounts.charlie), Ok(withdrawable));
+            assert_eq!(vesting.vested_withdraw(), Ok(()));
+            assert_eq!(get_balance(accounts.charlie), charlie_initial_balance + withdrawable);
+            assert_eq!(vesting.get_first_schedule(accounts.charlie), MAX_SCHEDULES_PER_WITHDRAWAL);
+
+            // The next withdrawal starts from the first schedule not fully withdrawn
+            assert_eq!(vesting.get_withdrawable(accounts.charlie), Ok(10 + 5));
+            assert_eq!(vesting.vested_withdraw(), Ok(()));
+            assert_eq!(vesting.get_first_schedule(accounts.charlie), MAX_SCHEDULES_PER_WITHDRAWAL + 1);
+            assert_eq!(vesting.get_withdrawable(accounts.charlie), Ok(0));
+        }
+
+        #[ink::test]
+        fn supports_interface_works() {
+            let vesting = create_contract();
+            assert!(vesting.supports_interface(interface_ids::INTROSPECTION));
+            assert!(vesting.supports_interface(interface_ids::VESTING));
+            assert!(!vesting.supports_interface(interface_ids::FAUCET));
+        }
+
+        fn default_accounts() -> ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment> {
+            ink::env::test::default_accounts::<Environment>()
+        }
+
+        fn set_next_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        fn set_block_timestamp(timestamp: Timestamp) {
+            ink::env::test::set_block_timestamp::<ink::env::DefaultEnvironment>(timestamp);
+        }
+
+        fn get_balance(account: AccountId) -> Balance {
+            ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account)
+                .expect("Cannot get account balance")
+        }
+
+        /// Creates a new instance of `LinearVesting`.
+        ///
+        /// Returns the `contract_instance`.
+        fn create_contract() -> LinearVesting {
+            let accounts = default_accounts();
+            set_next_caller(accounts.alice);
+            LinearVesting::new(CLIFF, DURATION)
+        }
+    }
+}
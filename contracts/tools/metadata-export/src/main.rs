@@ -0,0 +1,265 @@
+//! Exports a simplified, machine-readable description of a compiled contract metadata
+//! (i.e. the `<contract>.json` file generated by `cargo contract build`).
+//!
+//! The description lists the constructors, messages, events and errors with their docs,
+//! and is consumed by the Kudos backend code generator.
+//!
+//! Usage: `metadata-export <metadata.json> [output.json]`
+use serde_json::{json, Map, Value};
+use std::{env, fs, process};
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.is_empty() || args.len() > 2 {
+        eprintln!("Usage: metadata-export <metadata.json> [output.json]");
+        process::exit(1);
+    }
+
+    let metadata = fs::read_to_string(&args[0])
+        .map_err(|e| e.to_string())
+        .and_then(|content| serde_json::from_str::<Value>(&content).map_err(|e| e.to_string()))
+        .unwrap_or_else(|e| {
+            eprintln!("Cannot load metadata `{}`: {}", args[0], e);
+            process::exit(1);
+        });
+
+    let description = match export(&metadata) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("Invalid metadata `{}`: {}", args[0], e);
+            process::exit(1);
+        }
+    };
+
+    let output = serde_json::to_string_pretty(&description).expect("valid JSON");
+    match args.get(1) {
+        Some(path) => fs::write(path, output + "\n").unwrap_or_else(|e| {
+            eprintln!("Cannot write `{}`: {}", path, e);
+            process::exit(1);
+        }),
+        None => println!("{}", output),
+    }
+}
+
+/// Builds the simplified description of the given contract `metadata`.
+fn export(metadata: &Value) -> Result<Value, String> {
+    let spec = metadata.get("spec").ok_or("missing `spec`")?;
+    let types = metadata
+        .get("types")
+        .and_then(Value::as_array)
+        .ok_or("missing `types`")?;
+
+    Ok(json!({
+        "name": metadata.pointer("/contract/name").cloned().unwrap_or(Value::Null),
+        "version": metadata.pointer("/contract/version").cloned().unwrap_or(Value::Null),
+        "constructors": entries(spec, "constructors", |entry| callable(entry, false))?,
+        "messages": entries(spec, "messages", |entry| callable(entry, true))?,
+        "events": entries(spec, "events", event)?,
+        "errors": errors(types),
+    }))
+}
+
+/// Maps every entry of the `key` array of the `spec`.
+fn entries(spec: &Value, key: &str, f: fn(&Value) -> Value) -> Result<Vec<Value>, String> {
+    spec.get(key)
+        .and_then(Value::as_array)
+        .map(|entries| entries.iter().map(f).collect())
+        .ok_or(format!("missing `spec.{}`", key))
+}
+
+/// Describes a constructor or a message.
+fn callable(entry: &Value, is_message: bool) -> Value {
+    let mut description = Map::new();
+    description.insert("label".into(), field(entry, "label"));
+    description.insert("selector".into(), field(entry, "selector"));
+    description.insert("payable".into(), field(entry, "payable"));
+    if is_message {
+        description.insert("mutates".into(), field(entry, "mutates"));
+    }
+    description.insert("args".into(), args(entry));
+    description.insert(
+        "returns".into(),
+        entry
+            .pointer("/returnType/displayName")
+            .map(display_name)
+            .unwrap_or(Value::Null),
+    );
+    description.insert("docs".into(), docs(entry));
+    Value::Object(description)
+}
+
+/// Describes an event.
+fn event(entry: &Value) -> Value {
+    json!({
+        "label": field(entry, "label"),
+        "args": args(entry),
+        "docs": docs(entry),
+    })
+}
+
+/// Describes the errors, i.e. the enum types whose name ends with `Error`.
+fn errors(types: &[Value]) -> Vec<Value> {
+    let mut errors: Vec<Value> = Vec::new();
+    for ty in types {
+        let Some(path) = ty.pointer("/type/path").and_then(Value::as_array) else {
+            continue;
+        };
+        let Some(variants) = ty
+            .pointer("/type/def/variant/variants")
+            .and_then(Value::as_array)
+        else {
+            continue;
+        };
+        let name = path.last().and_then(Value::as_str).unwrap_or_default();
+        let path = display_name(&Value::Array(path.clone()));
+        if !name.ends_with("Error") || errors.iter().any(|error| error["path"] == path) {
+            continue;
+        }
+
+        errors.push(json!({
+            "name": name,
+            "path": path,
+            "variants": variants
+                .iter()
+                .map(|variant| json!({
+                    "name": field(variant, "name"),
+                    "index": field(variant, "index"),
+                    "docs": docs(variant),
+                }))
+                .collect::<Vec<_>>(),
+        }));
+    }
+    errors
+}
+
+/// Describes the arguments of a constructor, a message or an event.
+fn args(entry: &Value) -> Value {
+    entry
+        .get("args")
+        .and_then(Value::as_array)
+        .map(|args| {
+            args.iter()
+                .map(|arg| {
+                    let mut description = Map::new();
+                    description.insert("label".into(), field(arg, "label"));
+                    description.insert(
+                        "type".into(),
+                        arg.pointer("/type/displayName")
+                            .map(display_name)
+                            .unwrap_or(Value::Null),
+                    );
+                    if let Some(indexed) = arg.get("indexed") {
+                        description.insert("indexed".into(), indexed.clone());
+                    }
+                    Value::Object(description)
+                })
+                .collect()
+        })
+        .unwrap_or_else(|| json!([]))
+}
+
+/// Joins the doc lines into a single string.
+fn docs(entry: &Value) -> Value {
+    let lines: Vec<&str> = entry
+        .get("docs")
+        .and_then(Value::as_array)
+        .map(|docs| docs.iter().filter_map(Value::as_str).map(str::trim).collect())
+        .unwrap_or_default();
+    Value::String(lines.join("\n").trim().to_string())
+}
+
+/// Joins the display name segments into a path (e.g. `Result<..>` or `single_token::Parameter`).
+fn display_name(segments: &Value) -> Value {
+    segments
+        .as_array()
+        .map(|segments| {
+            let segments: Vec<&str> = segments.iter().filter_map(Value::as_str).collect();
+            Value::String(segments.join("::"))
+        })
+        .unwrap_or(Value::Null)
+}
+
+fn field(entry: &Value, key: &str) -> Value {
+    entry.get(key).cloned().unwrap_or(Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> Value {
+        json!({
+            "contract": { "name": "single_token_contract", "version": "0.1.0" },
+            "spec": {
+                "constructors": [{
+                    "label": "new",
+                    "selector": "0x9bae9d5e",
+                    "payable": false,
+                    "args": [{ "label": "workflow", "type": { "displayName": ["HashValue"], "type": 1 } }],
+                    "returnType": { "displayName": ["ink_primitives", "ConstructorResult"], "type": 2 },
+                    "docs": [" Constructor that initializes an asset reward for a given workflow"]
+                }],
+                "messages": [{
+                    "label": "Workflow::claim",
+                    "selector": "0x2c2d3d8b",
+                    "payable": false,
+                    "mutates": true,
+                    "args": [{ "label": "contribution_id", "type": { "displayName": ["u64"], "type": 3 } }],
+                    "returnType": { "displayName": ["ink", "MessageResult"], "type": 4 },
+                    "docs": [" Claim reward for a given `contribution_id`."]
+                }],
+                "events": [{
+                    "label": "RewardClaimed",
+                    "args": [{ "label": "contribution_id", "indexed": false, "type": { "displayName": ["u64"], "type": 3 }, "docs": [] }],
+                    "docs": []
+                }]
+            },
+            "types": [
+                {
+                    "id": 5,
+                    "type": {
+                        "path": ["kudos_ink_contracts", "traits", "workflow", "WorkflowError"],
+                        "def": { "variant": { "variants": [
+                            { "name": "AlreadyClaimed", "index": 9, "docs": ["Returned if the reward has already been claimed."] }
+                        ] } }
+                    }
+                },
+                {
+                    "id": 6,
+                    "type": {
+                        "path": ["single_token", "single_token", "Parameter"],
+                        "def": { "variant": { "variants": [{ "name": "PlatformKey", "index": 0 }] } }
+                    }
+                }
+            ]
+        })
+    }
+
+    #[test]
+    fn export_works() {
+        let description = export(&metadata()).unwrap();
+        assert_eq!(description["name"], "single_token_contract");
+        assert_eq!(description["constructors"][0]["args"][0]["type"], "HashValue");
+        assert_eq!(
+            description["constructors"][0]["docs"],
+            "Constructor that initializes an asset reward for a given workflow"
+        );
+        assert_eq!(description["messages"][0]["label"], "Workflow::claim");
+        assert_eq!(description["messages"][0]["mutates"], true);
+        assert_eq!(description["events"][0]["args"][0]["indexed"], false);
+
+        let errors = description["errors"].as_array().unwrap();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0]["name"], "WorkflowError");
+        assert_eq!(errors[0]["variants"][0]["name"], "AlreadyClaimed");
+        assert_eq!(
+            errors[0]["variants"][0]["docs"],
+            "Returned if the reward has already been claimed."
+        );
+    }
+
+    #[test]
+    fn export_without_spec_fails() {
+        assert!(export(&json!({ "types": [] })).is_err());
+    }
+}
@@ -0,0 +1,78 @@
+//! The reward amounts, explicit about their unit.
+
+/// A reward amount, stored in plancks (i.e. the smallest unit of the token).
+///
+/// Rewards are configured in plancks while humans think in token units, hence the explicit
+/// constructors: a reward given in units where plancks are expected would be off by
+/// `10^decimals`. The amount is SCALE encoded exactly like the plain balance.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, scale::Decode, scale::Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    all(feature = "std", feature = "ink"),
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct RewardAmount(u128);
+
+impl RewardAmount {
+    /// Returns the amount of a given number of `plancks`.
+    pub const fn from_plancks(plancks: u128) -> Self {
+        Self(plancks)
+    }
+
+    /// Returns the amount of a given number of token `units`, for a token with `decimals`,
+    /// or `None` on overflow.
+    pub fn from_units(units: u128, decimals: u8) -> Option<Self> {
+        10u128
+            .checked_pow(u32::from(decimals))
+            .and_then(|planck_per_unit| units.checked_mul(planck_per_unit))
+            .map(Self)
+    }
+
+    /// Returns the amount in plancks.
+    pub const fn plancks(self) -> u128 {
+        self.0
+    }
+
+    /// Returns the whole token units of the amount and the remaining plancks, for a token
+    /// with `decimals`.
+    pub fn units(self, decimals: u8) -> (u128, u128) {
+        match 10u128.checked_pow(u32::from(decimals)) {
+            Some(planck_per_unit) => (self.0 / planck_per_unit, self.0 % planck_per_unit),
+            None => (0, self.0),
+        }
+    }
+}
+
+impl From<RewardAmount> for u128 {
+    fn from(amount: RewardAmount) -> Self {
+        amount.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::Encode;
+
+    #[test]
+    fn from_units_works() {
+        assert_eq!(RewardAmount::from_units(3, 12), Some(RewardAmount::from_plancks(3_000_000_000_000)));
+        assert_eq!(RewardAmount::from_units(3, 0), Some(RewardAmount::from_plancks(3)));
+        assert_eq!(RewardAmount::from_units(u128::MAX, 1), None);
+        assert_eq!(RewardAmount::from_units(1, 39), None);
+    }
+
+    #[test]
+    fn units_works() {
+        let amount = RewardAmount::from_plancks(1_500_000_000_000);
+        assert_eq!(amount.units(12), (1, 500_000_000_000));
+        assert_eq!(amount.units(18), (0, 1_500_000_000_000));
+        assert_eq!(amount.units(39), (0, 1_500_000_000_000));
+        assert_eq!(u128::from(amount), amount.plancks());
+    }
+
+    #[test]
+    fn encoding_matches_balance() {
+        assert_eq!(RewardAmount::from_plancks(42).encode(), 42u128.encode());
+    }
+}
@@ -0,0 +1,129 @@
+//! The lifecycle of an approved contribution, up to the claim of its reward.
+
+use crate::{HashValue, Timestamp};
+
+/// A Contribution is represented by:
+/// - a unique id.
+/// - the contributor identity.
+/// - the contributor; allowed to claim the reward.
+/// - the template it was approved from, if some.
+/// - the timestamp after which the reward cannot be claimed anymore, if some.
+/// - the hash of the work artifact (e.g. the PR URL or an IPFS CID of the review), if some.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    all(feature = "std", feature = "ink"),
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct Contribution<AccountId> {
+    // The unique contribution ID (e.g. the Github issue #id).
+    pub id: u64,
+    // The contributor identity (e.g. the hashed Github ID).
+    pub identity: HashValue,
+    // The contributor public key (e.g. extract from the `identities` mapping).
+    pub contributor: AccountId,
+    pub is_reward_claimed: bool,
+    pub template_id: Option<u32>,
+    pub expires_at: Option<Timestamp>,
+    pub metadata: Option<HashValue>,
+}
+
+/// The reasons a reward cannot be claimed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ClaimError {
+    /// The claimer is not the contributor.
+    CallerIsNotContributor,
+    /// The reward has already been claimed.
+    AlreadyClaimed,
+    /// The reward cannot be claimed anymore.
+    ContributionExpired,
+}
+
+impl<AccountId: PartialEq> Contribution<AccountId> {
+    /// Approve a contribution, its reward being claimable by a given `contributor`.
+    pub fn approve(
+        id: u64,
+        identity: HashValue,
+        contributor: AccountId,
+        template_id: Option<u32>,
+        expires_at: Option<Timestamp>,
+    ) -> Self {
+        Self {
+            id,
+            identity,
+            contributor,
+            is_reward_claimed: false,
+            template_id,
+            expires_at,
+            metadata: None,
+        }
+    }
+
+    /// Returns the contribution linked to the hash of a work artifact, if some.
+    pub fn with_metadata(self, metadata: Option<HashValue>) -> Self {
+        Self { metadata, ..self }
+    }
+
+    /// Ensure the reward can be claimed by a given `claimer`.
+    pub fn ensure_claimable_by(&self, claimer: &AccountId) -> Result<(), ClaimError> {
+        if *claimer != self.contributor {
+            return Err(ClaimError::CallerIsNotContributor);
+        }
+        if self.is_reward_claimed {
+            return Err(ClaimError::AlreadyClaimed);
+        }
+        Ok(())
+    }
+
+    /// Ensure the reward has not expired at a given timestamp (`now`).
+    pub fn ensure_not_expired(&self, now: Timestamp) -> Result<(), ClaimError> {
+        match self.expires_at {
+            Some(expires_at) if now >= expires_at => Err(ClaimError::ContributionExpired),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the contribution once its reward is claimed.
+    pub fn claim(self) -> Self {
+        Self {
+            is_reward_claimed: true,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use scale::Encode;
+
+    #[test]
+    fn claim_works() {
+        let contribution = Contribution::approve(1, [1; 32], 2u8, None, Some(10));
+        assert_eq!(contribution.ensure_claimable_by(&3), Err(ClaimError::CallerIsNotContributor));
+        assert_eq!(contribution.ensure_claimable_by(&2), Ok(()));
+        assert_eq!(contribution.ensure_not_expired(9), Ok(()));
+        assert_eq!(contribution.ensure_not_expired(10), Err(ClaimError::ContributionExpired));
+
+        let contribution = contribution.claim();
+        assert!(contribution.is_reward_claimed);
+        assert_eq!(contribution.ensure_claimable_by(&2), Err(ClaimError::AlreadyClaimed));
+    }
+
+    #[test]
+    fn encoding_matches_contract_storage() {
+        let contribution = Contribution::approve(7, [1; 32], [2u8; 32], Some(3), None);
+        let mut expected = 7u64.to_le_bytes().to_vec();
+        expected.extend_from_slice(&[1; 32]);
+        expected.extend_from_slice(&[2; 32]);
+        expected.extend_from_slice(&[0, 1, 3, 0, 0, 0, 0, 0]);
+        assert_eq!(contribution.encode(), expected);
+
+        let contribution = contribution.with_metadata(Some([4; 32]));
+        expected.pop();
+        expected.push(1);
+        expected.extend_from_slice(&[4; 32]);
+        assert_eq!(contribution.encode(), expected);
+    }
+}
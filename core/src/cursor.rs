@@ -0,0 +1,110 @@
+//! The bounded iteration over the indexed collections, resumable across calls.
+//!
+//! No message iterates an unbounded collection: every batch operation (e.g. the refunds)
+//! processes a [`Chunk`] of at most [`MAX_CHUNK_SIZE`] indices from a [`Cursor`], persisted
+//! between the calls until the end of the collection is reached.
+
+use core::ops::Range;
+
+/// The maximum number of items processed by a single call.
+pub const MAX_CHUNK_SIZE: u32 = 100;
+
+/// The position of the next item to process in an indexed collection.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    all(feature = "std", feature = "ink"),
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct Cursor(u32);
+
+impl Cursor {
+    /// Returns the cursor at a given `position`.
+    pub const fn new(position: u32) -> Self {
+        Self(position)
+    }
+
+    /// Returns the position of the next item to process.
+    pub const fn position(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the next chunk of up to `limit` indices (capped to `MAX_CHUNK_SIZE`) of a
+    /// collection of `len` items.
+    pub fn chunk(self, limit: u32, len: u32) -> Chunk {
+        let start = self.0.min(len);
+        let end = start.saturating_add(limit.min(MAX_CHUNK_SIZE)).min(len);
+        Chunk { start, end, len }
+    }
+}
+
+/// A bounded range of indices to process in a single call.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Chunk {
+    start: u32,
+    end: u32,
+    len: u32,
+}
+
+impl Chunk {
+    /// Returns the indices of the chunk.
+    pub fn indices(self) -> Range<u32> {
+        self.start..self.end
+    }
+
+    /// Returns `true` if a given `index` is the last one of the collection.
+    pub fn is_last(self, index: u32) -> bool {
+        index.saturating_add(1) == self.len
+    }
+
+    /// Returns the cursor to resume from in the next call.
+    pub fn next(self) -> Cursor {
+        Cursor(self.end)
+    }
+
+    /// Returns the number of items left to process after the chunk.
+    pub fn remaining(self) -> u32 {
+        self.len - self.end
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunk_is_bounded() {
+        let chunk = Cursor::default().chunk(u32::MAX, 1_000);
+        assert_eq!(chunk.indices(), 0..MAX_CHUNK_SIZE);
+        assert_eq!(chunk.remaining(), 1_000 - MAX_CHUNK_SIZE);
+
+        let chunk = Cursor::new(995).chunk(10, 1_000);
+        assert_eq!(chunk.indices(), 995..1_000);
+        assert!(chunk.is_last(999));
+        assert_eq!(chunk.remaining(), 0);
+        assert_eq!(Cursor::new(2_000).chunk(10, 1_000).indices(), 1_000..1_000);
+    }
+
+    #[test]
+    fn processing_resumes_across_calls() {
+        let records: u32 = 10_500;
+        let mut cursor = Cursor::default();
+        let mut processed = 0u32;
+        let mut calls = 0u32;
+        loop {
+            let chunk = cursor.chunk(u32::MAX, records);
+            for index in chunk.indices() {
+                assert_eq!(index, processed);
+                processed += 1;
+            }
+            cursor = chunk.next();
+            calls += 1;
+            if chunk.remaining() == 0 {
+                break;
+            }
+        }
+        assert_eq!(processed, records);
+        assert_eq!(calls, records.div_ceil(MAX_CHUNK_SIZE));
+        assert_eq!(cursor.position(), records);
+    }
+}
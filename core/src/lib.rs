@@ -0,0 +1,30 @@
+//! The core state machine of the Kudos reward contracts, free of any contract environment.
+//!
+//! The contracts are thin wrappers around these types and transitions, so that runtimes (e.g. a
+//! pallet or an off-chain worker) can embed the same logic. The types are SCALE encoded exactly
+//! like the contract storage, hence a runtime can decode the contract state and vice versa.
+//!
+//! The `ink` feature derives the ink! storage layout of the types, required to store them in
+//! a contract.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod amount;
+pub mod contribution;
+pub mod cursor;
+pub mod math;
+pub mod stats;
+pub mod streak;
+
+pub use amount::RewardAmount;
+pub use contribution::{ClaimError, Contribution};
+pub use cursor::{Chunk, Cursor};
+pub use stats::ContributorStats;
+pub use streak::Streak;
+
+/// A 32 bytes hash (e.g. the hashed Github ID of a contributor).
+pub type HashValue = [u8; 32];
+
+/// A timestamp in milliseconds.
+pub type Timestamp = u64;
@@ -0,0 +1,130 @@
+//! Overflow-safe payout math.
+
+use alloc::vec::Vec;
+
+/// Computes `a * b / denominator` without intermediate overflow.
+///
+/// The product is computed on 256 bits, so the result is only `None` if
+/// `denominator` is zero or if the quotient itself does not fit in a `u128`.
+/// The result is rounded down.
+pub fn checked_mul_div(a: u128, b: u128, denominator: u128) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+    if let Some(product) = a.checked_mul(b) {
+        return Some(product / denominator);
+    }
+
+    let (high, low) = wide_mul(a, b);
+    if high >= denominator {
+        return None;
+    }
+
+    // Long division of the 256 bits product by the 128 bits denominator.
+    let mut remainder = high;
+    let mut quotient = 0u128;
+    for bit in (0..128).rev() {
+        let carry = remainder >> 127;
+        remainder = (remainder << 1) | ((low >> bit) & 1);
+        quotient <<= 1;
+        if carry == 1 || remainder >= denominator {
+            remainder = remainder.wrapping_sub(denominator);
+            quotient |= 1;
+        }
+    }
+    Some(quotient)
+}
+
+/// Splits an `amount` pro-rata to the `weights`, every share being rounded down and the
+/// rounding remainder (i.e. dust) added to the share at `remainder_index`.
+///
+/// The shares always add up to `amount`. The result is `None` if the weights add up to
+/// zero or overflow, or if `remainder_index` is out of bounds.
+pub fn split_pro_rata(amount: u128, weights: &[u128], remainder_index: usize) -> Option<Vec<u128>> {
+    let total = weights
+        .iter()
+        .try_fold(0u128, |total, weight| total.checked_add(*weight))?;
+    if remainder_index >= weights.len() {
+        return None;
+    }
+
+    let mut shares = weights
+        .iter()
+        .map(|weight| checked_mul_div(amount, *weight, total))
+        .collect::<Option<Vec<_>>>()?;
+    let dust = amount - shares.iter().sum::<u128>();
+    shares[remainder_index] += dust;
+    Some(shares)
+}
+
+/// Computes the 256 bits product of `a` and `b` as a `(high, low)` pair.
+fn wide_mul(a: u128, b: u128) -> (u128, u128) {
+    const MASK: u128 = u64::MAX as u128;
+    let (a_high, a_low) = (a >> 64, a & MASK);
+    let (b_high, b_low) = (b >> 64, b & MASK);
+
+    let low_low = a_low * b_low;
+    let low_high = a_low * b_high;
+    let high_low = a_high * b_low;
+    let high_high = a_high * b_high;
+
+    let middle = (low_low >> 64) + (low_high & MASK) + (high_low & MASK);
+    let low = (low_low & MASK) | (middle << 64);
+    let high = high_high + (low_high >> 64) + (high_low >> 64) + (middle >> 64);
+    (high, low)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checked_mul_div_works() {
+        assert_eq!(checked_mul_div(10, 3, 4), Some(7));
+        assert_eq!(checked_mul_div(0, u128::MAX, 1), Some(0));
+        assert_eq!(checked_mul_div(u128::MAX, 1, 1), Some(u128::MAX));
+    }
+
+    #[test]
+    fn checked_mul_div_handles_wide_products() {
+        assert_eq!(checked_mul_div(u128::MAX, u128::MAX, u128::MAX), Some(u128::MAX));
+        assert_eq!(checked_mul_div(u128::MAX, 3, 4), Some(u128::MAX / 4 * 3 + 2));
+        assert_eq!(
+            checked_mul_div(10u128.pow(30), 10u128.pow(30), 10u128.pow(31)),
+            Some(10u128.pow(29))
+        );
+    }
+
+    #[test]
+    fn split_pro_rata_assigns_the_remainder() {
+        assert_eq!(split_pro_rata(101, &[30, 70], 0), Some(vec![31, 70]));
+        assert_eq!(split_pro_rata(101, &[30, 70], 1), Some(vec![30, 71]));
+        assert_eq!(split_pro_rata(2, &[1, 1, 1], 2), Some(vec![0, 0, 2]));
+        assert_eq!(split_pro_rata(0, &[1, 1], 0), Some(vec![0, 0]));
+        assert_eq!(split_pro_rata(5, &[0, 1], 0), Some(vec![0, 5]));
+        assert_eq!(split_pro_rata(7, &[1, 0], 1), Some(vec![7, 0]));
+        assert_eq!(
+            split_pro_rata(u128::MAX, &[1, 1, 1], 0),
+            Some(vec![u128::MAX / 3, u128::MAX / 3, u128::MAX / 3])
+        );
+        assert_eq!(
+            split_pro_rata(u128::MAX, &[u128::MAX - 1, 1], 1),
+            Some(vec![u128::MAX - 1, 1])
+        );
+    }
+
+    #[test]
+    fn split_pro_rata_fails() {
+        assert_eq!(split_pro_rata(1, &[], 0), None);
+        assert_eq!(split_pro_rata(1, &[0, 0], 0), None);
+        assert_eq!(split_pro_rata(1, &[1, 1], 2), None);
+        assert_eq!(split_pro_rata(1, &[u128::MAX, 1], 0), None);
+    }
+
+    #[test]
+    fn checked_mul_div_fails() {
+        assert_eq!(checked_mul_div(1, 1, 0), None);
+        assert_eq!(checked_mul_div(u128::MAX, 2, 1), None);
+        assert_eq!(checked_mul_div(u128::MAX, u128::MAX, u128::MAX - 1), None);
+    }
+}
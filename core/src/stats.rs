@@ -0,0 +1,51 @@
+//! The claim statistics of the contributors, the foundation of reputation-weighted features.
+
+/// The claim statistics of an identity:
+/// - the number of claimed rewards.
+/// - the total reward amount claimed (in plancks).
+/// - the block numbers of the first and of the last claim.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    all(feature = "std", feature = "ink"),
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct ContributorStats {
+    pub claims_count: u32,
+    pub total_rewarded: u128,
+    pub first_claim_at: u32,
+    pub last_claim_at: u32,
+}
+
+impl ContributorStats {
+    /// Returns the statistics updated with a `reward` claimed at a given `block` number.
+    pub fn record(self, reward: u128, block: u32) -> Self {
+        Self {
+            claims_count: self.claims_count.saturating_add(1),
+            total_rewarded: self.total_rewarded.saturating_add(reward),
+            first_claim_at: match self.claims_count {
+                0 => block,
+                _ => self.first_claim_at,
+            },
+            last_claim_at: block,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_works() {
+        let stats = ContributorStats::default().record(10, 3);
+        assert_eq!(
+            stats,
+            ContributorStats { claims_count: 1, total_rewarded: 10, first_claim_at: 3, last_claim_at: 3 }
+        );
+        assert_eq!(
+            stats.record(5, 8),
+            ContributorStats { claims_count: 2, total_rewarded: 15, first_claim_at: 3, last_claim_at: 8 }
+        );
+    }
+}
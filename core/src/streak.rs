@@ -0,0 +1,58 @@
+//! The claim streaks of the contributors, counted in epochs.
+
+/// The claim streak of an identity:
+/// - the number of consecutive epochs with at least one claim, up to the last claim epoch.
+/// - the longest streak ever reached.
+/// - the epoch of the last claim.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, scale::Decode, scale::Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    all(feature = "std", feature = "ink"),
+    derive(ink::storage::traits::StorageLayout)
+)]
+pub struct Streak {
+    pub current: u32,
+    pub longest: u32,
+    pub last_epoch: u32,
+}
+
+impl Streak {
+    /// Returns the streak extended by a claim in a given `epoch`.
+    pub fn extend(self, epoch: u32) -> Self {
+        let current = match self.current {
+            0 => 1,
+            current if self.last_epoch == epoch => current,
+            current if self.last_epoch + 1 == epoch => current + 1,
+            _ => 1,
+        };
+        Self {
+            current,
+            longest: self.longest.max(current),
+            last_epoch: epoch,
+        }
+    }
+
+    /// Returns the streak as of a given `epoch`, broken if no claim happened in the previous one.
+    pub fn at(self, epoch: u32) -> Self {
+        if self.last_epoch.saturating_add(1) < epoch {
+            return Self { current: 0, ..self };
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extend_works() {
+        let streak = Streak::default().extend(3);
+        assert_eq!(streak, Streak { current: 1, longest: 1, last_epoch: 3 });
+        let streak = streak.extend(3).extend(4);
+        assert_eq!(streak, Streak { current: 2, longest: 2, last_epoch: 4 });
+        assert_eq!(streak.at(5), streak);
+        assert_eq!(streak.at(6).current, 0);
+        assert_eq!(streak.extend(6), Streak { current: 1, longest: 2, last_epoch: 6 });
+    }
+}
@@ -0,0 +1,420 @@
+//! Release tasks for the Kudos Ink contracts, run with `cargo xtask <task>`.
+//!
+//! - `bundle [--out <dir>] [--verifiable]`: builds every contract in release mode with
+//!   `cargo contract` and collects the `.contract`, `.json` and `.wasm` artifacts in a bundle
+//!   directory (`bundle/` by default), along with a `manifest.json` describing every contract
+//!   (version, code hash, build info) and a `SHA256SUMS` file of all the artifacts.
+//!
+//! The bundle content is deterministic for a given build: entries are sorted and no
+//! timestamp is recorded. Use `--verifiable` to build the contracts in the `cargo contract`
+//! docker image, so that anyone can reproduce the code hashes.
+//!
+//! - `deploy --network <name|url> --suri <suri> --repository <org/repo> --owner <account>
+//!   [--workflow <hash>] [--out <file>]`: builds and deploys with `cargo contract` the identity
+//!   registry, the names registry and a workflow instance of the repository, in this order, then
+//!   wires them: the workflow resolves its contributors with the identity registry (once the
+//!   queued change is executed with `execute_change`), and the repository name (owned by
+//!   `--owner`) resolves to the workflow. The deployed addresses are
+//!   written to a deployment file (`deployment.json` by default) once every step succeeded, the
+//!   addresses deployed so far being reported otherwise.
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    process::{self, Command},
+};
+
+/// The contracts shipped in the bundle, relative to the workspace root.
+const CONTRACTS: &[&str] = &[
+    "contracts/src/bridge",
+    "contracts/src/factory",
+    "contracts/src/faucet",
+    "contracts/src/identity",
+    "contracts/src/insurance",
+    "contracts/src/names",
+    "contracts/src/reputation/leaderboard",
+    "contracts/src/token/single-token",
+    "contracts/src/verifier",
+    "contracts/src/vesting",
+];
+
+/// The networks known by name, any other network being used as the node URL.
+const NETWORKS: &[(&str, &str)] = &[
+    ("local", "ws://127.0.0.1:9944"),
+    ("rococo", "wss://rococo-contracts-rpc.polkadot.io"),
+    ("shibuya", "wss://rpc.shibuya.astar.network"),
+];
+
+const USAGE: &str = "Usage: cargo xtask bundle [--out <dir>] [--verifiable]
+       cargo xtask deploy --network <name|url> --suri <suri> --repository <org/repo> --owner <account> [--workflow <hash>] [--out <file>]";
+
+fn main() {
+    if let Err(e) = run(env::args().skip(1).collect()) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
+
+fn run(args: Vec<String>) -> Result<(), String> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("bundle") => {
+            let mut out = workspace_root().join("bundle");
+            let mut verifiable = false;
+            while let Some(arg) = args.next() {
+                match arg.as_str() {
+                    "--out" => out = PathBuf::from(args.next().ok_or(USAGE)?),
+                    "--verifiable" => verifiable = true,
+                    _ => return Err(USAGE.into()),
+                }
+            }
+            bundle(&out, verifiable)
+        }
+        Some("deploy") => {
+            let (mut network, mut suri, mut repository, mut owner) = (None, None, None, None);
+            let mut workflow = format!("0x{}", "00".repeat(32));
+            let mut out = workspace_root().join("deployment.json");
+            while let Some(arg) = args.next() {
+                let value = args.next().ok_or(USAGE)?;
+                match arg.as_str() {
+                    "--network" => network = Some(value),
+                    "--suri" => suri = Some(value),
+                    "--repository" => repository = Some(value),
+                    "--owner" => owner = Some(value),
+                    "--workflow" => workflow = value,
+                    "--out" => out = PathBuf::from(value),
+                    _ => return Err(USAGE.into()),
+                }
+            }
+            let (Some(network), Some(suri), Some(repository), Some(owner)) = (network, suri, repository, owner) else {
+                return Err(USAGE.into());
+            };
+            let deployer = Deployer {
+                url: network_url(&network),
+                suri,
+            };
+            deploy(&deployer, &repository, &owner, &workflow, &out)
+        }
+        _ => Err(USAGE.into()),
+    }
+}
+
+/// Builds every contract and collects the artifacts in the `out` directory.
+fn bundle(out: &Path, verifiable: bool) -> Result<(), String> {
+    if out.exists() {
+        fs::remove_dir_all(out).map_err(|e| format!("Cannot clean `{}`: {}", out.display(), e))?;
+    }
+
+    let mut contracts = Vec::new();
+    let mut checksums = Vec::new();
+    for contract in CONTRACTS {
+        let artifacts = build(&workspace_root().join(contract).join("Cargo.toml"), verifiable)?;
+        let metadata = read_metadata(&artifacts.metadata)?;
+        let name = metadata
+            .pointer("/contract/name")
+            .and_then(Value::as_str)
+            .ok_or("Missing contract name in metadata")?
+            .to_string();
+
+        let dir = out.join(&name);
+        fs::create_dir_all(&dir).map_err(|e| format!("Cannot create `{}`: {}", dir.display(), e))?;
+
+        let mut files = Vec::new();
+        for source in [&artifacts.bundle, &artifacts.metadata, &artifacts.wasm] {
+            let file_name = source.file_name().ok_or("Invalid artifact path")?;
+            let content = fs::read(source).map_err(|e| format!("Cannot read `{}`: {}", source.display(), e))?;
+            fs::write(dir.join(file_name), &content)
+                .map_err(|e| format!("Cannot write `{}`: {}", dir.display(), e))?;
+
+            let path = format!("{}/{}", name, file_name.to_string_lossy());
+            let sha256 = sha256_hex(&content);
+            files.push(json!({ "path": path, "sha256": sha256 }));
+            checksums.push((path, sha256));
+        }
+
+        contracts.push(json!({
+            "name": name,
+            "version": metadata.pointer("/contract/version").cloned().unwrap_or(Value::Null),
+            "code_hash": metadata.pointer("/source/hash").cloned().unwrap_or(Value::Null),
+            "build_info": metadata.pointer("/source/build_info").cloned().unwrap_or(Value::Null),
+            "image": metadata.get("image").cloned().unwrap_or(Value::Null),
+            "files": files,
+        }));
+    }
+    contracts.sort_by(|a, b| a["name"].as_str().cmp(&b["name"].as_str()));
+
+    let manifest = serde_json::to_string_pretty(&json!({ "contracts": contracts })).expect("valid JSON");
+    write(&out.join("manifest.json"), manifest + "\n")?;
+    write(&out.join("SHA256SUMS"), sha256sums(checksums))?;
+
+    println!("Bundle written to `{}`", out.display());
+    Ok(())
+}
+
+/// Deploys the identity registry, the names registry and a workflow instance of the `repository`,
+/// then wires them and writes the deployed addresses to the `out` file.
+fn deploy(deployer: &Deployer, repository: &str, owner: &str, workflow: &str, out: &Path) -> Result<(), String> {
+    let mut deployed = Vec::new();
+    let result = deploy_contracts(deployer, repository, owner, workflow, &mut deployed);
+    if let Err(e) = result {
+        let deployed = deployed
+            .iter()
+            .map(|(name, address)| format!("  {}: {}\n", name, address))
+            .collect::<String>();
+        return Err(format!("{}\nDeployed so far:\n{}", e, deployed));
+    }
+
+    let contracts = deployed
+        .iter()
+        .map(|(name, address)| (name.clone(), Value::String(address.clone())))
+        .collect::<serde_json::Map<_, _>>();
+    let deployment = json!({
+        "url": deployer.url,
+        "repository": repository,
+        "contracts": contracts,
+    });
+    write(out, serde_json::to_string_pretty(&deployment).expect("valid JSON") + "\n")?;
+
+    println!("Deployment written to `{}`", out.display());
+    Ok(())
+}
+
+/// Deploys and wires the contracts, recording the deployed addresses as they come.
+fn deploy_contracts(
+    deployer: &Deployer,
+    repository: &str,
+    owner: &str,
+    workflow: &str,
+    deployed: &mut Vec<(String, String)>,
+) -> Result<(), String> {
+    let mut instantiate = |contract: &str, args: &[&str]| -> Result<String, String> {
+        let artifacts = build(&workspace_root().join(contract).join("Cargo.toml"), false)?;
+        let address = deployer.instantiate(&artifacts.bundle, args)?;
+        let name = artifacts
+            .bundle
+            .file_stem()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| contract.into());
+        deployed.push((name, address.clone()));
+        Ok(address)
+    };
+
+    let identity = instantiate("contracts/src/identity", &[])?;
+    let names = instantiate("contracts/src/names", &[])?;
+    let single_token = instantiate("contracts/src/token/single-token", &[workflow])?;
+
+    // The identity registry is set through the timelock queue, `execute_change` applying it
+    // once the change delay has elapsed
+    let identity_registry = format!("IdentityRegistry(Some({}))", identity);
+    deployer.call(&single_token, "queue_change", &[&identity_registry])?;
+    deployer.call(&names, "register", &[&repository_name(repository), owner, &single_token])?;
+    Ok(())
+}
+
+/// The account and node the contracts are deployed with.
+struct Deployer {
+    url: String,
+    suri: String,
+}
+
+impl Deployer {
+    /// Instantiates the contract `bundle` with its `new` constructor, returning its address.
+    fn instantiate(&self, bundle: &Path, args: &[&str]) -> Result<String, String> {
+        let mut command = self.command("instantiate");
+        command.arg(bundle).args(["--constructor", "new"]);
+        if !args.is_empty() {
+            command.arg("--args").args(args);
+        }
+
+        println!("Instantiating `{}`", bundle.display());
+        contract_address(&run_command(command)?)
+    }
+
+    /// Calls a `message` of the deployed `contract`.
+    fn call(&self, contract: &str, message: &str, args: &[&str]) -> Result<(), String> {
+        let mut command = self.command("call");
+        command.args(["--contract", contract, "--message", message, "--args"]).args(args);
+
+        println!("Calling `{}` on `{}`", message, contract);
+        run_command(command).map(|_| ())
+    }
+
+    fn command(&self, subcommand: &str) -> Command {
+        let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+        command.args(["contract", subcommand, "--url", &self.url, "--suri", &self.suri]);
+        command.args(["--execute", "--skip-confirm", "--output-json"]);
+        command
+    }
+}
+
+/// Runs a `cargo contract` command, returning its JSON output.
+fn run_command(mut command: Command) -> Result<Value, String> {
+    let output = command
+        .output()
+        .map_err(|e| format!("Cannot run `cargo contract` (is it installed?): {}", e))?;
+    if !output.status.success() {
+        return Err(format!("`cargo contract` failed:\n{}", String::from_utf8_lossy(&output.stderr)));
+    }
+
+    serde_json::from_slice(&output.stdout).map_err(|e| format!("Invalid `cargo contract` output: {}", e))
+}
+
+/// Extracts the contract address of a `cargo contract instantiate` JSON output.
+fn contract_address(output: &Value) -> Result<String, String> {
+    output
+        .get("contract")
+        .and_then(Value::as_str)
+        .map(String::from)
+        .ok_or("Missing `contract` in `cargo contract` output".into())
+}
+
+/// Resolves the node URL of a known `network`, any other network being the URL itself.
+fn network_url(network: &str) -> String {
+    NETWORKS
+        .iter()
+        .find(|(name, _)| *name == network)
+        .map_or(network, |(_, url)| url)
+        .to_string()
+}
+
+/// Computes the name of a `repository` slug (e.g. `"org/repo"`) in the names registry,
+/// i.e. its SHA-256 hash.
+fn repository_name(repository: &str) -> String {
+    format!("0x{}", sha256_hex(repository.as_bytes()))
+}
+
+/// The artifacts of a contract build.
+struct Artifacts {
+    bundle: PathBuf,
+    metadata: PathBuf,
+    wasm: PathBuf,
+}
+
+/// Builds the contract of the given `manifest` in release mode.
+fn build(manifest: &Path, verifiable: bool) -> Result<Artifacts, String> {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    command.args(["contract", "build", "--release", "--output-json", "--manifest-path"]);
+    command.arg(manifest);
+    if verifiable {
+        command.arg("--verifiable");
+    }
+
+    println!("Building `{}`", manifest.display());
+    let output = command
+        .output()
+        .map_err(|e| format!("Cannot run `cargo contract` (is it installed?): {}", e))?;
+    if !output.status.success() {
+        return Err(format!(
+            "Cannot build `{}`:\n{}",
+            manifest.display(),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let result: Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("Invalid `cargo contract` output: {}", e))?;
+    let path = |pointer: &str| {
+        result
+            .pointer(pointer)
+            .and_then(Value::as_str)
+            .map(PathBuf::from)
+            .ok_or(format!("Missing `{}` in `cargo contract` output", pointer))
+    };
+    Ok(Artifacts {
+        bundle: path("/metadata_result/dest_bundle")?,
+        metadata: path("/metadata_result/dest_metadata")?,
+        wasm: path("/dest_wasm")?,
+    })
+}
+
+fn read_metadata(path: &Path) -> Result<Value, String> {
+    fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|content| serde_json::from_str(&content).map_err(|e| e.to_string()))
+        .map_err(|e| format!("Cannot load metadata `{}`: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: String) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| format!("Cannot write `{}`: {}", path.display(), e))
+}
+
+/// Formats the checksums in the `sha256sum` format, sorted by path.
+fn sha256sums(mut checksums: Vec<(String, String)>) -> String {
+    checksums.sort();
+    checksums
+        .into_iter()
+        .map(|(path, sha256)| format!("{}  {}\n", sha256, path))
+        .collect()
+}
+
+fn sha256_hex(content: &[u8]) -> String {
+    hex::encode(Sha256::digest(content))
+}
+
+fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask is a workspace member")
+        .to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sha256sums_are_sorted() {
+        let checksums = vec![
+            ("b/b.wasm".to_string(), sha256_hex(b"b")),
+            ("a/a.json".to_string(), sha256_hex(b"")),
+        ];
+        assert_eq!(
+            sha256sums(checksums),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855  a/a.json\n\
+             3e23e8160039594a33894f6564e1b1348bbd7a0088d42c4acb73eeaed59c009d  b/b.wasm\n"
+        );
+    }
+
+    #[test]
+    fn unknown_task_fails() {
+        assert_eq!(run(vec!["release".into()]), Err(USAGE.into()));
+        assert_eq!(run(vec!["bundle".into(), "--out".into()]), Err(USAGE.into()));
+    }
+
+    #[test]
+    fn deploy_requires_arguments() {
+        assert_eq!(run(vec!["deploy".into()]), Err(USAGE.into()));
+        assert_eq!(
+            run(vec!["deploy".into(), "--network".into(), "local".into(), "--suri".into()]),
+            Err(USAGE.into())
+        );
+        assert_eq!(
+            run(vec!["deploy".into(), "--network".into(), "local".into(), "--suri".into(), "//Alice".into()]),
+            Err(USAGE.into())
+        );
+    }
+
+    #[test]
+    fn network_url_works() {
+        assert_eq!(network_url("local"), "ws://127.0.0.1:9944");
+        assert_eq!(network_url("wss://example.org:443"), "wss://example.org:443");
+    }
+
+    #[test]
+    fn contract_address_works() {
+        assert_eq!(
+            contract_address(&json!({ "contract": "5Fake", "events": [] })),
+            Ok("5Fake".into())
+        );
+        assert!(contract_address(&json!({ "events": [] })).is_err());
+    }
+
+    #[test]
+    fn repository_name_is_sha256() {
+        assert_eq!(
+            repository_name(""),
+            "0xe3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+}